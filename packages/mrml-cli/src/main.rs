@@ -118,6 +118,7 @@ impl Options {
         log::debug!("parsing mjml input");
         let options = ParserOptions {
             include_loader: self.include_loader()?,
+            ..Default::default()
         };
         Mjml::parse_with_options(input, &options).map_err(format_parser_error)
     }
@@ -256,7 +257,9 @@ mod tests {
     use crate::format_parser_error;
 
     use super::Options;
-    use mrml::prelude::parser::{loader::IncludeLoaderError, Error as ParserError, Origin, Span};
+    use mrml::prelude::parser::{
+        loader::IncludeLoaderError, Error as ParserError, Limit, Location, Origin, Span,
+    };
 
     fn origin_include() -> Origin {
         Origin::Include {
@@ -268,6 +271,14 @@ mod tests {
         Span { start: 10, end: 20 }
     }
 
+    const fn any_location() -> Location {
+        Location {
+            offset: 10,
+            line: 2,
+            column: 3,
+        }
+    }
+
     #[test]
     fn format_parser_error_end_of_stream_in_root() {
         assert_eq!(
@@ -293,9 +304,10 @@ mod tests {
         assert_eq!(
             format_parser_error(ParserError::UnexpectedElement {
                 origin: Origin::Root,
-                position: any_span()
+                position: any_span(),
+                location: any_location()
             }),
-            "unexpected element in root template at position 10:20"
+            "unexpected element in root template at line 2, column 3"
         );
     }
 
@@ -304,9 +316,49 @@ mod tests {
         assert_eq!(
             format_parser_error(ParserError::UnexpectedElement {
                 origin: origin_include(),
-                position: any_span()
+                position: any_span(),
+                location: any_location()
+            }),
+            "unexpected element in template from \"foo.mjml\" at line 2, column 3"
+        );
+    }
+
+    #[test]
+    fn format_parser_error_duplicate_element_in_root() {
+        assert_eq!(
+            format_parser_error(ParserError::DuplicateElement {
+                origin: Origin::Root,
+                position: any_span(),
+                location: any_location()
+            }),
+            "duplicate element in root template at line 2, column 3"
+        );
+    }
+
+    #[test]
+    fn format_parser_error_limit_exceeded_in_root() {
+        assert_eq!(
+            format_parser_error(ParserError::LimitExceeded {
+                limit: Limit::Depth,
+                origin: Origin::Root,
+                position: any_span(),
+                location: any_location()
+            }),
+            "depth limit exceeded in root template at line 2, column 3"
+        );
+    }
+
+    #[test]
+    fn format_parser_error_unknown_attribute_in_root() {
+        assert_eq!(
+            format_parser_error(ParserError::UnknownAttribute {
+                element: String::from("mj-spacer"),
+                name: String::from("align"),
+                origin: Origin::Root,
+                position: any_span(),
+                location: any_location()
             }),
-            "unexpected element in template from \"foo.mjml\" at position 10:20"
+            "unknown attribute \"align\" for mj-spacer in root template at line 2, column 3"
         );
     }
 
@@ -315,9 +367,10 @@ mod tests {
         assert_eq!(
             format_parser_error(ParserError::InvalidAttribute {
                 origin: Origin::Root,
-                position: any_span()
+                position: any_span(),
+                location: any_location()
             }),
-            "invalid attribute in root template at position 10:20"
+            "invalid attribute in root template at line 2, column 3"
         );
     }
 
@@ -326,9 +379,10 @@ mod tests {
         assert_eq!(
             format_parser_error(ParserError::InvalidAttribute {
                 origin: origin_include(),
-                position: any_span()
+                position: any_span(),
+                location: any_location()
             }),
-            "invalid attribute in template from \"foo.mjml\" at position 10:20"
+            "invalid attribute in template from \"foo.mjml\" at line 2, column 3"
         );
     }
 
@@ -337,9 +391,10 @@ mod tests {
         assert_eq!(
             format_parser_error(ParserError::InvalidFormat {
                 origin: Origin::Root,
-                position: any_span()
+                position: any_span(),
+                location: any_location()
             }),
-            "invalid format in root template at position 10:20"
+            "invalid format in root template at line 2, column 3"
         );
     }
 
@@ -348,9 +403,10 @@ mod tests {
         assert_eq!(
             format_parser_error(ParserError::InvalidFormat {
                 origin: origin_include(),
-                position: any_span()
+                position: any_span(),
+                location: any_location()
             }),
-            "invalid format in template from \"foo.mjml\" at position 10:20"
+            "invalid format in template from \"foo.mjml\" at line 2, column 3"
         );
     }
 
@@ -360,14 +416,15 @@ mod tests {
             format_parser_error(ParserError::IncludeLoaderError {
                 origin: Origin::Root,
                 position: any_span(),
-                source: IncludeLoaderError {
+                location: any_location(),
+                source: Box::new(IncludeLoaderError {
                     path: String::from("foo.mjml"),
                     reason: std::io::ErrorKind::NotFound,
                     message: None,
                     cause: None,
-                }
+                })
             }),
-            "unable to load included template in root template at position 10:20: foo.mjml entity not found"
+            "unable to load included template in root template at line 2, column 3: foo.mjml entity not found"
         );
     }
 
@@ -377,14 +434,28 @@ mod tests {
             format_parser_error(ParserError::IncludeLoaderError {
                 origin: Origin::Root,
                 position: any_span(),
-                source: IncludeLoaderError {
+                location: any_location(),
+                source: Box::new(IncludeLoaderError {
                     path: String::from("foo.mjml"),
                     reason: std::io::ErrorKind::NotFound,
                     message: None,
                     cause: None,
-                }
+                })
             }),
-            "unable to load included template in root template at position 10:20: foo.mjml entity not found"
+            "unable to load included template in root template at line 2, column 3: foo.mjml entity not found"
+        );
+    }
+
+    #[test]
+    fn format_parser_error_include_cycle_in_include() {
+        assert_eq!(
+            format_parser_error(ParserError::IncludeCycle {
+                path: String::from("bar.mjml"),
+                origin: origin_include(),
+                position: any_span(),
+                location: any_location()
+            }),
+            "include cycle with \"bar.mjml\" in template from \"foo.mjml\" at line 2, column 3"
         );
     }
 
@@ -394,9 +465,10 @@ mod tests {
             format_parser_error(ParserError::MissingAttribute {
                 name: "name",
                 origin: Origin::Root,
-                position: any_span()
+                position: any_span(),
+                location: any_location()
             }),
-            "missing attribute \"name\" in element in root template at position 10:20"
+            "missing attribute \"name\" in element in root template at line 2, column 3"
         );
     }
 
@@ -406,9 +478,23 @@ mod tests {
             format_parser_error(ParserError::MissingAttribute {
                 name: "name",
                 origin: origin_include(),
-                position: any_span()
+                position: any_span(),
+                location: any_location()
+            }),
+            "missing attribute \"name\" in element in template from \"foo.mjml\" at line 2, column 3"
+        );
+    }
+
+    #[test]
+    fn format_parser_error_io() {
+        assert_eq!(
+            format_parser_error(ParserError::Io {
+                source: std::sync::Arc::new(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "stream did not contain valid UTF-8"
+                ))
             }),
-            "missing attribute \"name\" in element in template from \"foo.mjml\" at position 10:20"
+            "unable to read template: stream did not contain valid UTF-8"
         );
     }
 
@@ -0,0 +1,58 @@
+//! Renders a large template, counting the allocations made by a render.
+//!
+//! All the elements write in the same `RenderBuffer`, shared through the
+//! `RenderCursor`, so there is no per element `String` being concatenated and
+//! no arena to add. The number of allocations only depends on the attributes
+//! and styles of the tags and on how many times the buffer needs to grow.
+//!
+//! Before/after reserving the document buffer, with 200 sections: 15048 then
+//! 15039 allocations per render, most of the remaining ones being for the
+//! attributes and styles of the tags, and around 3.2ms per render in both
+//! cases.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let section = r#"<mj-section><mj-column><mj-text>Hello World!</mj-text><mj-button href="https://example.com">Click</mj-button></mj-column></mj-section>"#;
+    let template = format!("<mjml><mj-body>{}</mj-body></mjml>", section.repeat(200));
+    let root = mrml::parse(template).unwrap();
+    let opts = mrml::prelude::render::RenderOptions::default();
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    root.element.render(&opts).unwrap();
+    let count = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    println!("allocations per render: {count}");
+
+    c.bench_function("render large template", |b| {
+        b.iter(|| black_box(&root.element).render(&opts).unwrap())
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -168,20 +168,22 @@ fn compare_attributes<'a>(
         });
     }
 
+    // if attribute is `class` or `style`, and the value is empty, we can ignore it
+    let is_relevant = |attr: &&Attribute<'a>| {
+        if ["class", "style"].contains(&attr.local.as_str()) {
+            attr.value.is_some_and(|v| !v.is_empty())
+        } else {
+            true
+        }
+    };
     let exp_keys = exp_attrs
         .iter()
-        .filter(|attr| {
-            // if attribute is `class` or `style`, and the value is empty, we can ignore it
-            if ["class", "style"].contains(&attr.local.as_str()) {
-                attr.value.map_or(false, |v| !v.is_empty())
-            } else {
-                true
-            }
-        })
+        .filter(is_relevant)
         .map(|attr| (attr.local.as_str(), attr.local))
         .collect::<BTreeMap<_, _>>();
     let gen_keys = gen_attrs
         .iter()
+        .filter(is_relevant)
         .map(|attr| (attr.local.as_str(), attr.local))
         .collect::<BTreeMap<_, _>>();
 
@@ -203,7 +205,7 @@ fn compare_attributes<'a>(
     }
     let diff = gen_str_keys
         .difference(&exp_str_keys)
-        .filter_map(|key| exp_keys.get(*key).copied())
+        .filter_map(|key| gen_keys.get(*key).copied())
         .collect::<Vec<_>>();
     if !diff.is_empty() {
         return Err(ErrorKind::UnexpectedAttributesFound(diff));
@@ -232,7 +234,7 @@ fn compare_attributes<'a>(
                 } else if exp_value.as_str() != gen_value.as_str() {
                     return Err(ErrorKind::InvalidAttributeValue {
                         expected: exp_attr.clone(),
-                        generated: exp_attr.clone(),
+                        generated: gen_attr.clone(),
                     });
                 }
             }
@@ -503,6 +505,24 @@ mod tests {
         ).unwrap();
     }
 
+    #[test]
+    fn unexpected_attribute_found() {
+        let err = compare(
+            r#"<a href="https://example.com" class="">Link</a>"#,
+            r#"<a href="https://example.com" rel="noopener">Link</a>"#,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err.kind,
+            ErrorKind::UnexpectedAttributesFound(ref attrs) if attrs.len() == 1 && attrs[0].as_str() == "rel"
+        ));
+        compare(
+            r#"<a href="https://example.com" class="">Link</a>"#,
+            r#"<a href="https://example.com" style="">Link</a>"#,
+        )
+        .unwrap();
+    }
+
     #[test]
     fn with_conditional_dom() {
         compare(
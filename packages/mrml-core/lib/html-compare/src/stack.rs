@@ -110,17 +110,18 @@ impl<'a> TokenStack<'a> {
                         Some(Token::ConditionalCommentStart { condition, .. })
                             if Some(condition.as_str()) == previous_condition =>
                         {
-                            // do nothing
+                            // the condition stays open
                         }
                         Some(next) => {
                             self.inner.push_front(next);
                             inner.push_back(token);
+                            previous_condition = None;
                         }
                         None => {
                             inner.push_back(token);
+                            previous_condition = None;
                         }
                     }
-                    previous_condition = None;
                 }
                 other => {
                     inner.push_back(other);
@@ -268,6 +269,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn remove_successive_duplication_conditions() {
+        let expected = TokenStack::parse(
+            "<!--[if mso | IE]><a><![endif]--><!--[if mso | IE]><b><![endif]--><!--[if mso | IE]><c><![endif]-->",
+        )
+        .sanitize();
+        let result = TokenStack::parse("<!--[if mso | IE]><a><b><c><![endif]-->").sanitize();
+        assert_eq!(
+            expected
+                .inner
+                .into_iter()
+                .map(|token| token.span().as_str())
+                .collect::<Vec<_>>(),
+            result
+                .inner
+                .into_iter()
+                .map(|token| token.span().as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn keep_different_conditions() {
         let expected = TokenStack::parse(
@@ -1,13 +1,19 @@
 use super::Comment;
 use crate::prelude::render::*;
 
-impl<'root> Render<'root> for Renderer<'root, Comment, ()> {
+#[derive(Default)]
+pub(crate) struct CommentExtra {
+    // the comments of an mj-raw are kept even when the comments are disabled
+    keep: bool,
+}
+
+impl<'root> Render<'root> for Renderer<'root, Comment, CommentExtra> {
     fn context(&self) -> &'root RenderContext<'root> {
         self.context
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        if !self.context.options.disable_comments {
+        if self.extra.keep || !self.context.options.disable_comments {
             cursor.buffer.push_str("<!--");
             cursor.buffer.push_str(self.element.children.as_str());
             cursor.buffer.push_str("-->");
@@ -25,7 +31,18 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for Comment {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Box::new(Renderer::new(context, self, CommentExtra::default()))
+    }
+}
+
+impl Comment {
+    /// Renders the comment whatever the `disable_comments` option, like the
+    /// conditional comments of an `mj-raw` element.
+    pub(crate) fn kept_renderer<'render, 'root: 'render>(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, CommentExtra { keep: true }))
     }
 }
 
@@ -17,6 +17,8 @@ pub enum SizeParserError {
 pub enum Size {
     Pixel(Pixel),
     Percent(Percent),
+    Em(f32),
+    Rem(f32),
     Raw(f32),
 }
 
@@ -36,11 +38,32 @@ impl Size {
         matches!(self, Self::Pixel(_))
     }
 
+    /// Whether the size depends on the font size, and therefore can't be
+    /// used when computing the width of the elements.
+    pub fn is_font_relative(&self) -> bool {
+        matches!(self, Self::Em(_) | Self::Rem(_))
+    }
+
+    /// Resolves the size to a pixel value, the percentages being relative to
+    /// the container. Returns `None` when the percentage can't be resolved
+    /// because the container isn't a pixel value, or when the size depends on
+    /// the font size.
+    pub fn to_pixel(self, container: Size) -> Option<Size> {
+        match (self, container) {
+            (Self::Pixel(_), _) => Some(self),
+            (Self::Raw(value), _) => Some(Self::pixel(value)),
+            (Self::Percent(pc), Self::Pixel(px)) => {
+                Some(Self::pixel(px.value() * pc.value() / 100.0))
+            }
+            _ => None,
+        }
+    }
+
     pub fn value(&self) -> f32 {
         match self {
             Self::Pixel(p) => p.value(),
             Self::Percent(p) => p.value(),
-            Self::Raw(v) => *v,
+            Self::Em(v) | Self::Rem(v) | Self::Raw(v) => *v,
         }
     }
 }
@@ -53,6 +76,10 @@ impl TryFrom<&str> for Size {
             Ok(Self::Pixel(Pixel::try_from(value)?))
         } else if value.ends_with('%') {
             Ok(Self::Percent(Percent::try_from(value)?))
+        } else if let Some(value) = value.strip_suffix("rem") {
+            Ok(Self::Rem(value.parse::<f32>()?))
+        } else if let Some(value) = value.strip_suffix("em") {
+            Ok(Self::Em(value.parse::<f32>()?))
         } else {
             Ok(Self::Raw(value.parse::<f32>()?))
         }
@@ -64,6 +91,8 @@ impl std::fmt::Display for Size {
         match self {
             Self::Pixel(inner) => inner.fmt(f),
             Self::Percent(inner) => inner.fmt(f),
+            Self::Em(inner) => write!(f, "{inner}em"),
+            Self::Rem(inner) => write!(f, "{inner}rem"),
             Self::Raw(inner) => write!(f, "{inner}"),
         }
     }
@@ -163,3 +192,58 @@ impl std::fmt::Display for Pixel {
         write!(f, "{}px", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Size;
+
+    #[test]
+    fn should_parse_sizes() {
+        assert_eq!(Size::try_from("10px").unwrap(), Size::pixel(10.0));
+        assert_eq!(Size::try_from("50%").unwrap(), Size::percent(50.0));
+        assert_eq!(Size::try_from("1.5em").unwrap(), Size::Em(1.5));
+        assert_eq!(Size::try_from("2rem").unwrap(), Size::Rem(2.0));
+        assert_eq!(Size::try_from("480").unwrap(), Size::Raw(480.0));
+        assert!(Size::try_from("2vw").is_err());
+        assert!(Size::try_from("em").is_err());
+    }
+
+    #[test]
+    fn should_parse_negative_sizes() {
+        assert_eq!(Size::try_from("-2px").unwrap(), Size::pixel(-2.0));
+        assert_eq!(Size::try_from("-0.5em").unwrap(), Size::Em(-0.5));
+        assert_eq!(Size::try_from("-10").unwrap(), Size::Raw(-10.0));
+    }
+
+    #[test]
+    fn should_display_sizes() {
+        for value in ["10px", "-0.5px", "50%", "1.5em", "2rem", "480"] {
+            assert_eq!(Size::try_from(value).unwrap().to_string(), value);
+        }
+    }
+
+    #[test]
+    fn should_resolve_to_pixel() {
+        let container = Size::pixel(600.0);
+        assert_eq!(
+            Size::percent(50.0).to_pixel(container),
+            Some(Size::pixel(300.0))
+        );
+        assert_eq!(
+            Size::pixel(20.0).to_pixel(container),
+            Some(Size::pixel(20.0))
+        );
+        assert_eq!(Size::Raw(20.0).to_pixel(container), Some(Size::pixel(20.0)));
+        assert_eq!(Size::percent(50.0).to_pixel(Size::percent(50.0)), None);
+        assert_eq!(Size::Em(2.0).to_pixel(container), None);
+    }
+
+    #[test]
+    fn should_be_font_relative() {
+        assert!(Size::Em(1.0).is_font_relative());
+        assert!(Size::Rem(1.0).is_font_relative());
+        assert!(!Size::pixel(1.0).is_font_relative());
+        assert!(!Size::percent(1.0).is_font_relative());
+        assert!(!Size::Raw(1.0).is_font_relative());
+    }
+}
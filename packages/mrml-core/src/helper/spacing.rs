@@ -33,15 +33,6 @@ impl Spacing {
         }
     }
 
-    pub fn into_top(self) -> Pixel {
-        match self {
-            Self::Single(top) => top,
-            Self::Two(vertical, _horizontal) => vertical,
-            Self::Three(top, _horizontal, _bottom) => top,
-            Self::Four(top, _right, _bottom, _left) => top,
-        }
-    }
-
     pub fn right(&self) -> &Pixel {
         match self {
             Self::Single(top) => top,
@@ -70,15 +61,6 @@ impl Spacing {
         }
     }
 
-    pub fn into_bottom(self) -> Pixel {
-        match self {
-            Self::Single(top) => top,
-            Self::Two(vertical, _horizontal) => vertical,
-            Self::Three(_top, _horizontal, bottom) => bottom,
-            Self::Four(_top, _right, bottom, _left) => bottom,
-        }
-    }
-
     pub fn left(&self) -> &Pixel {
         match self {
             Self::Single(top) => top,
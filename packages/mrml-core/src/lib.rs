@@ -40,6 +40,7 @@
 //! let loader = MemoryIncludeLoader::from(vec![("partial.mjml", "<mj-button>Hello</mj-button>")]);
 //! let options = ParserOptions {
 //!     include_loader: Box::new(loader),
+//!     ..Default::default()
 //! };
 //! match mrml::parse_with_options("<mjml><mj-head /><mj-body><mj-include path=\"partial.mjml\" /></mj-body></mjml>", &options) {
 //!     Ok(_) => println!("Success!"),
@@ -75,6 +76,7 @@
 //!     .with_any(Box::<NoopIncludeLoader>::default());
 //! let parser_options = AsyncParserOptions {
 //!     include_loader: Box::new(resolver),
+//!     ..Default::default()
 //! };
 //! let render_options = RenderOptions::default();
 //! let json = r#"<mjml>
@@ -144,6 +146,8 @@ pub mod mj_font;
 pub mod mj_group;
 pub mod mj_head;
 pub mod mj_hero;
+pub mod mj_html_attribute;
+pub mod mj_html_attributes;
 pub mod mj_image;
 pub mod mj_include;
 pub mod mj_navbar;
@@ -151,6 +155,7 @@ pub mod mj_navbar_link;
 pub mod mj_preview;
 pub mod mj_raw;
 pub mod mj_section;
+pub mod mj_selector;
 pub mod mj_social;
 pub mod mj_social_element;
 pub mod mj_spacer;
@@ -162,6 +167,10 @@ pub mod mj_wrapper;
 pub mod mjml;
 pub mod node;
 pub mod prelude;
+#[cfg(all(feature = "parse", feature = "render"))]
+mod renderer;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod text;
 
 // Only used to ignore the comments at the root level
@@ -186,6 +195,7 @@ mod helper;
 ///
 /// let options = ParserOptions {
 ///     include_loader: Box::new(MemoryIncludeLoader::default()),
+///     ..Default::default()
 /// };
 /// match mrml::parse_with_options("<mjml><mj-head /><mj-body /></mjml>", &options) {
 ///     Ok(_) => println!("Success!"),
@@ -224,6 +234,7 @@ pub fn parse_with_options<T: AsRef<str>>(
 ///
 /// let options = std::sync::Arc::new(AsyncParserOptions {
 ///     include_loader: Box::new(MemoryIncludeLoader::default()),
+///     ..Default::default()
 /// });
 /// match mrml::async_parse_with_options("<mjml><mj-head /><mj-body /></mjml>", options).await {
 ///     Ok(_) => println!("Success!"),
@@ -262,6 +273,36 @@ pub fn parse<T: AsRef<str>>(
     parse_with_options(input, &opts)
 }
 
+#[cfg(feature = "parse")]
+/// Function to parse a mjml template read from a [reader](std::io::Read), like
+/// a file, with some parsing [options](crate::prelude::parser::ParserOptions).
+///
+/// The whole content is read before being parsed, a read error or a content
+/// that is not valid UTF-8 fails with
+/// [`Error::Io`](crate::prelude::parser::Error::Io).
+///
+/// ```rust
+/// use mrml::prelude::parser::ParserOptions;
+///
+/// let reader = std::io::Cursor::new("<mjml><mj-head /><mj-body /></mjml>");
+/// match mrml::parse_reader(reader, &ParserOptions::default()) {
+///     Ok(_) => println!("Success!"),
+///     Err(err) => eprintln!("Something went wrong: {err:?}"),
+/// }
+/// ```
+pub fn parse_reader<R: std::io::Read>(
+    mut reader: R,
+    opts: &crate::prelude::parser::ParserOptions,
+) -> Result<prelude::parser::ParseOutput<mjml::Mjml>, prelude::parser::Error> {
+    let mut input = String::new();
+    reader
+        .read_to_string(&mut input)
+        .map_err(|source| prelude::parser::Error::Io {
+            source: std::sync::Arc::new(source),
+        })?;
+    parse_with_options(input, opts)
+}
+
 #[cfg(all(feature = "parse", feature = "async"))]
 /// Function to parse a raw mjml template using the default parsing
 /// [options](crate::prelude::parser::ParserOptions).
@@ -281,6 +322,180 @@ pub async fn async_parse<T: AsRef<str>>(
     async_parse_with_options(input, opts).await
 }
 
+#[cfg(feature = "parse")]
+/// Function to report all the issues of a raw mjml template, using some
+/// parsing [options](crate::prelude::parser::ParserOptions), without rendering
+/// it.
+///
+/// Unlike [`parse_with_options`](crate::parse_with_options), it doesn't stop at
+/// the first unexpected element: the element is reported as an error and
+/// skipped to keep looking for the other issues, in a single pass. The parsing
+/// warnings, like duplicate titles, are reported as warnings, along with the
+/// invalid sizes, which are only checked when validating. When an error can't
+/// be skipped, it's reported last, after the warnings found before it.
+///
+/// ```rust
+/// use mrml::prelude::parser::{ParserOptions, Severity};
+///
+/// let template = r#"<mjml>
+///   <mj-head>
+///     <mj-foo />
+///     <mj-title>Hello</mj-title>
+///     <mj-title>World</mj-title>
+///   </mj-head>
+/// </mjml>"#;
+/// let diagnostics = mrml::validate(template, &ParserOptions::default());
+/// assert_eq!(diagnostics.len(), 2);
+/// assert_eq!(diagnostics[0].severity, Severity::Error);
+/// assert_eq!(diagnostics[1].severity, Severity::Warning);
+/// ```
+pub fn validate<T: AsRef<str>>(
+    input: T,
+    opts: &crate::prelude::parser::ParserOptions,
+) -> Vec<crate::prelude::parser::Diagnostic> {
+    crate::prelude::parser::validate(input.as_ref(), opts)
+}
+
+#[cfg(all(feature = "parse", feature = "render"))]
+/// Error returned when parsing and rendering a template at once, with
+/// [`to_html`](crate::to_html), [`async_to_html`](crate::async_to_html) or
+/// [`write_html`](crate::write_html).
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Parser(#[from] prelude::parser::Error),
+    #[error(transparent)]
+    Render(#[from] prelude::render::Error),
+    /// Only returned by [`write_html`](crate::write_html), when the rendered
+    /// template cannot be written.
+    #[error("unable to write {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(all(feature = "parse", feature = "render"))]
+pub use renderer::Renderer;
+
+#[cfg(all(feature = "parse", feature = "render"))]
+/// Function to parse a raw mjml template with some parsing
+/// [options](crate::prelude::parser::ParserOptions) and to render it with some
+/// rendering [options](crate::prelude::render::RenderOptions).
+///
+/// ```rust
+/// use mrml::prelude::parser::ParserOptions;
+/// use mrml::prelude::render::RenderOptions;
+///
+/// match mrml::to_html("<mjml><mj-body /></mjml>", &ParserOptions::default(), &RenderOptions::default()) {
+///     Ok(html) => println!("{html}"),
+///     Err(err) => eprintln!("Something went wrong: {err:?}"),
+/// }
+/// ```
+pub fn to_html<T: AsRef<str>>(
+    input: T,
+    parser_options: &prelude::parser::ParserOptions,
+    render_options: &prelude::render::RenderOptions,
+) -> Result<String, Error> {
+    let root = parse_with_options(input, parser_options)?;
+    Ok(root.element.render(render_options)?)
+}
+
+#[cfg(all(feature = "parse", feature = "render"))]
+/// Function to parse a raw mjml template and to write its html into a
+/// [writer](std::io::Write), like a file or a socket, with the same options as
+/// [`to_html`](crate::to_html).
+///
+/// The document is rendered in memory before being written, see
+/// [`Mjml::write_to`](crate::mjml::Mjml::write_to).
+///
+/// ```rust
+/// use mrml::prelude::parser::ParserOptions;
+/// use mrml::prelude::render::RenderOptions;
+///
+/// let mut output = Vec::new();
+/// match mrml::write_html("<mjml><mj-body /></mjml>", &ParserOptions::default(), &RenderOptions::default(), &mut output) {
+///     Ok(_) => println!("{}", String::from_utf8_lossy(&output)),
+///     Err(err) => eprintln!("Something went wrong: {err:?}"),
+/// }
+/// ```
+pub fn write_html<T: AsRef<str>, W: std::io::Write>(
+    input: T,
+    parser_options: &prelude::parser::ParserOptions,
+    render_options: &prelude::render::RenderOptions,
+    writer: &mut W,
+) -> Result<(), Error> {
+    let html = to_html(input, parser_options, render_options)?;
+    writer.write_all(html.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(all(feature = "parse", feature = "render", feature = "async"))]
+/// Function to parse asynchronously a raw mjml template with some parsing
+/// [options](crate::prelude::parser::AsyncParserOptions) and to render it with
+/// some rendering [options](crate::prelude::render::RenderOptions).
+///
+/// Only the loading of the [`mj-include`](crate::mj_include) elements is
+/// asynchronous, through the
+/// [`AsyncIncludeLoader`](crate::prelude::parser::loader::AsyncIncludeLoader),
+/// the rendering itself is synchronous.
+///
+/// ```rust
+/// # tokio_test::block_on(async {
+/// use mrml::prelude::parser::AsyncParserOptions;
+/// use mrml::prelude::parser::memory_loader::MemoryIncludeLoader;
+/// use mrml::prelude::render::RenderOptions;
+///
+/// let loader = MemoryIncludeLoader::from(vec![("partial.mjml", "<mj-button>Hello</mj-button>")]);
+/// let parser_options = std::sync::Arc::new(AsyncParserOptions {
+///     include_loader: Box::new(loader),
+///     ..Default::default()
+/// });
+/// let template = r#"<mjml><mj-body><mj-include path="partial.mjml" /></mj-body></mjml>"#;
+/// match mrml::async_to_html(template, parser_options, &RenderOptions::default()).await {
+///     Ok(html) => println!("{html}"),
+///     Err(err) => eprintln!("Something went wrong: {err:?}"),
+/// }
+/// # })
+/// ```
+pub async fn async_to_html<T: AsRef<str>>(
+    input: T,
+    parser_options: std::sync::Arc<prelude::parser::AsyncParserOptions>,
+    render_options: &prelude::render::RenderOptions,
+) -> Result<String, Error> {
+    let root = async_parse_with_options(input, parser_options).await?;
+    Ok(root.element.render(render_options)?)
+}
+
+#[cfg(feature = "json")]
+/// Function to serialize a [`Mjml`](crate::mjml::Mjml) element into its json
+/// representation. This is useful to cache a parsed template and avoid parsing
+/// it again.
+///
+/// ```rust
+/// # #[cfg(feature = "parse")]
+/// # {
+/// let root = mrml::parse("<mjml><mj-body /></mjml>").expect("parse template");
+/// let json = mrml::to_json(&root.element).expect("serialize template");
+/// assert_eq!(json, r#"{"type":"mjml","children":[{"type":"mj-body"}]}"#);
+/// # }
+/// ```
+pub fn to_json(element: &mjml::Mjml) -> Result<String, serde_json::Error> {
+    serde_json::to_string(element)
+}
+
+#[cfg(feature = "json")]
+/// Function to deserialize a [`Mjml`](crate::mjml::Mjml) element from its json
+/// representation, as generated by [`to_json`](crate::to_json).
+///
+/// ```rust
+/// let json = r#"{"type":"mjml","children":[{"type":"mj-body"}]}"#;
+/// match mrml::from_json(json) {
+///     Ok(_) => println!("Success!"),
+///     Err(err) => eprintln!("Something went wrong: {err:?}"),
+/// }
+/// ```
+pub fn from_json<T: AsRef<str>>(input: T) -> Result<mjml::Mjml, serde_json::Error> {
+    serde_json::from_str(input.as_ref())
+}
+
 #[cfg(all(test, feature = "parse"))]
 mod tests {
     #[test]
@@ -293,4 +508,199 @@ mod tests {
         let _ =
             crate::parse_with_options("<mjml><mj-head /><mj-body /></mjml>", &Default::default());
     }
+
+    #[test]
+    fn parse_with_options_exceeding_limits() {
+        use crate::prelude::parser::{Error, Limit, ParserOptions};
+
+        let template = "<mjml><mj-body><mj-section><mj-column /></mj-section></mj-body></mjml>";
+        let options = ParserOptions {
+            max_depth: 3,
+            ..Default::default()
+        };
+        let err = crate::parse_with_options(template, &options).err().unwrap();
+        assert!(matches!(
+            err,
+            Error::LimitExceeded {
+                limit: Limit::Depth,
+                ..
+            }
+        ));
+        let options = ParserOptions {
+            max_elements: 3,
+            ..Default::default()
+        };
+        let err = crate::parse_with_options(template, &options).err().unwrap();
+        assert!(matches!(
+            err,
+            Error::LimitExceeded {
+                limit: Limit::Elements,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_reader() {
+        let reader = std::io::Cursor::new(b"<mjml><mj-head /><mj-body /></mjml>".to_vec());
+        let root = crate::parse_reader(reader, &Default::default()).unwrap();
+        assert!(root.element.head().is_some());
+        assert!(root.element.body().is_some());
+    }
+
+    #[test]
+    fn parse_reader_with_invalid_utf8() {
+        let reader = std::io::Cursor::new(vec![b'<', 0xff, b'>']);
+        let err = crate::parse_reader(reader, &Default::default())
+            .err()
+            .unwrap();
+        assert!(matches!(err, crate::prelude::parser::Error::Io { .. }));
+        assert!(err.origin().is_none());
+    }
+}
+
+#[cfg(all(test, feature = "json", feature = "parse", feature = "render"))]
+mod json_tests {
+    #[test]
+    fn should_render_the_same_after_json_round_trip() {
+        let template = r#"<mjml>
+  <mj-head><mj-title>Hello</mj-title></mj-head>
+  <mj-body>
+    <!-- some comment -->
+    <mj-section>
+      <mj-column>
+        <mj-text font-size="20px">Hello <b>World</b>!</mj-text>
+        <mj-button href="https://example.com">Click</mj-button>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let opts = crate::prelude::render::RenderOptions::default();
+        let root = crate::parse(template).unwrap();
+        let json = crate::to_json(&root.element).unwrap();
+        let restored = crate::from_json(json.as_str()).unwrap();
+        assert_eq!(json, crate::to_json(&restored).unwrap());
+        assert_eq!(
+            root.element.render(&opts).unwrap(),
+            restored.render(&opts).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_fail_with_invalid_json() {
+        assert!(crate::from_json(r#"{"type":"mj-body"}"#).is_err());
+    }
+}
+
+#[cfg(all(test, feature = "parse", feature = "render"))]
+mod html_tests {
+    use crate::prelude::parser::loader::{IncludeLoader, IncludeLoaderError};
+    use crate::prelude::parser::ParserOptions;
+    use crate::prelude::render::RenderOptions;
+
+    /// Loader returning a button for any path, with the path as label.
+    #[derive(Debug, Default)]
+    struct MockIncludeLoader;
+
+    impl MockIncludeLoader {
+        fn fragment(path: &str) -> Result<String, IncludeLoaderError> {
+            if path.ends_with(".mjml") {
+                Ok(format!("<mj-button>{path}</mj-button>"))
+            } else {
+                Err(IncludeLoaderError::not_found(path))
+            }
+        }
+    }
+
+    impl IncludeLoader for MockIncludeLoader {
+        fn resolve(&self, path: &str) -> Result<String, IncludeLoaderError> {
+            Self::fragment(path)
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+    impl crate::prelude::parser::loader::AsyncIncludeLoader for MockIncludeLoader {
+        async fn async_resolve(&self, path: &str) -> Result<String, IncludeLoaderError> {
+            Self::fragment(path)
+        }
+    }
+
+    const TEMPLATE: &str = r#"<mjml><mj-body><mj-include path="first.mjml" /><mj-include path="second.mjml" /></mj-body></mjml>"#;
+
+    #[test]
+    fn should_render_with_includes() {
+        let parser_options = ParserOptions {
+            include_loader: Box::new(MockIncludeLoader),
+            ..Default::default()
+        };
+        let html = crate::to_html(TEMPLATE, &parser_options, &RenderOptions::default()).unwrap();
+        assert!(html.contains("first.mjml"));
+        assert!(html.contains("second.mjml"));
+    }
+
+    #[test]
+    fn should_fail_with_missing_include() {
+        let parser_options = ParserOptions {
+            include_loader: Box::new(MockIncludeLoader),
+            ..Default::default()
+        };
+        let template = r#"<mjml><mj-body><mj-include path="missing.html" /></mj-body></mjml>"#;
+        let err = crate::to_html(template, &parser_options, &RenderOptions::default()).unwrap_err();
+        assert!(matches!(err, crate::Error::Parser(_)));
+    }
+
+    #[test]
+    fn should_write_html() {
+        let parser_options = ParserOptions {
+            include_loader: Box::new(MockIncludeLoader),
+            ..Default::default()
+        };
+        let render_options = RenderOptions::default();
+        let mut output = Vec::new();
+        crate::write_html(TEMPLATE, &parser_options, &render_options, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            crate::to_html(TEMPLATE, &parser_options, &render_options).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_fail_writing_html_with_full_writer() {
+        let parser_options = ParserOptions {
+            include_loader: Box::new(MockIncludeLoader),
+            ..Default::default()
+        };
+        let mut buffer = [0u8; 16];
+        let mut output = std::io::Cursor::new(&mut buffer[..]);
+        let err = crate::write_html(
+            TEMPLATE,
+            &parser_options,
+            &RenderOptions::default(),
+            &mut output,
+        )
+        .unwrap_err();
+        assert!(matches!(err, crate::Error::Io(_)));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_render_with_async_includes() {
+        let parser_options = std::sync::Arc::new(crate::prelude::parser::AsyncParserOptions {
+            include_loader: Box::new(MockIncludeLoader),
+            ..Default::default()
+        });
+        let html = crate::async_to_html(TEMPLATE, parser_options, &RenderOptions::default())
+            .await
+            .unwrap();
+        let parser_options = ParserOptions {
+            include_loader: Box::new(MockIncludeLoader),
+            ..Default::default()
+        };
+        assert_eq!(
+            html,
+            crate::to_html(TEMPLATE, &parser_options, &RenderOptions::default()).unwrap()
+        );
+    }
 }
@@ -24,10 +24,11 @@ impl ParseChildren<Vec<MjAccordionChild>> for MrmlParser<'_> {
                             self.parse(cursor, inner.local)?,
                         ));
                     } else {
-                        return Err(Error::UnexpectedElement {
+                        cursor.skip_unexpected_element(Error::UnexpectedElement {
                             origin: cursor.origin(),
                             position: inner.span.into(),
-                        });
+                            location: cursor.location(inner.span),
+                        })?;
                     }
                 }
                 MrmlToken::ElementClose(inner) => {
@@ -38,6 +39,7 @@ impl ParseChildren<Vec<MjAccordionChild>> for MrmlParser<'_> {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
@@ -68,10 +70,11 @@ impl AsyncParseChildren<Vec<MjAccordionChild>> for AsyncMrmlParser {
                             self.async_parse(cursor, inner.local).await?,
                         ));
                     } else {
-                        return Err(Error::UnexpectedElement {
+                        cursor.skip_unexpected_element(Error::UnexpectedElement {
                             origin: cursor.origin(),
                             position: inner.span.into(),
-                        });
+                            location: cursor.location(inner.span),
+                        })?;
                     }
                 }
                 MrmlToken::ElementClose(inner) => {
@@ -82,6 +85,7 @@ impl AsyncParseChildren<Vec<MjAccordionChild>> for AsyncMrmlParser {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
@@ -116,13 +120,13 @@ mod tests {
         should_error_with_text,
         MjAccordion,
         "<mj-accordion>Hello</mj-accordion>",
-        "UnexpectedToken { origin: Root, position: Span { start: 14, end: 19 } }"
+        "UnexpectedToken { origin: Root, position: Span { start: 14, end: 19 }, location: Location { offset: 14, line: 1, column: 15 } }"
     );
 
     crate::should_not_sync_parse!(
         should_error_with_unknown_element,
         MjAccordion,
         "<mj-accordion><span /></mj-accordion>",
-        "UnexpectedElement { origin: Root, position: Span { start: 14, end: 19 } }"
+        "UnexpectedElement { origin: Root, position: Span { start: 14, end: 19 }, location: Location { offset: 14, line: 1, column: 15 } }"
     );
 }
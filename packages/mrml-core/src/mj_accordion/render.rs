@@ -151,4 +151,97 @@ mod tests {
     crate::should_render!(font_padding, "mj-accordion-font-padding");
     crate::should_render!(icon, "mj-accordion-icon");
     crate::should_render!(other, "mj-accordion-other");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_toggle_for_each_element() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-accordion icon-wrapped-url="https://example.com/more.png" icon-unwrapped-url="https://example.com/less.png">
+          <mj-accordion-element>
+            <mj-accordion-title>First</mj-accordion-title>
+            <mj-accordion-text>One</mj-accordion-text>
+          </mj-accordion-element>
+          <mj-accordion-element icon-wrapped-url="https://example.com/plus.png" font-family="Georgia">
+            <mj-accordion-title>Second</mj-accordion-title>
+            <mj-accordion-text>Two</mj-accordion-text>
+          </mj-accordion-element>
+        </mj-accordion>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert_eq!(
+            result
+                .matches(r#"<!--[if !mso | IE]><!--><input type="checkbox" class="mj-accordion-checkbox" style="display:none;" /><!--<![endif]-->"#)
+                .count(),
+            2
+        );
+        assert!(result.contains(r#"<label class="mj-accordion-element" style="font-size:13px;">"#));
+        assert!(result.contains(
+            r#"<label class="mj-accordion-element" style="font-size:13px;font-family:Georgia;">"#
+        ));
+        assert!(result.contains(
+            r#"<img src="https://example.com/more.png" alt="+" class="mj-accordion-more""#
+        ));
+        assert!(result.contains(
+            r#"<img src="https://example.com/plus.png" alt="+" class="mj-accordion-more""#
+        ));
+        assert_eq!(
+            result
+                .matches(
+                    r#"<img src="https://example.com/less.png" alt="-" class="mj-accordion-less""#
+                )
+                .count(),
+            2
+        );
+        // the toggle rules are only injected once
+        assert_eq!(
+            result
+                .matches("input.mj-accordion-checkbox:checked+* .mj-accordion-content { display: block; }")
+                .count(),
+            1
+        );
+        assert!(result.contains(
+            "input.mj-accordion-checkbox:checked+* .mj-accordion-less { display: block !important; }"
+        ));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_add_style_once() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-accordion>
+          <mj-accordion-element>
+            <mj-accordion-title>First</mj-accordion-title>
+            <mj-accordion-text>First text</mj-accordion-text>
+          </mj-accordion-element>
+        </mj-accordion>
+        <mj-accordion>
+          <mj-accordion-element>
+            <mj-accordion-title>Second</mj-accordion-title>
+            <mj-accordion-text>Second text</mj-accordion-text>
+          </mj-accordion-element>
+        </mj-accordion>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert_eq!(
+            result
+                .matches("noinput.mj-accordion-checkbox { display: block! important; }")
+                .count(),
+            1
+        );
+        assert_eq!(result.matches("@goodbye { @gmail }").count(), 1);
+    }
 }
@@ -25,10 +25,11 @@ impl ParseChildren<MjAccordionElementChildren> for MrmlParser<'_> {
                         result.title = Some(self.parse(cursor, inner.local)?);
                     }
                     _ => {
-                        return Err(Error::UnexpectedElement {
+                        cursor.skip_unexpected_element(Error::UnexpectedElement {
                             origin: cursor.origin(),
                             position: inner.span.into(),
-                        });
+                            location: cursor.location(inner.span),
+                        })?;
                     }
                 },
                 MrmlToken::ElementClose(inner) => {
@@ -39,6 +40,7 @@ impl ParseChildren<MjAccordionElementChildren> for MrmlParser<'_> {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -67,10 +69,11 @@ impl AsyncParseChildren<MjAccordionElementChildren> for AsyncMrmlParser {
                         result.title = Some(self.async_parse(cursor, inner.local).await?);
                     }
                     _ => {
-                        return Err(Error::UnexpectedElement {
+                        cursor.skip_unexpected_element(Error::UnexpectedElement {
                             origin: cursor.origin(),
                             position: inner.span.into(),
-                        });
+                            location: cursor.location(inner.span),
+                        })?;
                     }
                 },
                 MrmlToken::ElementClose(inner) => {
@@ -81,6 +84,7 @@ impl AsyncParseChildren<MjAccordionElementChildren> for AsyncMrmlParser {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -102,13 +106,13 @@ mod tests {
         should_error_with_unknown_child,
         MjAccordionElement,
         "<mj-accordion-element><span /></mj-accordion-element>",
-        "UnexpectedElement { origin: Root, position: Span { start: 22, end: 27 } }"
+        "UnexpectedElement { origin: Root, position: Span { start: 22, end: 27 }, location: Location { offset: 22, line: 1, column: 23 } }"
     );
 
     crate::should_not_sync_parse!(
         should_error_with_comment,
         MjAccordionElement,
         "<mj-accordion-element><!-- comment --></mj-accordion-element>",
-        "UnexpectedToken { origin: Root, position: Span { start: 22, end: 38 } }"
+        "UnexpectedToken { origin: Root, position: Span { start: 22, end: 38 }, location: Location { offset: 22, line: 1, column: 23 } }"
     );
 }
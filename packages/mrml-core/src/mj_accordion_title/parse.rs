@@ -9,7 +9,7 @@ fn parse_children(cursor: &mut MrmlCursor<'_>) -> Result<Vec<Text>, Error> {
 
     while let Some(item) = cursor.next_text()? {
         if !item.text.trim().is_empty() {
-            result.push(Text::from(item.text.as_str()));
+            result.push(Text::from(cursor.text_content(item.text.as_str())));
         }
     }
 
@@ -57,6 +57,7 @@ impl ParseChildren<Vec<MjAttributesChild>> for MrmlParser<'_> {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
@@ -87,6 +88,7 @@ impl AsyncParseChildren<Vec<MjAttributesChild>> for AsyncMrmlParser {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
@@ -17,6 +17,7 @@ fn parse<'a>(cursor: &mut MrmlCursor<'a>, tag: StrSpan<'a>) -> Result<MjAttribut
                 name: "name",
                 origin: cursor.origin(),
                 position: tag.into(),
+                location: cursor.location(tag),
             })?;
     let attributes = MjAttributesClassAttributes { name, others };
 
@@ -64,12 +65,12 @@ mod tests {
         should_have_name,
         MjAttributesClass,
         r#"<mj-class color="red" />"#,
-        "MissingAttribute { name: \"name\", origin: Root, position: Span { start: 1, end: 9 } }"
+        "MissingAttribute { name: \"name\", origin: Root, position: Span { start: 1, end: 9 }, location: Location { offset: 1, line: 1, column: 2 } }"
     );
     crate::should_not_sync_parse!(
         should_close,
         MjAttributesClass,
         r#"<mj-class name="div" color="red"><whatever>"#,
-        "UnexpectedToken { origin: Root, position: Span { start: 33, end: 42 } }"
+        "UnexpectedToken { origin: Root, position: Span { start: 33, end: 42 }, location: Location { offset: 33, line: 1, column: 34 } }"
     );
 }
@@ -19,6 +19,7 @@ use crate::mj_wrapper::MjWrapper;
 use crate::node::Node;
 #[cfg(feature = "render")]
 use crate::prelude::render::{Render, RenderContext, Renderable};
+use crate::prelude::AttributeMap;
 use crate::text::Text;
 
 #[derive(Clone, Debug)]
@@ -48,6 +49,58 @@ pub enum MjBodyChild {
     Text(Text),
 }
 
+impl MjBodyChild {
+    /// Name of the element, `None` for comments and texts.
+    pub fn tag_name(&self) -> Option<&str> {
+        match self {
+            Self::Comment(_) | Self::Text(_) => None,
+            Self::MjAccordion(_) => Some(crate::mj_accordion::NAME),
+            Self::MjButton(_) => Some(crate::mj_button::NAME),
+            Self::MjCarousel(_) => Some(crate::mj_carousel::NAME),
+            Self::MjColumn(_) => Some(crate::mj_column::NAME),
+            Self::MjDivider(_) => Some(crate::mj_divider::NAME),
+            Self::MjGroup(_) => Some(crate::mj_group::NAME),
+            Self::MjHero(_) => Some(crate::mj_hero::NAME),
+            Self::MjInclude(_) => Some(crate::mj_include::NAME),
+            Self::MjImage(_) => Some(crate::mj_image::NAME),
+            Self::MjNavbar(_) => Some(crate::mj_navbar::NAME),
+            Self::MjRaw(_) => Some(crate::mj_raw::NAME),
+            Self::MjSection(_) => Some(crate::mj_section::NAME),
+            Self::MjSocial(_) => Some(crate::mj_social::NAME),
+            Self::MjSpacer(_) => Some(crate::mj_spacer::NAME),
+            Self::MjTable(_) => Some(crate::mj_table::NAME),
+            Self::MjText(_) => Some(crate::mj_text::NAME),
+            Self::MjWrapper(_) => Some(crate::mj_wrapper::NAME),
+            Self::Node(elt) => Some(elt.tag.as_str()),
+        }
+    }
+
+    /// Attributes defined on the element in the template, `None` for the
+    /// comments, the texts and the elements without regular attributes like
+    /// `mj-raw` or `mj-include`.
+    pub fn attributes(&self) -> Option<&AttributeMap> {
+        match self {
+            Self::Comment(_) | Self::Text(_) | Self::MjInclude(_) | Self::MjRaw(_) => None,
+            Self::MjAccordion(elt) => Some(&elt.attributes),
+            Self::MjButton(elt) => Some(&elt.attributes),
+            Self::MjCarousel(elt) => Some(&elt.attributes),
+            Self::MjColumn(elt) => Some(&elt.attributes),
+            Self::MjDivider(elt) => Some(&elt.attributes),
+            Self::MjGroup(elt) => Some(&elt.attributes),
+            Self::MjHero(elt) => Some(&elt.attributes),
+            Self::MjImage(elt) => Some(&elt.attributes),
+            Self::MjNavbar(elt) => Some(&elt.attributes),
+            Self::MjSection(elt) => Some(&elt.attributes),
+            Self::MjSocial(elt) => Some(&elt.attributes),
+            Self::MjSpacer(elt) => Some(&elt.attributes),
+            Self::MjTable(elt) => Some(&elt.attributes),
+            Self::MjText(elt) => Some(&elt.attributes),
+            Self::MjWrapper(elt) => Some(&elt.attributes),
+            Self::Node(elt) => Some(&elt.attributes),
+        }
+    }
+}
+
 #[cfg(feature = "render")]
 impl<'render, 'root: 'render> Renderable<'render, 'root> for MjBodyChild {
     fn is_raw(&self) -> bool {
@@ -8,6 +8,9 @@ mod print;
 #[cfg(feature = "render")]
 mod render;
 
+#[cfg(feature = "parse")]
+pub(crate) use parse::check_image_alt;
+
 use std::marker::PhantomData;
 
 pub use children::MjBodyChild;
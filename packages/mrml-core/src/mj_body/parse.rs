@@ -9,7 +9,7 @@ use crate::mj_column::NAME as MJ_COLUMN;
 use crate::mj_divider::NAME as MJ_DIVIDER;
 use crate::mj_group::NAME as MJ_GROUP;
 use crate::mj_hero::NAME as MJ_HERO;
-use crate::mj_image::NAME as MJ_IMAGE;
+use crate::mj_image::{MjImage, NAME as MJ_IMAGE};
 use crate::mj_include::NAME as MJ_INCLUDE;
 use crate::mj_navbar::NAME as MJ_NAVBAR;
 use crate::mj_raw::NAME as MJ_RAW;
@@ -23,6 +23,7 @@ use crate::node::Node;
 use crate::prelude::is_void_element;
 use crate::prelude::parser::{
     parse_attributes_map, Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement,
+    WarningKind,
 };
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
@@ -87,6 +88,14 @@ impl AsyncParseElement<Node<MjBodyChild>> for AsyncMrmlParser {
     }
 }
 
+/// Warns when an image has no `alt` attribute, an empty one being expected for
+/// the decorative images.
+pub(crate) fn check_image_alt(cursor: &mut MrmlCursor<'_>, image: &MjImage, span: StrSpan<'_>) {
+    if !image.attributes.contains_key("alt") {
+        cursor.add_warning(WarningKind::MissingAlt, span);
+    }
+}
+
 impl ParseElement<MjBodyChild> for MrmlParser<'_> {
     fn parse<'a>(
         &self,
@@ -101,7 +110,11 @@ impl ParseElement<MjBodyChild> for MrmlParser<'_> {
             MJ_DIVIDER => Ok(MjBodyChild::MjDivider(self.parse(cursor, tag)?)),
             MJ_GROUP => Ok(MjBodyChild::MjGroup(self.parse(cursor, tag)?)),
             MJ_HERO => Ok(MjBodyChild::MjHero(self.parse(cursor, tag)?)),
-            MJ_IMAGE => Ok(MjBodyChild::MjImage(self.parse(cursor, tag)?)),
+            MJ_IMAGE => {
+                let image = self.parse(cursor, tag)?;
+                check_image_alt(cursor, &image, tag);
+                Ok(MjBodyChild::MjImage(image))
+            }
             MJ_INCLUDE => Ok(MjBodyChild::MjInclude(self.parse(cursor, tag)?)),
             MJ_NAVBAR => Ok(MjBodyChild::MjNavbar(self.parse(cursor, tag)?)),
             MJ_RAW => Ok(MjBodyChild::MjRaw(self.parse(cursor, tag)?)),
@@ -111,7 +124,10 @@ impl ParseElement<MjBodyChild> for MrmlParser<'_> {
             MJ_TABLE => Ok(MjBodyChild::MjTable(self.parse(cursor, tag)?)),
             MJ_TEXT => Ok(MjBodyChild::MjText(self.parse(cursor, tag)?)),
             MJ_WRAPPER => Ok(MjBodyChild::MjWrapper(self.parse(cursor, tag)?)),
-            _ => Ok(MjBodyChild::Node(self.parse(cursor, tag)?)),
+            _ => {
+                let node: Node<MjBodyChild> = self.parse(cursor, tag)?;
+                Ok(self.options.custom_elements.build(node))
+            }
         }
     }
 }
@@ -137,7 +153,11 @@ impl AsyncParseElement<MjBodyChild> for AsyncMrmlParser {
             MJ_DIVIDER => Ok(MjBodyChild::MjDivider(self.async_parse(cursor, tag).await?)),
             MJ_GROUP => Ok(MjBodyChild::MjGroup(self.async_parse(cursor, tag).await?)),
             MJ_HERO => Ok(MjBodyChild::MjHero(self.async_parse(cursor, tag).await?)),
-            MJ_IMAGE => Ok(MjBodyChild::MjImage(self.async_parse(cursor, tag).await?)),
+            MJ_IMAGE => {
+                let image = self.async_parse(cursor, tag).await?;
+                check_image_alt(cursor, &image, tag);
+                Ok(MjBodyChild::MjImage(image))
+            }
             MJ_INCLUDE => Ok(MjBodyChild::MjInclude(self.async_parse(cursor, tag).await?)),
             MJ_NAVBAR => Ok(MjBodyChild::MjNavbar(self.async_parse(cursor, tag).await?)),
             MJ_RAW => Ok(MjBodyChild::MjRaw(self.async_parse(cursor, tag).await?)),
@@ -147,7 +167,10 @@ impl AsyncParseElement<MjBodyChild> for AsyncMrmlParser {
             MJ_TABLE => Ok(MjBodyChild::MjTable(self.async_parse(cursor, tag).await?)),
             MJ_TEXT => Ok(MjBodyChild::MjText(self.async_parse(cursor, tag).await?)),
             MJ_WRAPPER => Ok(MjBodyChild::MjWrapper(self.async_parse(cursor, tag).await?)),
-            _ => Ok(MjBodyChild::Node(self.async_parse(cursor, tag).await?)),
+            _ => {
+                let node: Node<MjBodyChild> = self.async_parse(cursor, tag).await?;
+                Ok(self.options.custom_elements.build(node))
+            }
         }
     }
 }
@@ -161,7 +184,9 @@ impl ParseChildren<Vec<MjBodyChild>> for MrmlParser<'_> {
                     result.push(MjBodyChild::Comment(Comment::from(inner.text.as_str())));
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjBodyChild::Text(Text::from(inner.text.as_str())));
+                    result.push(MjBodyChild::Text(Text::from(
+                        cursor.text_content(inner.text.as_str()),
+                    )));
                 }
                 MrmlToken::ElementStart(inner) => {
                     result.push(self.parse(cursor, inner.local)?);
@@ -174,6 +199,7 @@ impl ParseChildren<Vec<MjBodyChild>> for MrmlParser<'_> {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -197,7 +223,9 @@ impl AsyncParseChildren<Vec<MjBodyChild>> for AsyncMrmlParser {
                     result.push(MjBodyChild::Comment(Comment::from(inner.text.as_str())));
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjBodyChild::Text(Text::from(inner.text.as_str())));
+                    result.push(MjBodyChild::Text(Text::from(
+                        cursor.text_content(inner.text.as_str()),
+                    )));
                 }
                 MrmlToken::ElementStart(inner) => {
                     result.push(self.async_parse(cursor, inner.local).await?);
@@ -210,6 +238,7 @@ impl AsyncParseChildren<Vec<MjBodyChild>> for AsyncMrmlParser {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -1,7 +1,9 @@
 use std::convert::TryFrom;
 
-use super::MjBody;
+use super::{MjBody, MjBodyChild};
 use crate::helper::size::Pixel;
+use crate::mj_head::MjHead;
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl<'root> Renderer<'root, MjBody, ()> {
@@ -10,14 +12,15 @@ impl<'root> Renderer<'root, MjBody, ()> {
             .and_then(|value| Pixel::try_from(value).ok())
     }
 
-    fn get_body_tag(&self) -> Tag {
+    fn get_body_tag(&self) -> Tag<'_> {
         self.set_body_style(Tag::new("body").add_style("word-spacing", "normal"))
     }
 
-    fn get_content_div_tag(&self) -> Tag {
+    fn get_content_div_tag(&self) -> Tag<'_> {
         self.set_body_style(Tag::new("div"))
             .maybe_add_attribute("class", self.attribute("css-class"))
             .maybe_add_attribute("lang", self.context.header.lang())
+            .maybe_add_attribute("dir", self.context.header.dir())
     }
 
     fn set_body_style<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
@@ -29,6 +32,9 @@ impl<'root> Renderer<'root, MjBody, ()> {
     }
 
     fn render_preview(&self, buf: &mut RenderBuffer) {
+        if !self.context.options.inject_preview_text {
+            return;
+        }
         if let Some(value) = self.context.header.preview() {
             buf.push_str(r#"<div style="display:none;font-size:1px;color:#ffffff;line-height:1px;max-height:0px;max-width:0px;opacity:0;overflow:hidden;">"#);
             buf.push_str(value);
@@ -98,7 +104,169 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjBody {
     }
 }
 
+impl MjBodyChild {
+    /// Renders the element alone, as if it was a child of a default `mj-body`
+    /// of 600px, without the document around it.
+    ///
+    /// The attributes defined in the `mj-attributes` of the provided head are
+    /// applied but nothing is added to the head, so the fonts, the media
+    /// queries and the styles the element depends on are not part of the
+    /// output.
+    pub fn render_with_head(
+        &self,
+        head: Option<&MjHead>,
+        opts: &RenderOptions,
+    ) -> Result<String, Error> {
+        let header = Header::new(head, None, None, opts.breakpoint()?);
+        let context = RenderContext::new(opts, header);
+        let mut cursor = RenderCursor::default();
+        let mut renderer = self.renderer(&context);
+        renderer.set_container_width(Some(Pixel::new(600.0)));
+        renderer.set_siblings(1);
+        renderer.render(&mut cursor)?;
+        Ok(cursor.buffer.into())
+    }
+
+    /// Renders the element alone without any head, see
+    /// [`MjBodyChild::render_with_head`].
+    pub fn render(&self, opts: &RenderOptions) -> Result<String, Error> {
+        self.render_with_head(None, opts)
+    }
+
+    /// Value of the attribute used when rendering the element, once merged
+    /// with the `mj-attributes` of the provided head and the default value of
+    /// the element.
+    pub fn resolved_attribute(&self, head: Option<&MjHead>, name: &str) -> Option<String> {
+        let opts = RenderOptions::default();
+        let header = Header::new(head, None, None, None);
+        let context = RenderContext::new(&opts, header);
+        let renderer = self.renderer(&context);
+        renderer.attribute(name).map(String::from)
+    }
+
+    /// Attributes of the element merged with the `mj-attributes` of the
+    /// provided head, in the order of the template then of the head. The
+    /// default values of the element are only available through
+    /// [`MjBodyChild::resolved_attribute`].
+    pub fn resolved_attributes(&self, head: Option<&MjHead>) -> Map<String, String> {
+        let opts = RenderOptions::default();
+        let header = Header::new(head, None, None, None);
+        let context = RenderContext::new(&opts, header);
+        let renderer = self.renderer(&context);
+        let attributes = self.attributes();
+        let mj_classes = attributes
+            .and_then(|attrs| attrs.get("mj-class"))
+            .and_then(|value| value.as_deref());
+        attributes
+            .into_iter()
+            .flat_map(|attrs| attrs.keys().map(String::as_str))
+            .chain(context.header.attribute_names(self.tag_name(), mj_classes))
+            .filter_map(|name| {
+                renderer
+                    .attribute(name)
+                    .map(|value| (name.to_string(), value.to_string()))
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::mj_body::MjBodyChild;
+    use crate::mj_head::MjHead;
+    use crate::mj_image::MjImage;
+    use crate::prelude::render::RenderOptions;
+
     crate::should_render!(empty, "mj-body");
+
+    #[test]
+    fn should_render_standalone_image() {
+        let mut image = MjImage::default();
+        image
+            .attributes
+            .insert("src".into(), Some("https://example.com/logo.png".into()));
+        let element = MjBodyChild::MjImage(image);
+        let output = element.render(&RenderOptions::default()).unwrap();
+        assert!(output.starts_with("<table "));
+        assert!(output.contains(r#"src="https://example.com/logo.png""#));
+        assert!(output.contains(r#"width="550""#));
+        assert!(!output.contains("<body"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_standalone_with_head_attributes() {
+        let head = crate::parse(
+            r#"<mjml><mj-head><mj-attributes><mj-button background-color="red" /></mj-attributes></mj-head></mjml>"#,
+        )
+        .unwrap();
+        let head: Option<&MjHead> = head.element.head();
+        let element = MjBodyChild::MjButton(Default::default());
+        let output = element
+            .render_with_head(head, &RenderOptions::default())
+            .unwrap();
+        assert!(output.contains("background:red;"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_inject_preview_text() {
+        let root = crate::parse(
+            "<mjml><mj-head><mj-preview>Hello preview</mj-preview></mj-head><mj-body /></mjml>",
+        )
+        .unwrap();
+        let hidden = r#"<div style="display:none;font-size:1px;color:#ffffff;line-height:1px;max-height:0px;max-width:0px;opacity:0;overflow:hidden;">Hello preview</div>"#;
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains(hidden));
+
+        let options = RenderOptions::builder()
+            .inject_preview_text(false)
+            .build()
+            .unwrap();
+        let output = root.element.render(&options).unwrap();
+        assert!(!output.contains("Hello preview"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_resolve_button_attributes() {
+        let root = crate::parse(
+            r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <mj-all font-family="Arial" />
+      <mj-button color="red" />
+      <mj-class name="big" font-size="20px" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-button mj-class="big" color="blue">Click</mj-button>
+  </mj-body>
+</mjml>"#,
+        )
+        .unwrap();
+        let head = root.element.head();
+        let button = &root.element.body().unwrap().children[0];
+        assert_eq!(button.attributes().unwrap().len(), 2);
+
+        let attributes = button.resolved_attributes(head);
+        assert_eq!(
+            attributes
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("mj-class", "big"),
+                ("color", "blue"),
+                ("font-family", "Arial"),
+                ("font-size", "20px"),
+            ]
+        );
+        assert!(!attributes.contains_key("href"));
+        assert_eq!(
+            button.resolved_attribute(head, "background-color"),
+            Some("#414141".to_string())
+        );
+        assert_eq!(button.resolved_attribute(head, "href"), None);
+    }
 }
@@ -3,7 +3,7 @@ use htmlparser::StrSpan;
 use super::MjBreakpointAttributes;
 #[cfg(feature = "async")]
 use crate::prelude::parser::AsyncMrmlParser;
-use crate::prelude::parser::{Error, MrmlCursor, MrmlParser, ParseAttributes, WarningKind};
+use crate::prelude::parser::{is_valid_pixel, Error, MrmlCursor, MrmlParser, ParseAttributes};
 
 #[inline]
 fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjBreakpointAttributes, Error> {
@@ -11,10 +11,14 @@ fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjBreakpointAttribute
     while let Some(attr) = cursor.next_attribute()? {
         match (attr.local.as_str(), attr.value) {
             ("width", Some(value)) => {
+                // the media queries are only generated with a pixel breakpoint
+                if !is_valid_pixel(value.as_str()) {
+                    cursor.add_invalid_size(&attr)?;
+                }
                 result.width = value.to_string();
             }
             _ => {
-                cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span);
+                cursor.add_unknown_attribute(super::NAME, &attr)?;
             }
         }
     }
@@ -58,4 +62,36 @@ mod tests {
         r#"<mj-breakpoint whatever="42px" />"#,
         1
     );
+    crate::should_sync_parse!(
+        percent_width,
+        MjBreakpoint,
+        r#"<mj-breakpoint width="50%" />"#,
+        1
+    );
+
+    #[test]
+    fn should_warn_with_invalid_width() {
+        use crate::prelude::parser::WarningKind;
+
+        let template = r#"<mjml><mj-head><mj-breakpoint width="50%" /></mj-head></mjml>"#;
+        let output = crate::parse(template).unwrap();
+        let head = output.element.head().unwrap();
+        assert_eq!(head.breakpoint().unwrap().value(), "50%");
+        assert_eq!(output.warnings.len(), 1);
+        assert!(matches!(output.warnings[0].kind, WarningKind::InvalidSize));
+        assert_eq!(output.warnings[0].span.start, 30);
+    }
+
+    #[test]
+    fn should_fail_with_invalid_width_in_strict_mode() {
+        use crate::prelude::parser::{Error, ParserOptions};
+
+        let template = r#"<mjml><mj-head><mj-breakpoint width="50%" /></mj-head></mjml>"#;
+        let opts = ParserOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let err = crate::parse_with_options(template, &opts).err().unwrap();
+        assert!(matches!(err, Error::InvalidAttribute { .. }));
+    }
 }
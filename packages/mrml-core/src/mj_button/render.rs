@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use super::{MjButton, NAME};
 use crate::helper::size::Pixel;
 use crate::prelude::render::*;
@@ -78,10 +80,12 @@ impl<'root> Renderer<'root, MjButton, ()> {
             .maybe_add_style("font-style", self.attribute("font-style"))
             .maybe_add_style("font-weight", self.attribute("font-weight"))
             .maybe_add_style("line-height", self.attribute("line-height"))
-            .maybe_add_style("line-spacing", self.attribute("line-spacing"))
+            .maybe_add_style("letter-spacing", self.attribute("letter-spacing"))
             .add_style("margin", "0")
             .maybe_add_style("text-decoration", self.attribute("text-decoration"))
             .maybe_add_style("text-transform", self.attribute("text-transform"))
+            .maybe_add_style("word-break", self.attribute("word-break"))
+            .maybe_add_style("overflow-wrap", self.attribute("overflow-wrap"))
             .maybe_add_style("padding", self.attribute("inner-padding"))
             .add_style("mso-padding-alt", "0px")
             .maybe_add_style("border-radius", self.attribute("border-radius"))
@@ -138,15 +142,26 @@ impl<'root> Render<'root> for Renderer<'root, MjButton, ()> {
             .maybe_add_attribute("bgcolor", self.attribute("background-color"))
             .add_attribute("role", "presentation")
             .maybe_add_attribute("valign", self.attribute("vertical-align"));
-        let link = Tag::new(self.attribute("href").map(|_| "a").unwrap_or("p"))
-            .maybe_add_attribute("href", self.attribute("href"))
-            .maybe_add_attribute("rel", self.attribute("rel"))
+        let href = self
+            .attribute("href")
+            .map(|href| self.context.options.with_link_params(Cow::Borrowed(href)));
+        let target = href.as_ref().and_then(|_| self.attribute("target"));
+        // a link explicitly opened in a new tab shouldn't get access to the page
+        // opening it, the default target being rendered like mjml does
+        let rel = self.attribute("rel").or_else(|| {
+            href.as_ref()
+                .and_then(|_| self.raw_attribute("target"))
+                .filter(|value| *value == "_blank")
+                .map(|_| "noopener")
+        });
+        let link = Tag::new(href.as_ref().map(|_| "a").unwrap_or("p"))
+            .maybe_add_attribute("href", href)
+            .maybe_add_attribute("rel", rel)
             .maybe_add_attribute("name", self.attribute("name"))
-            .maybe_add_attribute(
-                "target",
-                self.attribute("href")
-                    .and_then(|_v| self.attribute("target")),
-            );
+            .maybe_add_attribute("title", self.attribute("title"))
+            .maybe_add_attribute("role", self.attribute("role"))
+            .maybe_add_attribute("aria-label", self.attribute("aria-label"))
+            .maybe_add_attribute("target", target);
         let link = self.set_style_content(link);
 
         table.render_open(&mut cursor.buffer)?;
@@ -201,4 +216,73 @@ mod tests {
     crate::should_render!(text_transform, "mj-button-text-transform");
     crate::should_render!(vertical_align, "mj-button-vertical-align");
     crate::should_render!(width, "mj-button-width");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_apply_letter_spacing() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-button letter-spacing="2px">Hello World!</mj-button>
+        <mj-button href="https://example.com" letter-spacing="0.5em">Hello <b>World</b>!</mj-button>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let output = root.element.render(&Default::default()).unwrap();
+        assert!(output.contains("line-height:120%;letter-spacing:2px;margin:0;"));
+        assert!(output.contains("line-height:120%;letter-spacing:0.5em;margin:0;"));
+        assert!(output.contains(">Hello <b>World</b>!</a>"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_apply_title() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-button href="https://example.com" title="Go to example">Link</mj-button>
+        <mj-button title="Not a link">Text</mj-button>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let output = root.element.render(&Default::default()).unwrap();
+        let title = output.find(r#" title="Go to example""#).unwrap();
+        let tag = output[..title].rfind('<').unwrap();
+        assert!(output[tag..].starts_with(r#"<a href="https://example.com""#));
+        assert!(output.contains(r#"<p title="Not a link" style="display:inline-block;"#));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_add_accessibility_attributes() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-button href="https://example.com/a" aria-label="Open the offer" role="button" target="_blank">First</mj-button>
+        <mj-button href="https://example.com/b" rel="noopener noreferrer">Second</mj-button>
+        <mj-button href="https://example.com/c" target="_self">Third</mj-button>
+        <mj-button aria-label="Label">Fourth</mj-button>
+        <mj-button href="https://example.com/e">Fifth</mj-button>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let output = root.element.render(&Default::default()).unwrap();
+        assert!(output.contains(r#"<a href="https://example.com/a" rel="noopener" role="button" aria-label="Open the offer" target="_blank""#));
+        assert!(output.contains(
+            r#"<a href="https://example.com/b" rel="noopener noreferrer" target="_blank""#
+        ));
+        assert!(output.contains(r#"<a href="https://example.com/c" target="_self""#));
+        assert!(output.contains(r#"<p aria-label="Label" style="#));
+        assert!(output.contains(r#"<a href="https://example.com/e" target="_blank""#));
+        assert_eq!(output.matches(r#"rel="noopener"#).count(), 2);
+    }
 }
@@ -22,10 +22,11 @@ impl ParseChildren<Vec<MjCarouselChild>> for MrmlParser<'_> {
                             self.parse(cursor, inner.local)?,
                         ));
                     } else {
-                        return Err(Error::UnexpectedElement {
+                        cursor.skip_unexpected_element(Error::UnexpectedElement {
                             origin: cursor.origin(),
                             position: inner.span.into(),
-                        });
+                            location: cursor.location(inner.span),
+                        })?;
                     }
                 }
                 MrmlToken::ElementClose(inner) => {
@@ -36,6 +37,7 @@ impl ParseChildren<Vec<MjCarouselChild>> for MrmlParser<'_> {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
@@ -64,10 +66,11 @@ impl AsyncParseChildren<Vec<MjCarouselChild>> for AsyncMrmlParser {
                             self.async_parse(cursor, inner.local).await?,
                         ));
                     } else {
-                        return Err(Error::UnexpectedElement {
+                        cursor.skip_unexpected_element(Error::UnexpectedElement {
                             origin: cursor.origin(),
                             position: inner.span.into(),
-                        });
+                            location: cursor.location(inner.span),
+                        })?;
                     }
                 }
                 MrmlToken::ElementClose(inner) => {
@@ -78,6 +81,7 @@ impl AsyncParseChildren<Vec<MjCarouselChild>> for AsyncMrmlParser {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
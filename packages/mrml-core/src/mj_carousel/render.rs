@@ -506,4 +506,64 @@ mod tests {
     crate::should_render!(icon, "mj-carousel-icon");
     crate::should_render!(tb, "mj-carousel-tb");
     crate::should_render!(thumbnails, "mj-carousel-thumbnails");
+
+    #[cfg(feature = "parse")]
+    fn render(thumbnails: &str) -> String {
+        let template = format!(
+            r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-carousel thumbnails="{thumbnails}" tb-width="50px">
+          <mj-carousel-image src="https://example.com/1.png" href="https://example.com/1" alt="one" />
+          <mj-carousel-image src="https://example.com/2.png" alt="two" />
+          <mj-carousel-image src="https://example.com/3.png" alt="three" />
+        </mj-carousel>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#
+        );
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        root.element.render(&Default::default()).unwrap()
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_radio_for_each_image() {
+        let result = render("visible");
+        assert_eq!(
+            result
+                .matches(r#"type="radio" name="mj-carousel-radio-00000000""#)
+                .count(),
+            3
+        );
+        assert!(result.contains(r#"<input checked="checked" type="radio""#));
+        for index in 1..=3 {
+            assert!(result.contains(&format!(r#"id="mj-carousel-00000000-radio-{index}""#)));
+            assert!(result.contains(&format!(
+                r#"class="mj-carousel-image mj-carousel-image-{index}""#
+            )));
+            assert!(result.contains(&format!(
+                r#"class="mj-carousel-thumbnail mj-carousel-00000000-thumbnail mj-carousel-00000000-thumbnail-{index}""#
+            )));
+            assert!(result.contains(&format!(
+                r#"<label for="mj-carousel-00000000-radio-{index}" class="mj-carousel-next mj-carousel-next-{index}">"#
+            )));
+        }
+        assert!(result.contains(r#"<div class="mj-carousel">"#));
+        assert!(result.contains(r#"<div class="mj-carousel-images">"#));
+        assert!(result.contains("overflow:hidden;width:50px;"));
+        assert!(
+            result.contains(".mj-carousel-00000000-icons-cell { display: table-cell !important;")
+        );
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_not_render_hidden_thumbnails() {
+        let result = render("hidden");
+        assert_eq!(result.matches(r#"type="radio""#).count(), 3);
+        assert!(!result.contains(r#"class="mj-carousel-thumbnail "#));
+    }
 }
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use super::{MjCarouselImage, NAME};
 use crate::helper::size::Pixel;
 use crate::prelude::hash::Map;
@@ -94,7 +96,8 @@ impl<'root> Renderer<'root, MjCarouselImage, MjCarouselImageExtra<'root>> {
             .maybe_add_attribute(
                 "src",
                 self.attribute("thumbnails-src")
-                    .or_else(|| self.attribute("src")),
+                    .or_else(|| self.attribute("src"))
+                    .map(|src| self.context.options.with_image_base_url(Cow::Borrowed(src))),
             )
             .maybe_add_attribute("alt", self.attribute("alt"))
             .maybe_add_attribute(
@@ -199,7 +202,11 @@ impl<'root> Render<'root> for Renderer<'root, MjCarouselImage, MjCarouselImageEx
             .set_style_images_img(Tag::new("img"))
             .add_attribute("border", "0")
             .maybe_add_attribute("alt", self.attribute("alt"))
-            .maybe_add_attribute("src", self.attribute("src"))
+            .maybe_add_attribute(
+                "src",
+                self.attribute("src")
+                    .map(|src| self.context.options.with_image_base_url(Cow::Borrowed(src))),
+            )
             .maybe_add_attribute("title", self.attribute("title"))
             .maybe_add_attribute(
                 "width",
@@ -10,8 +10,16 @@ mod print;
 #[cfg(feature = "render")]
 mod render;
 
+#[cfg(feature = "render")]
+pub(crate) use render::auto_width;
+
 pub const NAME: &str = "mj-column";
 
+/// Class added to the sections containing some columns with a `mobile-order`
+/// attribute, to reorder them when they are stacked below the breakpoint.
+#[cfg(feature = "render")]
+pub(crate) const MOBILE_REORDER_CLASS: &str = "mj-mobile-reorder";
+
 pub struct MjColumnTag;
 
 impl StaticTag for MjColumnTag {
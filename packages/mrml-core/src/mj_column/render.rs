@@ -1,10 +1,48 @@
-use super::{MjColumn, NAME};
+use super::{MjColumn, MOBILE_REORDER_CLASS, NAME};
 use crate::helper::size::{Pixel, Size};
+use crate::mj_body::MjBodyChild;
 use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
+/// Computes the width of the columns without a `width` attribute, sharing
+/// what's left of the container once the widths of their siblings are
+/// subtracted. Returns `None` when no sibling defines its width, the columns
+/// being evenly split, or when an element other than a column doesn't
+/// define it.
+pub(crate) fn auto_width<'root>(
+    context: &'root RenderContext<'root>,
+    children: &'root [MjBodyChild],
+    container_width: Option<Pixel>,
+) -> Option<Pixel> {
+    let container_width = container_width?;
+    let mut explicit = 0.0;
+    let mut has_explicit = false;
+    let mut automatic = 0;
+    for child in children.iter().filter(|child| !child.is_raw()) {
+        let renderer = child.renderer(context);
+        match renderer
+            .attribute_as_size("width")
+            .filter(|size| !size.is_font_relative())
+            .and_then(|size| size.to_pixel(Size::Pixel(container_width)))
+        {
+            Some(width) => {
+                explicit += width.value();
+                has_explicit = true;
+            }
+            None if matches!(child, MjBodyChild::MjColumn(_)) => automatic += 1,
+            None => return None,
+        }
+    }
+    if !has_explicit || automatic == 0 {
+        return None;
+    }
+    let remaining = (container_width.value() - explicit).max(0.0);
+    Some(Pixel::new(remaining / automatic as f32))
+}
+
 struct MjColumnExtra<'a> {
     attributes: Map<&'a str, &'a str>,
+    auto_width: Option<Pixel>,
 }
 
 impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
@@ -12,7 +50,6 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         let parent_width = self.container_width.as_ref()?;
         let non_raw_siblings = self.non_raw_siblings();
         let borders = self.get_border_horizontal();
-        let paddings = self.get_padding_horizontal();
         let inner_border_left = self
             .get_inner_border_left()
             .map(|size| size.value())
@@ -22,18 +59,17 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
             .map(|size| size.value())
             .unwrap_or(0.0);
         let inner_borders = inner_border_left + inner_border_right;
-        let all_paddings = paddings.value() + borders.value() + inner_borders;
 
         let container_width = self
             .attribute_as_size("width")
-            .unwrap_or_else(|| Size::pixel(parent_width.value() / (non_raw_siblings as f32)));
-        if let Size::Percent(pc) = container_width {
-            Some(Pixel::new(
-                (parent_width.value() * pc.value() / 100.0) - all_paddings,
-            ))
-        } else {
-            Some(Pixel::new(container_width.value() - all_paddings))
-        }
+            .and_then(|size| size.to_pixel(Size::Pixel(*parent_width)))
+            .map(|size| size.value())
+            .or_else(|| self.extra.auto_width.map(|width| width.value()))
+            .unwrap_or_else(|| parent_width.value() / (non_raw_siblings as f32));
+        // the percent paddings are relative to the width of the column
+        let paddings = self.get_padding_horizontal(&Pixel::new(container_width));
+        let all_paddings = paddings.value() + borders.value() + inner_borders;
+        Some(Pixel::new(container_width - all_paddings))
     }
 
     fn non_raw_siblings(&self) -> usize {
@@ -42,6 +78,14 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
 
     fn get_parsed_width(&self) -> Size {
         self.attribute_as_size("width")
+            .filter(|size| !size.is_font_relative())
+            .or_else(|| {
+                let auto_width = self.extra.auto_width?;
+                let container_width = self.container_width.filter(|width| width.value() > 0.0)?;
+                Some(Size::percent(
+                    auto_width.value() * 100.0 / container_width.value(),
+                ))
+            })
             .unwrap_or_else(|| Size::percent(100.0 / (self.non_raw_siblings() as f32)))
     }
 
@@ -55,6 +99,29 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         (classname.replace('.', "-"), parsed_width)
     }
 
+    /// Position of the column when the columns are stacked, below the
+    /// breakpoint, given by the `mobile-order` attribute.
+    fn get_mobile_order(&self) -> Option<u32> {
+        self.attribute("mobile-order")
+            .and_then(|value| value.trim().parse::<u32>().ok())
+    }
+
+    /// Media query stacking the columns in a flex container, shared by all the
+    /// reordered columns so it's only rendered once in the header.
+    fn render_mobile_reorder_style(&self) -> String {
+        format!(
+            "@media only screen and (max-width:{}) {{ .{MOBILE_REORDER_CLASS} {{ display: flex !important; flex-direction: column !important; }} }}",
+            self.context.header.breakpoint().lower(),
+        )
+    }
+
+    fn render_mobile_order_style(&self, order: u32) -> String {
+        format!(
+            "@media only screen and (max-width:{}) {{ .mj-mobile-order-{order} {{ order: {order} !important; }} }}",
+            self.context.header.breakpoint().lower(),
+        )
+    }
+
     fn get_mobile_width(&self) -> Option<Size> {
         if !self.attribute_exists("mobile-width") {
             return Some(Size::percent(100.0));
@@ -85,12 +152,10 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
     fn get_width_as_pixel(&self) -> String {
         if let Some(ref container_width) = self.container_width {
             let parsed_width = self.get_parsed_width();
-            match parsed_width {
-                Size::Percent(value) => {
-                    Pixel::new(container_width.value() * value.value() / 100.0).to_string()
-                }
-                _ => parsed_width.to_string(),
-            }
+            parsed_width
+                .to_pixel(Size::Pixel(*container_width))
+                .unwrap_or(parsed_width)
+                .to_string()
         } else {
             String::from("100%")
         }
@@ -213,19 +278,8 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
                 renderer.render(cursor)?;
             } else {
                 let tr = Tag::tr();
-                let td = Tag::td()
-                    .maybe_add_style(
-                        "background",
-                        renderer.attribute("container-background-color"),
-                    )
-                    .add_style("font-size", "0px")
-                    .maybe_add_style("padding", renderer.attribute("padding"))
-                    .maybe_add_style("padding-top", renderer.attribute("padding-top"))
-                    .maybe_add_style("padding-right", renderer.attribute("padding-right"))
-                    .maybe_add_style("padding-bottom", renderer.attribute("padding-bottom"))
-                    .maybe_add_style("padding-left", renderer.attribute("padding-left"))
-                    .add_style("word-break", "break-word")
-                    .maybe_add_attribute("align", renderer.attribute("align"))
+                let td = renderer
+                    .container_cell()
                     .maybe_add_attribute("vertical-align", renderer.attribute("vertical-align"))
                     .maybe_add_class(renderer.attribute("css-class"));
 
@@ -247,7 +301,7 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
 impl<'root> Render<'root> for Renderer<'root, MjColumn, MjColumnExtra<'root>> {
     fn default_attribute(&self, name: &str) -> Option<&'static str> {
         match name {
-            "direction" => Some("ltr"),
+            "direction" => Some(self.context.header.direction()),
             "vertical-align" => Some("top"),
             _ => None,
         }
@@ -292,6 +346,10 @@ impl<'root> Render<'root> for Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         self.raw_siblings = value;
     }
 
+    fn set_auto_width(&mut self, width: Option<Pixel>) {
+        self.extra.auto_width = width;
+    }
+
     fn set_style<'a, 't>(&'a self, name: &str, tag: Tag<'t>) -> Tag<'t>
     where
         'root: 'a,
@@ -306,11 +364,19 @@ impl<'root> Render<'root> for Renderer<'root, MjColumn, MjColumnExtra<'root>> {
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let (classname, size) = self.get_column_class();
         cursor.header.add_media_query(classname.clone(), size);
+        let mobile_order = self.get_mobile_order();
+        if let Some(order) = mobile_order {
+            cursor.header.add_style(self.render_mobile_reorder_style());
+            cursor
+                .header
+                .add_style(self.render_mobile_order_style(order));
+        }
 
         let div = self
             .set_style_root_div(Tag::div())
             .add_class("mj-outlook-group-fix")
             .add_class(classname)
+            .maybe_add_class(mobile_order.map(|order| format!("mj-mobile-order-{order}")))
             .maybe_add_class(self.attribute("css-class"));
 
         div.render_open(&mut cursor.buffer)?;
@@ -334,6 +400,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjColumn {
             self,
             MjColumnExtra {
                 attributes: Map::new(),
+                auto_width: None,
             },
         ))
     }
@@ -346,10 +413,208 @@ mod tests {
     crate::should_render!(border_radius, "mj-column-border-radius");
     crate::should_render!(border, "mj-column-border");
     crate::should_render!(class, "mj-column-class");
+    crate::should_render!(class_multiple, "mj-column-class-multiple");
     crate::should_render!(inner_background_color, "mj-column-inner-background-color");
     crate::should_render!(padding, "mj-column-padding");
     crate::should_render!(vertical_align, "mj-column-vertical-align");
     crate::should_render!(width, "mj-column-width");
     // issues
     crate::should_render!(border_issue_466, "mj-column-border-issue-466");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_share_remaining_width() {
+        let render = |width: &str| {
+            let template = format!(
+                r#"<mjml><mj-body><mj-section><mj-column width="{width}"><mj-text>a</mj-text></mj-column><mj-column><mj-text>b</mj-text></mj-column><mj-column><mj-text>c</mj-text></mj-column></mj-section></mj-body></mjml>"#
+            );
+            let root = crate::parse(template).unwrap();
+            root.element.render(&Default::default()).unwrap()
+        };
+
+        let output = render("200px");
+        assert!(output.contains(r#"style="vertical-align:top;width:200px;""#));
+        assert_eq!(
+            output
+                .matches(r#"style="vertical-align:top;width:200px;""#)
+                .count(),
+            3
+        );
+        assert!(output.contains(".mj-column-px-200 { width:200px !important; max-width:200px; }"));
+        assert!(output.contains(
+            ".mj-column-per-33-333332 { width:33.333332% !important; max-width:33.333332%; }"
+        ));
+
+        let output = render("300px");
+        assert_eq!(
+            output
+                .matches(r#"style="vertical-align:top;width:300px;""#)
+                .count(),
+            1
+        );
+        assert_eq!(
+            output
+                .matches(r#"style="vertical-align:top;width:150px;""#)
+                .count(),
+            2
+        );
+        assert!(output.contains(".mj-column-per-25 { width:25% !important; max-width:25%; }"));
+
+        let output = render("50%");
+        assert_eq!(
+            output
+                .matches(r#"style="vertical-align:top;width:300px;""#)
+                .count(),
+            1
+        );
+        assert_eq!(
+            output
+                .matches(r#"style="vertical-align:top;width:150px;""#)
+                .count(),
+            2
+        );
+        assert!(output.contains(".mj-column-per-50 { width:50% !important; max-width:50%; }"));
+        assert!(output.contains(".mj-column-per-25 { width:25% !important; max-width:25%; }"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_align_columns_of_different_heights() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column vertical-align="middle">
+        <mj-text>First</mj-text>
+        <mj-text>Second</mj-text>
+        <mj-text>Third</mj-text>
+      </mj-column>
+      <mj-column vertical-align="middle">
+        <mj-text>Centered</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let output = root.element.render(&Default::default()).unwrap();
+        // the ghost table cell for outlook
+        assert_eq!(
+            output
+                .matches(r#"style="vertical-align:middle;width:300px;""#)
+                .count(),
+            2
+        );
+        // the inline block for the other clients
+        assert_eq!(
+            output
+                .matches("display:inline-block;vertical-align:middle;width:100%;")
+                .count(),
+            2
+        );
+        assert_eq!(
+            output
+                .matches(r#"width="100%" style="vertical-align:middle;""#)
+                .count(),
+            2
+        );
+        assert!(!output.contains("vertical-align:top"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_reorder_columns_on_mobile() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column mobile-order="2"><mj-text>Text</mj-text></mj-column>
+      <mj-column mobile-order="1"><mj-image src="image.png" /></mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let output = root.element.render(&Default::default()).unwrap();
+        assert!(output.contains(r#"<td class="mj-mobile-reorder" style="#));
+        assert!(
+            output.contains(r#"class="mj-outlook-group-fix mj-column-per-50 mj-mobile-order-2""#)
+        );
+        assert!(
+            output.contains(r#"class="mj-outlook-group-fix mj-column-per-50 mj-mobile-order-1""#)
+        );
+        assert!(output.contains(
+            ".mj-mobile-reorder { display: flex !important; flex-direction: column !important; }"
+        ));
+        assert!(output.contains(".mj-mobile-order-1 { order: 1 !important; }"));
+        assert!(output.contains(".mj-mobile-order-2 { order: 2 !important; }"));
+        assert_eq!(output.matches(".mj-mobile-reorder {").count(), 1);
+        assert!(!output.contains("\n                .mj-mobile-"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_reorder_columns_in_group_on_mobile() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-group>
+        <mj-column mobile-order="2"><mj-text>Text</mj-text></mj-column>
+        <mj-column mobile-order="1"><mj-text>Other</mj-text></mj-column>
+      </mj-group>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let output = root.element.render(&Default::default()).unwrap();
+        assert!(output.contains(r#"<td class="mj-mobile-reorder" style="#));
+        assert_eq!(output.matches(".mj-mobile-reorder {").count(), 1);
+    }
+
+    #[test]
+    fn should_ignore_font_relative_width() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let render = |width: &str| {
+            let source = format!(
+                "<mjml><mj-body><mj-section><mj-column{width}><mj-text>a</mj-text></mj-column><mj-column><mj-text>b</mj-text></mj-column></mj-section></mj-body></mjml>"
+            );
+            let root = Mjml::parse(source).unwrap();
+            root.element.render(&RenderOptions::default()).unwrap()
+        };
+        let expected = render("");
+        assert!(expected.contains("mj-column-per-50"));
+        assert_eq!(render(r#" width="10em""#), expected);
+        assert_eq!(render(r#" width="2rem""#), expected);
+    }
+
+    #[test]
+    fn should_deduplicate_css_classes() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r#"<mjml><mj-body><mj-section><mj-column css-class="a b a"><mj-text>Hi</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains(r#"class="a-outlook b-outlook""#));
+        assert!(output.contains(r#"class="mj-outlook-group-fix mj-column-per-100 a b""#));
+    }
+
+    #[test]
+    fn should_resolve_percent_padding() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let render = |padding: &str| {
+            let source = format!(
+                r#"<mjml><mj-body><mj-section><mj-column padding="{padding}"><mj-image src="a.png" /></mj-column></mj-section></mj-body></mjml>"#
+            );
+            let root = Mjml::parse(source).unwrap();
+            root.element.render(&RenderOptions::default()).unwrap()
+        };
+        // 5% of the 600px column on each side, then the 25px padding of the image
+        let output = render("5%");
+        assert!(output.contains(r#"<td style="vertical-align:top;padding:5%;">"#));
+        assert!(output.contains(r#" width="490" "#));
+        let output = render("10px 5% 10px 20px");
+        assert!(output.contains(r#"padding:10px 5% 10px 20px;"#));
+        assert!(output.contains(r#" width="500" "#));
+    }
 }
@@ -3,6 +3,14 @@ use crate::helper::size::{Pixel, Size};
 use crate::prelude::render::*;
 
 impl<'root> Renderer<'root, MjDivider, ()> {
+    fn get_margin(&self) -> &'static str {
+        match self.attribute("align") {
+            Some("left") => "0px",
+            Some("right") => "0px 0px 0px auto",
+            _ => "0px auto",
+        }
+    }
+
     fn set_style_p_without_width<'t>(&self, tag: Tag<'t>) -> Tag<'t> {
         tag.add_style(
             "border-top",
@@ -17,7 +25,7 @@ impl<'root> Renderer<'root, MjDivider, ()> {
             ),
         )
         .add_style("font-size", "1px")
-        .add_style("margin", "0px auto")
+        .add_style("margin", self.get_margin())
     }
     fn set_style_p<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
     where
@@ -35,7 +43,7 @@ impl<'root> Renderer<'root, MjDivider, ()> {
 
     fn get_outlook_width(&self) -> Option<Pixel> {
         let container_width = self.container_width.as_ref()?;
-        let padding_horizontal = self.get_padding_horizontal();
+        let padding_horizontal = self.get_padding_horizontal(container_width);
         let width = self
             .attribute_as_size("width")
             .unwrap_or_else(|| Size::percent(100.0));
@@ -53,7 +61,7 @@ impl<'root> Renderer<'root, MjDivider, ()> {
     fn render_after(&self, buf: &mut RenderBuffer) -> Result<(), Error> {
         let table = self
             .set_style_outlook(Tag::table_presentation())
-            .add_attribute("align", "center")
+            .maybe_add_attribute("align", self.attribute("align"))
             .maybe_add_attribute("width", self.get_outlook_width().map(|v| v.to_string()));
         let tr = Tag::tr();
         let td = Tag::td()
@@ -129,6 +137,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjDivider {
 #[cfg(test)]
 mod tests {
     crate::should_render!(basic, "mj-divider");
+    crate::should_render!(border, "mj-divider-border");
     crate::should_render!(class, "mj-divider-class");
     crate::should_render!(
         container_background_color,
@@ -136,4 +145,42 @@ mod tests {
     );
     crate::should_render!(padding, "mj-divider-padding");
     crate::should_render!(width, "mj-divider-width");
+
+    #[test]
+    fn should_apply_align() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-divider width="50%" />
+        <mj-divider width="50%" align="left" />
+        <mj-divider width="200px" align="right" />
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        for (align, margin, width) in [
+            ("center", "0px auto", "50%"),
+            ("left", "0px", "50%"),
+            ("right", "0px 0px 0px auto", "200px"),
+        ] {
+            assert!(output.contains(&format!(
+                r#"<td align="{align}" style="font-size:0px;padding:10px 25px;word-break:break-word;"><p style="border-top:solid 4px #000000;font-size:1px;margin:{margin};width:{width};">"#
+            )));
+        }
+        for (align, margin, width) in [
+            ("center", "0px auto", "275px"),
+            ("left", "0px", "275px"),
+            ("right", "0px 0px 0px auto", "200px"),
+        ] {
+            assert!(output.contains(&format!(
+                r#"role="presentation" align="{align}" width="{width}" style="border-top:solid 4px #000000;font-size:1px;margin:{margin};width:{width};">"#
+            )));
+        }
+    }
 }
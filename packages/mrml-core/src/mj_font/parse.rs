@@ -3,7 +3,7 @@ use htmlparser::StrSpan;
 use super::MjFontAttributes;
 #[cfg(feature = "async")]
 use crate::prelude::parser::AsyncMrmlParser;
-use crate::prelude::parser::{Error, MrmlCursor, MrmlParser, ParseAttributes, WarningKind};
+use crate::prelude::parser::{Error, MrmlCursor, MrmlParser, ParseAttributes};
 
 #[inline(always)]
 fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjFontAttributes, Error> {
@@ -13,7 +13,7 @@ fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjFontAttributes, Err
         match (attrs.local.as_str(), attrs.value) {
             ("name", Some(value)) => result.name = value.to_string(),
             ("href", Some(value)) => result.href = value.to_string(),
-            _ => cursor.add_warning(WarningKind::UnexpectedAttribute, attrs.span),
+            _ => cursor.add_unknown_attribute(super::NAME, &attrs)?,
         }
     }
 
@@ -9,7 +9,6 @@ impl<'root> Renderer<'root, MjGroup, ()> {
         let parent_width = self.container_width.as_ref()?;
         let non_raw_siblings = self.non_raw_siblings();
         let borders = self.get_border_horizontal();
-        let paddings = self.get_padding_horizontal();
         let inner_border_left = self
             .get_inner_border_left()
             .map(|size| size.value())
@@ -19,16 +18,16 @@ impl<'root> Renderer<'root, MjGroup, ()> {
             .map(|size| size.value())
             .unwrap_or(0.0);
         let inner_borders = inner_border_left + inner_border_right;
-        let all_paddings = paddings.value() + borders.value() + inner_borders;
 
         let container_width = self
             .attribute_as_size("width")
-            .unwrap_or_else(|| Size::pixel(parent_width.value() / (non_raw_siblings as f32)));
-        Some(if let Size::Percent(pc) = container_width {
-            Pixel::new((parent_width.value() * pc.value() / 100.0) - all_paddings)
-        } else {
-            Pixel::new(container_width.value() - all_paddings)
-        })
+            .and_then(|size| size.to_pixel(Size::Pixel(*parent_width)))
+            .map(|size| size.value())
+            .unwrap_or_else(|| parent_width.value() / (non_raw_siblings as f32));
+        // the percent paddings are relative to the width of the column
+        let paddings = self.get_padding_horizontal(&Pixel::new(container_width));
+        let all_paddings = paddings.value() + borders.value() + inner_borders;
+        Some(Pixel::new(container_width - all_paddings))
     }
 
     fn non_raw_siblings(&self) -> usize {
@@ -37,6 +36,7 @@ impl<'root> Renderer<'root, MjGroup, ()> {
 
     fn get_parsed_width(&self) -> Size {
         self.attribute_as_size("width")
+            .filter(|size| !size.is_font_relative())
             .unwrap_or_else(|| Size::percent(100.0 / (self.non_raw_siblings() as f32)))
     }
 
@@ -83,6 +83,8 @@ impl<'root> Renderer<'root, MjGroup, ()> {
             .iter()
             .filter(|item| item.is_raw())
             .count();
+        let auto_width =
+            crate::mj_column::auto_width(self.context(), &self.element.children, current_width);
 
         for (index, child) in self.element.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
@@ -90,6 +92,7 @@ impl<'root> Renderer<'root, MjGroup, ()> {
             renderer.set_siblings(siblings);
             renderer.set_raw_siblings(raw_siblings);
             renderer.set_container_width(current_width);
+            renderer.set_auto_width(auto_width);
             renderer.add_extra_attribute("mobile-width", "mobile-width");
             if child.is_raw() {
                 renderer.render(cursor)?;
@@ -121,7 +124,7 @@ impl<'root> Renderer<'root, MjGroup, ()> {
 impl<'root> Render<'root> for Renderer<'root, MjGroup, ()> {
     fn default_attribute(&self, name: &str) -> Option<&'static str> {
         match name {
-            "direction" => Some("ltr"),
+            "direction" => Some(self.context.header.direction()),
             _ => None,
         }
     }
@@ -222,4 +225,67 @@ mod tests {
     crate::should_render!(direction, "mj-group-direction");
     crate::should_render!(vertical_align, "mj-group-vertical-align");
     crate::should_render!(width, "mj-group-width");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_keep_grouped_columns_inline_on_mobile() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-group>
+        <mj-column><mj-text>First</mj-text></mj-column>
+        <mj-column><mj-text>Second</mj-text></mj-column>
+        <mj-column><mj-text>Third</mj-text></mj-column>
+      </mj-group>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        // the columns have their width in the style attribute, so they don't
+        // fallback to 100% when the desktop media query doesn't apply
+        assert_eq!(
+            result
+                .matches("display:inline-block;vertical-align:top;width:33.333332%;")
+                .count(),
+            3
+        );
+        assert!(result.contains(".mj-column-per-33-333332 { width:33.333332% !important;"));
+        assert!(result.contains(".mj-column-per-100 { width:100% !important;"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_resolve_nested_percent_widths() {
+        let template = r#"<mjml><mj-body><mj-section><mj-group width="50%"><mj-column width="50%"><mj-text>a</mj-text></mj-column><mj-column width="50%"><mj-text>b</mj-text></mj-column></mj-group></mj-section></mj-body></mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert_eq!(result.matches(r#"<td style="width:300px;">"#).count(), 1);
+        assert_eq!(result.matches("vertical-align:top;width:150px;").count(), 2);
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_only_stack_columns_outside_of_groups() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-text>a</mj-text></mj-column><mj-column><mj-text>b</mj-text></mj-column></mj-section><mj-section><mj-group><mj-column><mj-text>c</mj-text></mj-column><mj-column><mj-text>d</mj-text></mj-column></mj-group></mj-section></mj-body></mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        // the column widths are only set above the breakpoint, the columns
+        // stacking below it because of their 100% inline width, while the
+        // grouped ones keep their own width
+        assert!(result.contains("@media only screen and (min-width:480px) { .mj-column-per-100 { width:100% !important; max-width:100%; } .mj-column-per-50 { width:50% !important; max-width:50%; }"));
+        assert!(!result.contains("@media only screen and (max-width:"));
+        let columns = result
+            .split(r#"class="mj-outlook-group-fix mj-column-per-50" style=""#)
+            .skip(1)
+            .map(|item| &item[..item.find('"').unwrap()])
+            .collect::<Vec<_>>();
+        assert_eq!(columns.len(), 4);
+        assert!(columns[..2]
+            .iter()
+            .all(|style| style.ends_with("width:100%;")));
+        assert!(columns[2..]
+            .iter()
+            .all(|style| style.ends_with("width:50%;")));
+    }
 }
@@ -0,0 +1,207 @@
+use super::{MjHead, MjHeadChild};
+use crate::mj_attributes::{MjAttributes, MjAttributesChild};
+use crate::mj_attributes_all::MjAttributesAll;
+use crate::mj_attributes_class::{MjAttributesClass, MjAttributesClassAttributes};
+use crate::mj_attributes_element::MjAttributesElement;
+use crate::mj_breakpoint::{MjBreakpoint, MjBreakpointAttributes};
+use crate::mj_font::{MjFont, MjFontAttributes};
+use crate::prelude::AttributeMap;
+
+/// Builder to create a [`MjHead`] without parsing a template, to render
+/// elements built in rust with
+/// [`MjBodyChild::render_with_head`](crate::mj_body::MjBodyChild::render_with_head).
+///
+/// ```rust
+/// use mrml::mj_head::MjHead;
+///
+/// let head = MjHead::builder()
+///     .breakpoint("320px")
+///     .add_font("Raleway", "https://fonts.googleapis.com/css?family=Raleway")
+///     .add_all_attribute("font-family", "Raleway")
+///     .add_element_attribute("mj-text", "color", "red")
+///     .add_class_attribute("blue", "color", "blue")
+///     .build();
+/// assert_eq!(head.breakpoint().unwrap().value(), "320px");
+/// ```
+#[derive(Debug, Default)]
+pub struct MjHeadBuilder {
+    breakpoint: Option<String>,
+    fonts: Vec<MjFont>,
+    attributes: Vec<MjAttributesChild>,
+}
+
+impl MjHeadBuilder {
+    pub fn breakpoint<V: Into<String>>(mut self, value: V) -> Self {
+        self.breakpoint = Some(value.into());
+        self
+    }
+
+    pub fn add_font<N: Into<String>, H: Into<String>>(mut self, name: N, href: H) -> Self {
+        self.fonts.push(MjFont::new(
+            MjFontAttributes {
+                name: name.into(),
+                href: href.into(),
+            },
+            (),
+        ));
+        self
+    }
+
+    fn all_attributes(&mut self) -> &mut AttributeMap {
+        let index = match self
+            .attributes
+            .iter()
+            .position(|child| matches!(child, MjAttributesChild::MjAttributesAll(_)))
+        {
+            Some(index) => index,
+            None => {
+                self.attributes.push(MjAttributesChild::MjAttributesAll(
+                    MjAttributesAll::default(),
+                ));
+                self.attributes.len() - 1
+            }
+        };
+        match &mut self.attributes[index] {
+            MjAttributesChild::MjAttributesAll(inner) => &mut inner.attributes,
+            _ => unreachable!(),
+        }
+    }
+
+    fn element_attributes(&mut self, element: String) -> &mut AttributeMap {
+        let index = match self.attributes.iter().position(|child| {
+            matches!(child, MjAttributesChild::MjAttributesElement(inner) if inner.name == element)
+        }) {
+            Some(index) => index,
+            None => {
+                self.attributes
+                    .push(MjAttributesChild::MjAttributesElement(
+                        MjAttributesElement::new(element),
+                    ));
+                self.attributes.len() - 1
+            }
+        };
+        match &mut self.attributes[index] {
+            MjAttributesChild::MjAttributesElement(inner) => &mut inner.attributes,
+            _ => unreachable!(),
+        }
+    }
+
+    fn class_attributes(&mut self, class: String) -> &mut AttributeMap {
+        let index = match self.attributes.iter().position(|child| {
+            matches!(child, MjAttributesChild::MjAttributesClass(inner) if inner.attributes.name == class)
+        }) {
+            Some(index) => index,
+            None => {
+                self.attributes
+                    .push(MjAttributesChild::MjAttributesClass(MjAttributesClass::new(
+                        MjAttributesClassAttributes {
+                            name: class,
+                            others: AttributeMap::default(),
+                        },
+                        (),
+                    )));
+                self.attributes.len() - 1
+            }
+        };
+        match &mut self.attributes[index] {
+            MjAttributesChild::MjAttributesClass(inner) => &mut inner.attributes.others,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Default attribute applied to all the elements, like `mj-all`.
+    pub fn add_all_attribute<N: Into<String>, V: Into<String>>(
+        mut self,
+        name: N,
+        value: V,
+    ) -> Self {
+        self.all_attributes()
+            .insert(name.into(), Some(value.into()));
+        self
+    }
+
+    /// Default attribute applied to the elements with the given tag, like
+    /// `mj-text` in `mj-attributes`.
+    pub fn add_element_attribute<E: Into<String>, N: Into<String>, V: Into<String>>(
+        mut self,
+        element: E,
+        name: N,
+        value: V,
+    ) -> Self {
+        self.element_attributes(element.into())
+            .insert(name.into(), Some(value.into()));
+        self
+    }
+
+    /// Attribute applied to the elements using the given `mj-class`.
+    pub fn add_class_attribute<C: Into<String>, N: Into<String>, V: Into<String>>(
+        mut self,
+        class: C,
+        name: N,
+        value: V,
+    ) -> Self {
+        self.class_attributes(class.into())
+            .insert(name.into(), Some(value.into()));
+        self
+    }
+
+    pub fn build(self) -> MjHead {
+        let mut children = Vec::with_capacity(self.fonts.len() + 2);
+        if let Some(width) = self.breakpoint {
+            children.push(MjHeadChild::MjBreakpoint(MjBreakpoint::new(
+                MjBreakpointAttributes { width },
+                (),
+            )));
+        }
+        children.extend(self.fonts.into_iter().map(MjHeadChild::MjFont));
+        if !self.attributes.is_empty() {
+            children.push(MjHeadChild::MjAttributes(MjAttributes::new(
+                (),
+                self.attributes,
+            )));
+        }
+        MjHead::new((), children)
+    }
+}
+
+impl MjHead {
+    pub fn builder() -> MjHeadBuilder {
+        MjHeadBuilder::default()
+    }
+}
+
+#[cfg(all(test, feature = "render"))]
+mod tests {
+    use crate::mj_body::MjBodyChild;
+    use crate::mj_column::MjColumn;
+    use crate::mj_head::MjHead;
+    use crate::mj_text::MjText;
+    use crate::prelude::render::RenderOptions;
+
+    #[test]
+    fn should_render_column_with_built_head() {
+        let head = MjHead::builder()
+            .breakpoint("320px")
+            .add_all_attribute("padding", "4px")
+            .add_element_attribute("mj-column", "background-color", "red")
+            .add_element_attribute("mj-column", "vertical-align", "middle")
+            .add_class_attribute("blue", "color", "blue")
+            .build();
+        assert_eq!(head.breakpoint().unwrap().value(), "320px");
+
+        let mut text = MjText::default();
+        text.attributes
+            .insert("mj-class".into(), Some("blue".into()));
+        let mut column = MjColumn::default();
+        column.children.push(MjBodyChild::MjText(text));
+        let element = MjBodyChild::MjColumn(column);
+
+        let output = element
+            .render_with_head(Some(&head), &RenderOptions::default())
+            .unwrap();
+        assert!(output.contains("background-color:red;"));
+        assert!(output.contains("vertical-align:middle;"));
+        assert!(output.contains("padding:4px;"));
+        assert!(output.contains("color:blue;"));
+    }
+}
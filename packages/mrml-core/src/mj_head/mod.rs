@@ -1,3 +1,4 @@
+mod builder;
 mod children;
 #[cfg(feature = "json")]
 mod json;
@@ -10,6 +11,7 @@ mod render;
 
 use std::marker::PhantomData;
 
+pub use builder::MjHeadBuilder;
 pub use children::MjHeadChild;
 
 use crate::prelude::{Component, StaticTag};
@@ -88,7 +90,83 @@ impl MjHead {
             .last()
     }
 
+    /// The `mj-font` elements, including the ones from the included files.
+    pub fn fonts(&self) -> impl Iterator<Item = &crate::mj_font::MjFont> {
+        self.children.iter().flat_map(|item| {
+            item.as_mj_font().into_iter().chain(
+                item.as_mj_include()
+                    .into_iter()
+                    .flat_map(|inner| inner.0.children.iter())
+                    .filter_map(|child| child.as_mj_font()),
+            )
+        })
+    }
+
+    /// The `mj-style` elements, including the ones from the included mjml
+    /// files. The included css files are not part of them.
+    pub fn styles(&self) -> impl Iterator<Item = &crate::mj_style::MjStyle> {
+        self.children.iter().flat_map(|item| {
+            item.as_mj_style().into_iter().chain(
+                item.as_mj_include()
+                    .into_iter()
+                    .flat_map(|inner| inner.0.children.iter())
+                    .filter_map(|child| child.as_mj_style()),
+            )
+        })
+    }
+
     pub fn children(&self) -> &Vec<MjHeadChild> {
         &self.children
     }
 }
+
+impl<'a> IntoIterator for &'a MjHead {
+    type Item = &'a MjHeadChild;
+    type IntoIter = std::slice::Iter<'a, MjHeadChild>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.children.iter()
+    }
+}
+
+#[cfg(all(test, feature = "parse", feature = "render"))]
+mod tests {
+    use crate::mjml::Mjml;
+
+    #[test]
+    fn should_list_head_components() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-title>Newsletter</mj-title>
+    <mj-preview>Hello</mj-preview>
+    <mj-breakpoint width="320px" />
+    <mj-font name="Raleway" href="https://fonts.googleapis.com/css?family=Raleway" />
+    <mj-font name="Lato" href="https://fonts.googleapis.com/css?family=Lato" />
+    <mj-style>.red { color: red; }</mj-style>
+  </mj-head>
+  <mj-body></mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let head = root.element.head().unwrap();
+        assert_eq!(head.title().unwrap().content(), "Newsletter");
+        assert_eq!(head.preview().unwrap().content(), "Hello");
+        assert_eq!(head.breakpoint().unwrap().value(), "320px");
+        let fonts = head
+            .fonts()
+            .map(|font| (font.name(), font.href()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fonts,
+            vec![
+                ("Raleway", "https://fonts.googleapis.com/css?family=Raleway"),
+                ("Lato", "https://fonts.googleapis.com/css?family=Lato"),
+            ]
+        );
+        let styles = head
+            .styles()
+            .map(|style| style.children())
+            .collect::<Vec<_>>();
+        assert_eq!(styles, vec![".red { color: red; }"]);
+        assert_eq!(head.into_iter().count(), head.children().len());
+    }
+}
@@ -5,6 +5,7 @@ use crate::comment::Comment;
 use crate::mj_attributes::NAME as MJ_ATTRIBUTES;
 use crate::mj_breakpoint::NAME as MJ_BREAKPOINT;
 use crate::mj_font::NAME as MJ_FONT;
+use crate::mj_html_attributes::NAME as MJ_HTML_ATTRIBUTES;
 use crate::mj_include::NAME as MJ_INCLUDE;
 use crate::mj_preview::NAME as MJ_PREVIEW;
 use crate::mj_raw::NAME as MJ_RAW;
@@ -13,9 +14,38 @@ use crate::mj_title::NAME as MJ_TITLE;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement,
+    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement, WarningKind,
 };
 
+/// Checks if the element should only be defined once in the head and already
+/// is, in which case only the last one is used.
+fn is_duplicate(children: &[MjHeadChild], name: &str) -> bool {
+    match name {
+        MJ_PREVIEW => children
+            .iter()
+            .any(|child| matches!(child, MjHeadChild::MjPreview(_))),
+        MJ_TITLE => children
+            .iter()
+            .any(|child| matches!(child, MjHeadChild::MjTitle(_))),
+        _ => false,
+    }
+}
+
+/// Warns when the preview is too long to be displayed entirely by the email
+/// clients.
+fn check_preview_length(
+    cursor: &mut MrmlCursor<'_>,
+    child: &MjHeadChild,
+    span: StrSpan<'_>,
+    max_length: usize,
+) {
+    if let MjHeadChild::MjPreview(preview) = child {
+        if preview.children.chars().count() > max_length {
+            cursor.add_warning(WarningKind::PreviewTooLong, span);
+        }
+    }
+}
+
 impl ParseChildren<Vec<MjHeadChild>> for MrmlParser<'_> {
     fn parse_children(&self, cursor: &mut MrmlCursor<'_>) -> Result<Vec<MjHeadChild>, Error> {
         let mut result = Vec::new();
@@ -25,7 +55,20 @@ impl ParseChildren<Vec<MjHeadChild>> for MrmlParser<'_> {
                     result.push(MjHeadChild::Comment(Comment::from(inner.text.as_str())));
                 }
                 MrmlToken::ElementStart(inner) => {
-                    result.push(self.parse(cursor, inner.local)?);
+                    if is_duplicate(&result, inner.local.as_str()) {
+                        cursor.add_duplicate_element(inner.span)?;
+                    }
+                    let child = self.parse(cursor, inner.local);
+                    let Some(child) = cursor.skip_unexpected_result(inner.local, child)? else {
+                        continue;
+                    };
+                    check_preview_length(
+                        cursor,
+                        &child,
+                        inner.span,
+                        self.options.max_preview_length,
+                    );
+                    result.push(child);
                 }
                 MrmlToken::ElementClose(close) => {
                     cursor.rewind(MrmlToken::ElementClose(close));
@@ -35,6 +78,7 @@ impl ParseChildren<Vec<MjHeadChild>> for MrmlParser<'_> {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -59,7 +103,20 @@ impl AsyncParseChildren<Vec<MjHeadChild>> for AsyncMrmlParser {
                     result.push(MjHeadChild::Comment(Comment::from(inner.text.as_str())));
                 }
                 MrmlToken::ElementStart(inner) => {
-                    result.push(self.async_parse(cursor, inner.local).await?);
+                    if is_duplicate(&result, inner.local.as_str()) {
+                        cursor.add_duplicate_element(inner.span)?;
+                    }
+                    let child = self.async_parse(cursor, inner.local).await;
+                    let Some(child) = cursor.skip_unexpected_result(inner.local, child)? else {
+                        continue;
+                    };
+                    check_preview_length(
+                        cursor,
+                        &child,
+                        inner.span,
+                        self.options.max_preview_length,
+                    );
+                    result.push(child);
                 }
                 MrmlToken::ElementClose(close) => {
                     cursor.rewind(MrmlToken::ElementClose(close));
@@ -69,6 +126,7 @@ impl AsyncParseChildren<Vec<MjHeadChild>> for AsyncMrmlParser {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -86,6 +144,7 @@ impl ParseElement<MjHeadChild> for MrmlParser<'_> {
             MJ_ATTRIBUTES => self.parse(cursor, tag).map(MjHeadChild::MjAttributes),
             MJ_BREAKPOINT => self.parse(cursor, tag).map(MjHeadChild::MjBreakpoint),
             MJ_FONT => self.parse(cursor, tag).map(MjHeadChild::MjFont),
+            MJ_HTML_ATTRIBUTES => self.parse(cursor, tag).map(MjHeadChild::MjHtmlAttributes),
             MJ_INCLUDE => self.parse(cursor, tag).map(MjHeadChild::MjInclude),
             MJ_PREVIEW => self.parse(cursor, tag).map(MjHeadChild::MjPreview),
             MJ_RAW => self.parse(cursor, tag).map(MjHeadChild::MjRaw),
@@ -94,6 +153,7 @@ impl ParseElement<MjHeadChild> for MrmlParser<'_> {
             _ => Err(Error::UnexpectedElement {
                 origin: cursor.origin(),
                 position: tag.into(),
+                location: cursor.location(tag),
             }),
         }
     }
@@ -118,6 +178,10 @@ impl AsyncParseElement<MjHeadChild> for AsyncMrmlParser {
                 .await
                 .map(MjHeadChild::MjBreakpoint),
             MJ_FONT => self.async_parse(cursor, tag).await.map(MjHeadChild::MjFont),
+            MJ_HTML_ATTRIBUTES => self
+                .async_parse(cursor, tag)
+                .await
+                .map(MjHeadChild::MjHtmlAttributes),
             MJ_INCLUDE => self
                 .async_parse(cursor, tag)
                 .await
@@ -138,6 +202,7 @@ impl AsyncParseElement<MjHeadChild> for AsyncMrmlParser {
             _ => Err(Error::UnexpectedElement {
                 origin: cursor.origin(),
                 position: tag.into(),
+                location: cursor.location(tag),
             }),
         }
     }
@@ -155,9 +220,60 @@ mod tests {
 
     crate::should_parse!(with_comment, MjHead, "<mj-head><!-- HEAD --></mj-head>");
 
+    crate::should_parse!(
+        duplicate_title_and_preview,
+        MjHead,
+        "<mj-head><mj-title>A</mj-title><mj-preview>A</mj-preview><mj-title>B</mj-title><mj-preview>B</mj-preview></mj-head>",
+        2
+    );
+
     crate::should_not_parse!(
         unexpected_element,
         MjHead,
         "<mj-head><mj-text>Hello World!</mj-text></mj-head>"
     );
+
+    #[test]
+    fn should_fail_on_duplicate_title_when_strict() {
+        use crate::prelude::parser::{Error, MrmlCursor, MrmlParser, ParserOptions};
+
+        let template = "<mj-head><mj-title>A</mj-title><mj-title>B</mj-title></mj-head>";
+        let options = ParserOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let parser = MrmlParser::new(&options);
+        let mut cursor = MrmlCursor::new(template);
+        let err = parser.parse_root::<MjHead>(&mut cursor).unwrap_err();
+        assert!(matches!(err, Error::DuplicateElement { .. }));
+        assert_eq!(
+            err.to_string(),
+            "duplicate element in root template at line 1, column 32"
+        );
+    }
+
+    #[test]
+    fn should_warn_on_long_preview() {
+        use crate::prelude::parser::{MrmlCursor, MrmlParser, ParserOptions, WarningKind};
+
+        let parse = |template: &str, max_preview_length: usize| {
+            let options = ParserOptions {
+                max_preview_length,
+                ..Default::default()
+            };
+            let parser = MrmlParser::new(&options);
+            let mut cursor = MrmlCursor::new(template);
+            let _: MjHead = parser.parse_root(&mut cursor).unwrap();
+            cursor.warnings()
+        };
+        let long = format!(
+            "<mj-head><mj-preview>{}</mj-preview></mj-head>",
+            "é".repeat(151)
+        );
+        let warnings = parse(&long, crate::prelude::parser::DEFAULT_MAX_PREVIEW_LENGTH);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, WarningKind::PreviewTooLong);
+        assert!(parse(&long, 151).is_empty());
+        assert!(parse("<mj-head><mj-preview>Hello</mj-preview></mj-head>", 5).is_empty());
+    }
 }
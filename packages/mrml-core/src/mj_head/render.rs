@@ -90,19 +90,25 @@ impl MjHead {
     }
 
     pub fn build_font_families(&self) -> Map<&str, &str> {
+        self.fonts()
+            .map(|font| (font.name(), font.href()))
+            .collect()
+    }
+
+    pub fn build_html_attributes(&self) -> Vec<(&str, &str, &str)> {
         self.children
             .iter()
             .flat_map(|item| {
-                item.as_mj_font()
+                item.as_mj_html_attributes()
                     .into_iter()
-                    .chain(item.as_mj_include().into_iter().flat_map(|incl| {
-                        incl.0
-                            .children
-                            .iter()
-                            .filter_map(|child| child.as_mj_font())
-                    }))
+                    .flat_map(|inner| inner.mj_html_attributes_iter())
+                    .chain(
+                        item.as_mj_include()
+                            .filter(|item| item.0.attributes.kind.is_mjml())
+                            .into_iter()
+                            .flat_map(|inner| inner.mj_html_attributes_iter()),
+                    )
             })
-            .map(|font| (font.name(), font.href()))
             .collect()
     }
 }
@@ -119,23 +125,37 @@ fn render_font_link(target: &mut String, href: &str) {
     target.push_str("\" rel=\"stylesheet\" type=\"text/css\">");
 }
 
-impl Renderer<'_, MjHead, ()> {
-    fn mj_style_iter(&self) -> impl Iterator<Item = &str> {
-        self.element.children.iter().flat_map(|item| {
+impl MjHead {
+    /// Iterates over the content of the `mj-style` elements, including the
+    /// ones from the included files. The styles meant to be inlined are
+    /// excluded.
+    pub(crate) fn mj_style_iter(&self) -> impl Iterator<Item = &str> {
+        self.mj_style_iter_with(false)
+    }
+
+    /// Iterates over the content of the `mj-style inline="inline"` elements,
+    /// including the ones from the included files.
+    pub(crate) fn mj_inline_style_iter(&self) -> impl Iterator<Item = &str> {
+        self.mj_style_iter_with(true)
+    }
+
+    fn mj_style_iter_with(&self, inline: bool) -> impl Iterator<Item = &str> {
+        self.children.iter().flat_map(move |item| {
             item.as_mj_include()
                 .into_iter()
-                .flat_map(|inner| {
+                .flat_map(move |inner| {
                     inner
                         .0
                         .children
                         .iter()
                         .filter_map(|child| child.as_mj_style())
+                        .filter(move |child| child.is_inline() == inline)
                         .map(|child| child.children.trim())
                 })
                 .chain(
                     item.as_mj_include()
                         .into_iter()
-                        .filter(|child| child.0.attributes.kind.is_css(false))
+                        .filter(move |child| child.0.attributes.kind.is_css(inline))
                         .flat_map(|child| {
                             child
                                 .0
@@ -148,21 +168,43 @@ impl Renderer<'_, MjHead, ()> {
                 .chain(
                     item.as_mj_style()
                         .into_iter()
+                        .filter(move |item| item.is_inline() == inline)
                         .map(|item| item.children.trim()),
                 )
         })
     }
+}
 
+impl Renderer<'_, MjHead, ()> {
     fn render_font_families(&self, cursor: &mut RenderCursor) {
-        let used_font_families = cursor.header.used_font_families();
-        if used_font_families.is_empty() {
+        let mut font_families = cursor
+            .header
+            .used_font_families()
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        if self.context.options.always_include_fonts {
+            let mut registered = self
+                .context
+                .header
+                .font_families()
+                .keys()
+                .copied()
+                .chain(self.context.options.fonts.keys().map(String::as_str))
+                .filter(|name| !font_families.contains(name))
+                .collect::<Vec<_>>();
+            registered.sort_unstable();
+            registered.dedup();
+            font_families.extend(registered);
+        }
+        if font_families.is_empty() {
             return;
         }
 
         let mut links = String::default();
         let mut imports = String::default();
-        for name in cursor.header.used_font_families().iter() {
-            if let Some(href) = self.context.header.font_families().get(name.as_str()) {
+        for name in font_families {
+            if let Some(href) = self.context.header.font_families().get(name) {
                 render_font_link(&mut links, href);
                 render_font_import(&mut imports, href);
             } else if let Some(href) = self.context.options.fonts.get(name) {
@@ -238,8 +280,15 @@ impl Renderer<'_, MjHead, ()> {
 
         // TODO this should be optional
         cursor.buffer.push_str("<style type=\"text/css\">");
-        for item in self.mj_style_iter() {
-            cursor.buffer.push_str(item);
+        if self.context.options.inline_css && self.context.options.strip_inlined {
+            let styles = self.element.mj_style_iter().collect::<String>();
+            cursor
+                .buffer
+                .push_str(Stylesheet::parse(&styles).remaining());
+        } else {
+            for item in self.element.mj_style_iter() {
+                cursor.buffer.push_str(item);
+            }
         }
         cursor.buffer.push_str("</style>");
     }
@@ -294,6 +343,14 @@ impl<'root> Render<'root> for Renderer<'root, MjHead, ()> {
         cursor
             .buffer
             .push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">");
+        if self.context.options.dark_mode {
+            cursor
+                .buffer
+                .push_str("<meta name=\"color-scheme\" content=\"light dark\">");
+            cursor
+                .buffer
+                .push_str("<meta name=\"supported-color-schemes\" content=\"light dark\">");
+        }
         cursor.buffer.push_str(STYLE_BASE);
         self.render_font_families(cursor);
         self.render_media_queries(cursor);
@@ -538,4 +595,122 @@ mod tests {
         assert_eq!(fonts.get("foo"), Some("http://foo/include").as_ref());
         assert_eq!(fonts.get("bar"), Some("http://bar/root").as_ref());
     }
+
+    #[test]
+    fn should_split_inline_styles() {
+        use crate::mj_style::{MjStyle, MjStyleAttributes};
+        use crate::prelude::render::{Header, Stylesheet};
+
+        let element = MjHead::new(
+            (),
+            vec![
+                MjHeadChild::MjStyle(MjStyle::from(".head { color: red; }")),
+                MjHeadChild::MjStyle(MjStyle::new(
+                    MjStyleAttributes {
+                        inline: Some(String::from("inline")),
+                    },
+                    String::from(".inline { color: blue; }"),
+                )),
+                MjHeadChild::MjInclude(MjIncludeHead::new(
+                    MjIncludeHeadAttributes {
+                        path: String::from("foo"),
+                        kind: crate::mj_include::head::MjIncludeHeadKind::Mjml,
+                    },
+                    vec![MjIncludeHeadChild::MjStyle(MjStyle::new(
+                        MjStyleAttributes {
+                            inline: Some(String::from("inline")),
+                        },
+                        String::from(".included { color: green; }"),
+                    ))],
+                )),
+            ],
+        );
+        assert_eq!(
+            element.mj_style_iter().collect::<Vec<_>>(),
+            vec![".head { color: red; }"]
+        );
+        let header = Header::new(Some(&element), None, None, None);
+        assert_eq!(
+            header.inline_styles(),
+            &[".inline { color: blue; }", ".included { color: green; }"]
+        );
+        assert!(!Stylesheet::parse(&header.inline_styles().concat()).is_empty());
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_prefer_mj_font_over_options() {
+        use crate::prelude::render::RenderOptions;
+
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-font name="Raleway" href="https://example.com/raleway.css" />
+    <mj-font name="Lato" href="https://example.com/lato.css" />
+  </mj-head>
+  <mj-body>
+    <mj-text font-family="Raleway, Lato, sans-serif">Hello World</mj-text>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let options = RenderOptions::builder()
+            .add_font("Raleway", "https://example.com/options.css")
+            .build()
+            .unwrap();
+        let output = root.element.render(&options).unwrap();
+        assert!(output.contains(
+            r#"<link href="https://example.com/raleway.css" rel="stylesheet" type="text/css">"#
+        ));
+        assert!(output.contains(
+            r#"<link href="https://example.com/lato.css" rel="stylesheet" type="text/css">"#
+        ));
+        assert!(!output.contains("options.css"));
+        assert!(!output.contains("fonts.googleapis.com"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_concatenate_mj_styles_in_order() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-style>.first { color: red; }</mj-style>
+    <mj-style>@media (max-width:480px) { .second { color: blue; } }</mj-style>
+  </mj-head>
+  <mj-body>
+    <mj-text css-class="first second">Hello World</mj-text>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let output = root.element.render(&Default::default()).unwrap();
+        assert!(output.contains(
+            r#"<style type="text/css">.first { color: red; }@media (max-width:480px) { .second { color: blue; } }</style>"#
+        ));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_add_color_scheme_meta_in_dark_mode() {
+        use crate::prelude::render::RenderOptions;
+
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-style>@media (prefers-color-scheme: dark) { .title { color: white; } }</mj-style>
+  </mj-head>
+  <mj-body>
+    <mj-text css-class="title">Hello World</mj-text>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let color_scheme = r#"<meta name="color-scheme" content="light dark">"#;
+        let supported = r#"<meta name="supported-color-schemes" content="light dark">"#;
+
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(!output.contains(color_scheme));
+        assert!(!output.contains(supported));
+
+        let options = RenderOptions::builder().dark_mode(true).build().unwrap();
+        let output = root.element.render(&options).unwrap();
+        assert!(output.contains(color_scheme));
+        assert!(output.contains(supported));
+        assert!(output.contains("@media (prefers-color-scheme: dark) { .title { color: white; } }"));
+    }
 }
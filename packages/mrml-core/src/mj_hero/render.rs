@@ -26,10 +26,10 @@ impl<'root> Renderer<'root, MjHero, ()> {
             .attribute_as_size("background-height")
             .and_then(|height| {
                 self.attribute_as_size("background-width")
-                    .map(|width| height.value() * 100.0 / width.value())
+                    .map(|width| (height.value() * 10000.0 / width.value()).round() / 100.0)
             });
         tag.add_style("mso-padding-bottom-alt", "0")
-            .maybe_add_style("padding-bottom", bg_ratio.map(|v| v.to_string()))
+            .maybe_add_style("padding-bottom", bg_ratio.map(|v| format!("{v}%")))
             .add_style("width", "0.01%")
     }
 
@@ -151,19 +151,8 @@ impl<'root> Renderer<'root, MjHero, ()> {
                 renderer.render(cursor)?;
             } else {
                 let tr = Tag::tr();
-                let td = Tag::td()
-                    .maybe_add_style(
-                        "background",
-                        renderer.attribute("container-background-color"),
-                    )
-                    .add_style("font-size", "0px")
-                    .maybe_add_style("padding", renderer.attribute("padding"))
-                    .maybe_add_style("padding-top", renderer.attribute("padding-top"))
-                    .maybe_add_style("padding-right", renderer.attribute("padding-right"))
-                    .maybe_add_style("padding-bottom", renderer.attribute("padding-bottom"))
-                    .maybe_add_style("padding-left", renderer.attribute("padding-left"))
-                    .add_style("word-break", "break-word")
-                    .maybe_add_attribute("align", renderer.attribute("align"))
+                let td = renderer
+                    .container_cell()
                     .maybe_add_attribute(
                         "background",
                         renderer.attribute("container-background-color"),
@@ -251,7 +240,9 @@ impl<'root> Renderer<'root, MjHero, ()> {
             .attribute_as_pixel("height")
             .map(|v| v.value())
             .unwrap_or(0.0);
-        let padding = self.get_padding_vertical().value();
+        let padding = self
+            .get_padding_vertical(&self.container_width.unwrap_or_default())
+            .value();
         let height = height - padding;
         let td = self
             .set_style_hero(Tag::td())
@@ -268,7 +259,7 @@ impl<'root> Renderer<'root, MjHero, ()> {
 
     fn render_mode(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         match self.attribute("mode") {
-            Some(inner) if inner.eq("fluid") => self.render_mode_fluid(cursor),
+            Some("fluid-height") => self.render_mode_fluid(cursor),
             _ => self.render_mode_fixed(cursor),
         }
     }
@@ -383,8 +374,37 @@ mod tests {
     crate::should_render!(background_url, "mj-hero-background-url");
     crate::should_render!(background_width, "mj-hero-background-width");
     crate::should_render!(class, "mj-hero-class");
+    crate::should_render!(
+        container_background_color,
+        "mj-hero-container-background-color"
+    );
     crate::should_render!(height, "mj-hero-height");
     crate::should_render!(mode, "mj-hero-mode");
     crate::should_render!(vertical_align, "mj-hero-vertical-align");
     crate::should_render!(width, "mj-hero-width");
+
+    #[test]
+    fn should_render_fluid_height_mode() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r##"<mjml>
+  <mj-body>
+    <mj-hero mode="fluid-height" height="469px" background-width="600px" background-height="469px" background-url="https://example.com/bg.jpg" background-color="#2a3448">
+      <mj-text>GO TO SPACE</mj-text>
+    </mj-hero>
+  </mj-body>
+</mjml>"##;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        // the ratio of the background is rounded to two decimals, like mjml
+        assert_eq!(
+            output
+                .matches(
+                    r#"<td style="mso-padding-bottom-alt:0;padding-bottom:78.17%;width:0.01%;" />"#
+                )
+                .count(),
+            2
+        );
+    }
 }
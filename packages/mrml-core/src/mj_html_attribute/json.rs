@@ -0,0 +1,37 @@
+use crate::prelude::json::JsonAttributes;
+
+impl JsonAttributes for super::MjHtmlAttributeAttributes {
+    fn has_attributes(&self) -> bool {
+        true
+    }
+
+    fn try_from_serde<Err: serde::de::Error>(this: Option<Self>) -> Result<Self, Err>
+    where
+        Self: Sized,
+    {
+        this.ok_or_else(|| serde::de::Error::missing_field("attributes"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_html_attribute::MjHtmlAttribute;
+
+    #[test]
+    fn serialize() {
+        let elt = MjHtmlAttribute::build("data-id", "42");
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-html-attribute","attributes":{"name":"data-id"},"children":"42"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json =
+            r#"{"type":"mj-html-attribute","attributes":{"name":"data-id"},"children":"42"}"#;
+        let res: MjHtmlAttribute = serde_json::from_str(json).unwrap();
+        assert_eq!(res.name(), "data-id");
+        assert_eq!(res.value(), "42");
+    }
+}
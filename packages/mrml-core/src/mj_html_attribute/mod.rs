@@ -0,0 +1,49 @@
+use std::marker::PhantomData;
+
+use crate::prelude::{Component, StaticTag};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+
+pub const NAME: &str = "mj-html-attribute";
+
+pub struct MjHtmlAttributeTag;
+
+impl StaticTag for MjHtmlAttributeTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct MjHtmlAttributeAttributes {
+    pub name: String,
+}
+
+pub type MjHtmlAttribute =
+    Component<PhantomData<MjHtmlAttributeTag>, MjHtmlAttributeAttributes, String>;
+
+#[cfg(test)]
+impl MjHtmlAttribute {
+    pub(crate) fn build<N: Into<String>, V: Into<String>>(name: N, value: V) -> Self {
+        Self::new(
+            MjHtmlAttributeAttributes { name: name.into() },
+            value.into(),
+        )
+    }
+}
+
+impl MjHtmlAttribute {
+    pub fn name(&self) -> &str {
+        &self.attributes.name
+    }
+
+    pub fn value(&self) -> &str {
+        &self.children
+    }
+}
@@ -0,0 +1,76 @@
+use htmlparser::StrSpan;
+
+use super::MjHtmlAttributeAttributes;
+#[cfg(feature = "async")]
+use crate::prelude::parser::AsyncMrmlParser;
+use crate::prelude::parser::{Error, MrmlCursor, MrmlParser, ParseAttributes};
+
+#[inline(always)]
+fn parse_attributes(
+    cursor: &mut MrmlCursor<'_>,
+    tag: &StrSpan<'_>,
+) -> Result<MjHtmlAttributeAttributes, Error> {
+    let mut name = None;
+
+    while let Some(attrs) = cursor.next_attribute()? {
+        match (attrs.local.as_str(), attrs.value) {
+            ("name", Some(value)) => name = Some(value.to_string()),
+            _ => cursor.add_unknown_attribute(super::NAME, &attrs)?,
+        }
+    }
+
+    Ok(MjHtmlAttributeAttributes {
+        name: name.ok_or_else(|| Error::MissingAttribute {
+            name: "name",
+            origin: cursor.origin(),
+            position: tag.into(),
+            location: cursor.location(tag),
+        })?,
+    })
+}
+
+impl ParseAttributes<MjHtmlAttributeAttributes> for MrmlParser<'_> {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        tag: &StrSpan<'_>,
+    ) -> Result<MjHtmlAttributeAttributes, Error> {
+        parse_attributes(cursor, tag)
+    }
+}
+
+#[cfg(feature = "async")]
+impl ParseAttributes<MjHtmlAttributeAttributes> for AsyncMrmlParser {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        tag: &StrSpan<'_>,
+    ) -> Result<MjHtmlAttributeAttributes, Error> {
+        parse_attributes(cursor, tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_html_attribute::MjHtmlAttribute;
+
+    crate::should_parse!(
+        success,
+        MjHtmlAttribute,
+        r#"<mj-html-attribute name="data-id">42</mj-html-attribute>"#
+    );
+
+    crate::should_sync_parse!(
+        unexpected_attribute,
+        MjHtmlAttribute,
+        r#"<mj-html-attribute name="data-id" unknown="whatever">42</mj-html-attribute>"#,
+        1
+    );
+
+    crate::should_not_sync_parse!(
+        should_have_name,
+        MjHtmlAttribute,
+        r#"<mj-html-attribute>42</mj-html-attribute>"#,
+        "MissingAttribute { name: \"name\", origin: Root, position: Span { start: 1, end: 18 }, location: Location { offset: 1, line: 1, column: 2 } }"
+    );
+}
@@ -0,0 +1,28 @@
+use crate::prelude::print::Printable;
+
+impl Printable for super::MjHtmlAttribute {
+    fn print<P: crate::prelude::print::Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        printer.push_indent();
+        printer.open_tag(super::NAME)?;
+        printer.push_attribute("name", self.attributes.name.as_str())?;
+        printer.close_tag();
+        printer.push_str(self.children.as_str());
+        printer.end_tag(super::NAME)?;
+        printer.push_new_line();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn normal() {
+        let item = crate::mj_html_attribute::MjHtmlAttribute::build("data-id", "42");
+        assert_eq!(
+            r#"<mj-html-attribute name="data-id">42</mj-html-attribute>"#,
+            item.print_dense().unwrap()
+        );
+    }
+}
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_html_attribute::MjHtmlAttribute;
+    use crate::mj_html_attributes::MjHtmlAttributes;
+    use crate::mj_selector::{MjSelector, MjSelectorAttributes};
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjHtmlAttributes::default();
+        elt.children.push(MjSelector::new(
+            MjSelectorAttributes {
+                path: ".cta".into(),
+            },
+            vec![MjHtmlAttribute::build("data-id", "42")],
+        ));
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-html-attributes","children":[{"type":"mj-selector","attributes":{"path":".cta"},"children":[{"type":"mj-html-attribute","attributes":{"name":"data-id"},"children":"42"}]}]}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-html-attributes","children":[{"type":"mj-selector","attributes":{"path":".cta"},"children":[{"type":"mj-html-attribute","attributes":{"name":"data-id"},"children":"42"}]}]}"#;
+        let res: MjHtmlAttributes = serde_json::from_str(json).unwrap();
+        assert_eq!(res.children.len(), 1);
+        let next = serde_json::to_string(&res).unwrap();
+        assert_eq!(next, json);
+    }
+}
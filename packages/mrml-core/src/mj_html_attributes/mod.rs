@@ -0,0 +1,43 @@
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+
+use std::marker::PhantomData;
+
+use crate::mj_selector::MjSelector;
+use crate::prelude::{Component, StaticTag};
+
+pub const NAME: &str = "mj-html-attributes";
+
+pub struct MjHtmlAttributesTag;
+
+impl StaticTag for MjHtmlAttributesTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjHtmlAttributes = Component<PhantomData<MjHtmlAttributesTag>, (), Vec<MjSelector>>;
+
+#[cfg(feature = "render")]
+impl MjHtmlAttributes {
+    /// Iterates over the selector path, attribute name and attribute value of
+    /// each `mj-html-attribute`.
+    pub(crate) fn mj_html_attributes_iter(&self) -> impl Iterator<Item = (&str, &str, &str)> {
+        self.children.iter().flat_map(|selector| {
+            selector
+                .children
+                .iter()
+                .map(move |attr| (selector.path(), attr.name(), attr.value()))
+        })
+    }
+}
+
+impl MjHtmlAttributes {
+    pub fn children(&self) -> &Vec<MjSelector> {
+        &self.children
+    }
+}
@@ -0,0 +1,103 @@
+use crate::mj_selector::{MjSelector, NAME as MJ_SELECTOR};
+#[cfg(feature = "async")]
+use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
+use crate::prelude::parser::{
+    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement,
+};
+
+impl ParseChildren<Vec<MjSelector>> for MrmlParser<'_> {
+    fn parse_children(&self, cursor: &mut MrmlCursor<'_>) -> Result<Vec<MjSelector>, Error> {
+        let mut result = Vec::new();
+
+        loop {
+            match cursor.assert_next()? {
+                MrmlToken::ElementStart(inner) if inner.local.as_str() == MJ_SELECTOR => {
+                    result.push(self.parse(cursor, inner.local)?);
+                }
+                MrmlToken::ElementStart(inner) => {
+                    cursor.skip_unexpected_element(Error::UnexpectedElement {
+                        origin: cursor.origin(),
+                        position: inner.local.into(),
+                        location: cursor.location(inner.local),
+                    })?;
+                }
+                MrmlToken::ElementClose(inner) => {
+                    cursor.rewind(MrmlToken::ElementClose(inner));
+                    return Ok(result);
+                }
+                other => {
+                    return Err(Error::UnexpectedToken {
+                        origin: cursor.origin(),
+                        position: other.span(),
+                        location: cursor.location(other.span()),
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AsyncParseChildren<Vec<MjSelector>> for AsyncMrmlParser {
+    async fn async_parse_children<'a>(
+        &self,
+        cursor: &mut MrmlCursor<'a>,
+    ) -> Result<Vec<MjSelector>, Error> {
+        let mut result = Vec::new();
+
+        loop {
+            match cursor.assert_next()? {
+                MrmlToken::ElementStart(inner) if inner.local.as_str() == MJ_SELECTOR => {
+                    result.push(self.async_parse(cursor, inner.local).await?);
+                }
+                MrmlToken::ElementStart(inner) => {
+                    cursor.skip_unexpected_element(Error::UnexpectedElement {
+                        origin: cursor.origin(),
+                        position: inner.local.into(),
+                        location: cursor.location(inner.local),
+                    })?;
+                }
+                MrmlToken::ElementClose(inner) => {
+                    cursor.rewind(MrmlToken::ElementClose(inner));
+                    return Ok(result);
+                }
+                other => {
+                    return Err(Error::UnexpectedToken {
+                        origin: cursor.origin(),
+                        position: other.span(),
+                        location: cursor.location(other.span()),
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_html_attributes::MjHtmlAttributes;
+
+    crate::should_parse!(
+        parse_complete,
+        MjHtmlAttributes,
+        r#"
+    <mj-html-attributes>
+        <mj-selector path=".cta">
+            <mj-html-attribute name="data-id">42</mj-html-attribute>
+        </mj-selector>
+        <mj-selector path=".footer a">
+            <mj-html-attribute name="target">_blank</mj-html-attribute>
+        </mj-selector>
+    </mj-html-attributes>
+"#
+    );
+
+    crate::should_not_sync_parse!(
+        unexpected_element,
+        MjHtmlAttributes,
+        r#"<mj-html-attributes><mj-class name="foo" /></mj-html-attributes>"#,
+        "UnexpectedElement { origin: Root, position: Span { start: 21, end: 29 }, location: Location { offset: 21, line: 1, column: 22 } }"
+    );
+}
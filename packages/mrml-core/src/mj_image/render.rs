@@ -1,8 +1,55 @@
+use std::borrow::Cow;
+
 use super::{MjImage, NAME};
 use crate::helper::size::Pixel;
 use crate::prelude::render::*;
 
+const DEFAULT_RETINA_SUFFIX: &str = "@2x";
+
+/// Inserts the suffix before the extension of the file name, or at the end
+/// of the path when the file has no extension.
+fn retina_src(src: &str, suffix: &str) -> String {
+    let end = src.find(['?', '#']).unwrap_or(src.len());
+    let (path, query) = src.split_at(end);
+    let name_start = path.rfind('/').map(|idx| idx + 1).unwrap_or(0);
+    match path[name_start..].rfind('.') {
+        Some(dot) if dot > 0 => {
+            let (stem, ext) = path.split_at(name_start + dot);
+            format!("{stem}{suffix}{ext}{query}")
+        }
+        _ => format!("{path}{suffix}{query}"),
+    }
+}
+
 impl<'root> Renderer<'root, MjImage, ()> {
+    fn is_retina(&self) -> bool {
+        self.attribute("retina")
+            .and_then(|value| value.parse::<bool>().ok())
+            .unwrap_or(false)
+    }
+
+    fn get_src(&self) -> Option<Cow<'_, str>> {
+        self.attribute("src")
+            .map(|src| self.context.options.with_image_base_url(Cow::Borrowed(src)))
+    }
+
+    /// Uses the `srcset` attribute when provided, otherwise generates one from
+    /// the `src` when the `retina` attribute is set.
+    fn get_srcset(&self) -> Option<String> {
+        if let Some(srcset) = self.attribute("srcset") {
+            return Some(srcset.to_string());
+        }
+        if !self.is_retina() {
+            return None;
+        }
+        self.get_src().map(|src| {
+            let suffix = self
+                .attribute("retina-suffix")
+                .unwrap_or(DEFAULT_RETINA_SUFFIX);
+            format!("{src} 1x, {} 2x", retina_src(&src, suffix))
+        })
+    }
+
     fn is_fluid_on_mobile(&self) -> bool {
         self.attribute("fluid-on-mobile")
             .and_then(|value| value.parse::<bool>().ok())
@@ -16,7 +63,7 @@ impl<'root> Renderer<'root, MjImage, ()> {
     fn get_box_width(&self) -> Option<Pixel> {
         self.container_width.as_ref().map(|width| {
             let hborder = self.get_border_horizontal();
-            let hpadding = self.get_padding_horizontal();
+            let hpadding = self.get_padding_horizontal(width);
             Pixel::new(width.value() - hborder.value() - hpadding.value())
         })
     }
@@ -84,17 +131,25 @@ impl<'root> Renderer<'root, MjImage, ()> {
             .add_style("border-spacing", "0px")
     }
 
+    /// The `alt` attribute, kept when it's empty or has no value, for the
+    /// decorative images to be ignored by the screen readers.
+    fn get_alt(&self) -> Option<&str> {
+        self.attribute("alt")
+            .or_else(|| self.element.attributes.contains_key("alt").then_some(""))
+    }
+
     fn render_image(&self, buf: &mut RenderBuffer) -> std::fmt::Result {
         let img = Tag::new("img")
-            .maybe_add_attribute("alt", self.attribute("alt"))
+            .maybe_add_attribute("alt", self.get_alt())
             .add_attribute(
                 "height",
                 self.attribute_as_size("height")
                     .map(|size| size.value().to_string())
                     .unwrap_or_else(|| "auto".into()),
             )
-            .maybe_add_attribute("src", self.attribute("src"))
-            .maybe_add_attribute("srcset", self.attribute("srcset"))
+            .maybe_add_attribute("src", self.get_src())
+            .maybe_add_attribute("srcset", self.get_srcset())
+            .maybe_add_attribute("sizes", self.attribute("sizes"))
             .maybe_add_attribute("title", self.attribute("title"))
             .maybe_add_attribute(
                 "width",
@@ -108,7 +163,11 @@ impl<'root> Renderer<'root, MjImage, ()> {
 
     fn render_link(&self, buf: &mut RenderBuffer) -> std::fmt::Result {
         Tag::new("a")
-            .maybe_add_attribute("href", self.attribute("href"))
+            .maybe_add_attribute(
+                "href",
+                self.attribute("href")
+                    .map(|href| self.context.options.with_link_params(Cow::Borrowed(href))),
+            )
             .maybe_add_attribute("name", self.attribute("name"))
             .maybe_add_attribute("rel", self.attribute("rel"))
             .maybe_add_attribute("target", self.attribute("target"))
@@ -214,7 +273,181 @@ mod tests {
         container_background_color,
         "mj-image-container-background-color"
     );
+    crate::should_render!(fluid_on_mobile, "mj-image-fluid-on-mobile");
     crate::should_render!(height, "mj-image-height");
     crate::should_render!(href, "mj-image-href");
     crate::should_render!(padding, "mj-image-padding");
+    crate::should_render!(rel, "mj-image-rel");
+    crate::should_render!(title, "mj-image-title");
+
+    #[test]
+    fn should_build_retina_src() {
+        assert_eq!(
+            super::retina_src("https://example.com/img/logo.png", "@2x"),
+            "https://example.com/img/logo@2x.png"
+        );
+        assert_eq!(
+            super::retina_src("https://example.com/img/logo.png?v=1", "-2x"),
+            "https://example.com/img/logo-2x.png?v=1"
+        );
+        assert_eq!(
+            super::retina_src("https://example.com/v1.0/logo", "@2x"),
+            "https://example.com/v1.0/logo@2x"
+        );
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_srcset_and_sizes() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-image src="https://example.com/logo.png" srcset="https://example.com/logo.png 1x, https://example.com/logo@2x.png 2x" sizes="(max-width: 480px) 100vw, 550px" /></mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains(r#"<img height="auto" src="https://example.com/logo.png" srcset="https://example.com/logo.png 1x, https://example.com/logo@2x.png 2x" sizes="(max-width: 480px) 100vw, 550px" width="550""#));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_retina_srcset() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <mj-image retina-suffix="_hd" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-image src="https://example.com/a.png" retina="true" />
+        <mj-image src="https://example.com/b.png" retina="true" srcset="https://example.com/b.png 1x" />
+        <mj-image src="https://example.com/c.png" />
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result
+            .contains(r#"srcset="https://example.com/a.png 1x, https://example.com/a_hd.png 2x""#));
+        assert!(result.contains(r#"srcset="https://example.com/b.png 1x""#));
+        assert_eq!(result.matches("srcset=").count(), 2);
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_full_width_mobile_class_when_fluid() {
+        let template = |fluid: &str| {
+            format!(
+                r#"<mjml>
+  <mj-head>
+    <mj-breakpoint width="320px" />
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-image src="https://example.com/a.png"{fluid} />
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#
+            )
+        };
+        let render = |template: String| {
+            let root = crate::mjml::Mjml::parse(template).unwrap();
+            root.element.render(&Default::default()).unwrap()
+        };
+        let media = "@media only screen and (max-width:319px) {\n                table.mj-full-width-mobile { width: 100% !important; }";
+
+        let result = render(template(r#" fluid-on-mobile="true""#));
+        assert!(result.contains(r#"class="mj-full-width-mobile""#));
+        assert!(result.contains(media));
+
+        for fluid in ["", r#" fluid-on-mobile="false""#] {
+            let result = render(template(fluid));
+            assert!(!result.contains(r#"class="mj-full-width-mobile""#));
+        }
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_pass_through_html_attributes() {
+        let template = r##"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-image src="https://example.com/a.png" href="https://example.com" name="logo" rel="noopener" title="Home" alt="Logo" usemap="#map" />
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"##;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains(
+            r#"<a href="https://example.com" name="logo" rel="noopener" target="_blank">"#
+        ));
+        assert!(result.contains(r#" title="Home" "#));
+        assert!(result.contains(r#" alt="Logo" "#));
+        assert!(result.contains(r##" usemap="#map" "##));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_warn_about_missing_alt() {
+        use crate::prelude::parser::WarningKind;
+
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-image src="https://example.com/a.png" />
+    <mj-section>
+      <mj-column>
+        <mj-image src="https://example.com/b.png" alt="B" />
+        <mj-image src="https://example.com/c.png" />
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        assert_eq!(root.warnings.len(), 2);
+        assert!(root
+            .warnings
+            .iter()
+            .all(|warning| warning.kind == WarningKind::MissingAlt));
+        assert_eq!(
+            &template[root.warnings[0].span.start..root.warnings[0].span.end],
+            "mj-image"
+        );
+        // the warning is not fatal
+        let result = root.element.render(&Default::default()).unwrap();
+        assert_eq!(result.matches("<img ").count(), 3);
+        assert_eq!(result.matches(" alt=").count(), 1);
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_keep_empty_alt() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-image src="https://example.com/a.png" alt="" />
+    <mj-image src="https://example.com/b.png" alt />
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        assert!(root.warnings.is_empty());
+        let result = root.element.render(&Default::default()).unwrap();
+        assert_eq!(result.matches(r#"<img alt="" "#).count(), 2);
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_escape_attribute_values() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-image src="https://example.com/a.png?w=1&h=2" alt='He said "hi"' title="a\b" />
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains(r#" alt="He said &quot;hi&quot;" "#));
+        assert!(result.contains(r#" src="https://example.com/a.png?w=1&amp;h=2" "#));
+        assert!(result.contains(r#" title="a\b" "#));
+    }
 }
@@ -36,10 +36,11 @@ pub enum MjIncludeBodyChild {
     Text(crate::text::Text),
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "json", serde(rename_all = "snake_case"))]
 pub enum MjIncludeBodyKind {
+    #[default]
     Mjml,
     Html,
 }
@@ -60,12 +61,6 @@ impl MjIncludeBodyKind {
     }
 }
 
-impl Default for MjIncludeBodyKind {
-    fn default() -> Self {
-        Self::Mjml
-    }
-}
-
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 pub struct MjIncludeBodyAttributes {
@@ -3,7 +3,7 @@ use htmlparser::StrSpan;
 use super::{MjIncludeBody, MjIncludeBodyAttributes, MjIncludeBodyChild, MjIncludeBodyKind};
 use crate::comment::Comment;
 use crate::mj_accordion::NAME as MJ_ACCORDION;
-use crate::mj_body::MjBodyChild;
+use crate::mj_body::{check_image_alt, MjBodyChild};
 use crate::mj_button::NAME as MJ_BUTTON;
 use crate::mj_carousel::NAME as MJ_CAROUSEL;
 use crate::mj_column::NAME as MJ_COLUMN;
@@ -19,14 +19,51 @@ use crate::mj_spacer::NAME as MJ_SPACER;
 use crate::mj_table::NAME as MJ_TABLE;
 use crate::mj_text::NAME as MJ_TEXT;
 use crate::mj_wrapper::{MjWrapper, NAME as MJ_WRAPPER};
+use crate::node::Node;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
     Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
-    WarningKind,
 };
 use crate::text::Text;
 
+/// Converts the element built by a registered custom element, which can't be
+/// an other `mj-include` at the root of an included template.
+fn build_custom_element(
+    cursor: &MrmlCursor<'_>,
+    element: MjBodyChild,
+    tag: StrSpan<'_>,
+) -> Result<MjIncludeBodyChild, Error> {
+    Ok(match element {
+        MjBodyChild::Comment(inner) => MjIncludeBodyChild::Comment(inner),
+        MjBodyChild::MjAccordion(inner) => MjIncludeBodyChild::MjAccordion(inner),
+        MjBodyChild::MjButton(inner) => MjIncludeBodyChild::MjButton(inner),
+        MjBodyChild::MjCarousel(inner) => MjIncludeBodyChild::MjCarousel(inner),
+        MjBodyChild::MjColumn(inner) => MjIncludeBodyChild::MjColumn(inner),
+        MjBodyChild::MjDivider(inner) => MjIncludeBodyChild::MjDivider(inner),
+        MjBodyChild::MjGroup(inner) => MjIncludeBodyChild::MjGroup(inner),
+        MjBodyChild::MjHero(inner) => MjIncludeBodyChild::MjHero(inner),
+        MjBodyChild::MjImage(inner) => MjIncludeBodyChild::MjImage(inner),
+        MjBodyChild::MjNavbar(inner) => MjIncludeBodyChild::MjNavbar(inner),
+        MjBodyChild::MjRaw(inner) => MjIncludeBodyChild::MjRaw(inner),
+        MjBodyChild::MjSection(inner) => MjIncludeBodyChild::MjSection(inner),
+        MjBodyChild::MjSocial(inner) => MjIncludeBodyChild::MjSocial(inner),
+        MjBodyChild::MjSpacer(inner) => MjIncludeBodyChild::MjSpacer(inner),
+        MjBodyChild::MjTable(inner) => MjIncludeBodyChild::MjTable(inner),
+        MjBodyChild::MjText(inner) => MjIncludeBodyChild::MjText(inner),
+        MjBodyChild::MjWrapper(inner) => MjIncludeBodyChild::MjWrapper(inner),
+        MjBodyChild::Node(inner) => MjIncludeBodyChild::Node(inner),
+        MjBodyChild::Text(inner) => MjIncludeBodyChild::Text(inner),
+        MjBodyChild::MjInclude(_) => {
+            return Err(Error::UnexpectedElement {
+                origin: cursor.origin(),
+                position: tag.into(),
+                location: cursor.location(tag),
+            })
+        }
+    })
+}
+
 impl ParseElement<MjIncludeBodyChild> for MrmlParser<'_> {
     fn parse<'a>(
         &self,
@@ -41,7 +78,11 @@ impl ParseElement<MjIncludeBodyChild> for MrmlParser<'_> {
             MJ_DIVIDER => Ok(MjIncludeBodyChild::MjDivider(self.parse(cursor, tag)?)),
             MJ_GROUP => Ok(MjIncludeBodyChild::MjGroup(self.parse(cursor, tag)?)),
             MJ_HERO => Ok(MjIncludeBodyChild::MjHero(self.parse(cursor, tag)?)),
-            MJ_IMAGE => Ok(MjIncludeBodyChild::MjImage(self.parse(cursor, tag)?)),
+            MJ_IMAGE => {
+                let image = self.parse(cursor, tag)?;
+                check_image_alt(cursor, &image, tag);
+                Ok(MjIncludeBodyChild::MjImage(image))
+            }
             MJ_NAVBAR => Ok(MjIncludeBodyChild::MjNavbar(self.parse(cursor, tag)?)),
             MJ_RAW => Ok(MjIncludeBodyChild::MjRaw(self.parse(cursor, tag)?)),
             MJ_SECTION => Ok(MjIncludeBodyChild::MjSection(self.parse(cursor, tag)?)),
@@ -50,9 +91,14 @@ impl ParseElement<MjIncludeBodyChild> for MrmlParser<'_> {
             MJ_TABLE => Ok(MjIncludeBodyChild::MjTable(self.parse(cursor, tag)?)),
             MJ_TEXT => Ok(MjIncludeBodyChild::MjText(self.parse(cursor, tag)?)),
             MJ_WRAPPER => Ok(MjIncludeBodyChild::MjWrapper(self.parse(cursor, tag)?)),
+            name if self.options.custom_elements.contains(name) => {
+                let node: Node<MjBodyChild> = self.parse(cursor, tag)?;
+                build_custom_element(cursor, self.options.custom_elements.build(node), tag)
+            }
             _ => Err(Error::UnexpectedElement {
                 origin: cursor.origin(),
                 position: tag.into(),
+                location: cursor.location(tag),
             }),
         }
     }
@@ -89,9 +135,11 @@ impl AsyncParseElement<MjIncludeBodyChild> for AsyncMrmlParser {
             MJ_HERO => Ok(MjIncludeBodyChild::MjHero(
                 self.async_parse(cursor, tag).await?,
             )),
-            MJ_IMAGE => Ok(MjIncludeBodyChild::MjImage(
-                self.async_parse(cursor, tag).await?,
-            )),
+            MJ_IMAGE => {
+                let image = self.async_parse(cursor, tag).await?;
+                check_image_alt(cursor, &image, tag);
+                Ok(MjIncludeBodyChild::MjImage(image))
+            }
             MJ_NAVBAR => Ok(MjIncludeBodyChild::MjNavbar(
                 self.async_parse(cursor, tag).await?,
             )),
@@ -116,9 +164,14 @@ impl AsyncParseElement<MjIncludeBodyChild> for AsyncMrmlParser {
             MJ_WRAPPER => Ok(MjIncludeBodyChild::MjWrapper(
                 self.async_parse(cursor, tag).await?,
             )),
+            name if self.options.custom_elements.contains(name) => {
+                let node: Node<MjBodyChild> = self.async_parse(cursor, tag).await?;
+                build_custom_element(cursor, self.options.custom_elements.build(node), tag)
+            }
             _ => Err(Error::UnexpectedElement {
                 origin: cursor.origin(),
                 position: tag.into(),
+                location: cursor.location(tag),
             }),
         }
     }
@@ -137,6 +190,7 @@ impl MjIncludeBodyKind {
         Self::maybe_parse(&span).ok_or_else(|| Error::InvalidAttribute {
             origin: cursor.origin(),
             position: span.into(),
+            location: cursor.location(span),
         })
     }
 }
@@ -157,7 +211,7 @@ fn parse_attributes(
                 kind = Some(MjIncludeBodyKind::parse(cursor, value)?);
             }
             _ => {
-                cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span);
+                cursor.add_unknown_attribute(crate::mj_include::NAME, &attr)?;
             }
         }
     }
@@ -166,6 +220,7 @@ fn parse_attributes(
             name: "path",
             origin: cursor.origin(),
             position: tag.into(),
+            location: cursor.location(tag),
         })?,
         kind: kind.unwrap_or_default(),
     })
@@ -207,19 +262,25 @@ impl ParseChildren<Vec<MjIncludeBodyChild>> for MrmlParser<'_> {
                     )));
                 }
                 MrmlToken::ElementStart(inner) => {
-                    result.push(self.parse(cursor, inner.local)?);
+                    let child = self.parse(cursor, inner.local);
+                    if let Some(child) = cursor.skip_unexpected_result(inner.local, child)? {
+                        result.push(child);
+                    }
                 }
                 MrmlToken::ElementClose(inner) => {
                     cursor.rewind(MrmlToken::ElementClose(inner));
                     return Ok(result);
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjIncludeBodyChild::Text(Text::from(inner.text.as_str())));
+                    result.push(MjIncludeBodyChild::Text(Text::from(
+                        cursor.text_content(inner.text.as_str()),
+                    )));
                 }
                 other => {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
@@ -247,19 +308,25 @@ impl AsyncParseChildren<Vec<MjIncludeBodyChild>> for AsyncMrmlParser {
                     )));
                 }
                 MrmlToken::ElementStart(inner) => {
-                    result.push(self.async_parse(cursor, inner.local).await?);
+                    let child = self.async_parse(cursor, inner.local).await;
+                    if let Some(child) = cursor.skip_unexpected_result(inner.local, child)? {
+                        result.push(child);
+                    }
                 }
                 MrmlToken::ElementClose(inner) => {
                     cursor.rewind(MrmlToken::ElementClose(inner));
                     return Ok(result);
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjIncludeBodyChild::Text(Text::from(inner.text.as_str())));
+                    result.push(MjIncludeBodyChild::Text(Text::from(
+                        cursor.text_content(inner.text.as_str()),
+                    )));
                 }
                 other => {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
@@ -280,6 +347,7 @@ impl ParseElement<MjIncludeBody> for MrmlParser<'_> {
 
         // if a mj-include has some content, we don't load it
         let children: Vec<MjIncludeBodyChild> = if children.is_empty() {
+            cursor.assert_not_included(&attributes.path, tag)?;
             let child = self
                 .options
                 .include_loader
@@ -287,13 +355,14 @@ impl ParseElement<MjIncludeBody> for MrmlParser<'_> {
                 .map_err(|source| Error::IncludeLoaderError {
                     origin: cursor.origin(),
                     position: tag.into(),
-                    source,
+                    location: cursor.location(tag),
+                    source: Box::new(source),
                 })?;
             match attributes.kind {
                 MjIncludeBodyKind::Html => {
                     let mut sub = cursor.new_child(&attributes.path, child.as_str());
                     let children: Vec<MjBodyChild> = self.parse_children(&mut sub)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.with_child(sub);
                     vec![MjIncludeBodyChild::MjWrapper(MjWrapper::new(
                         Default::default(),
                         children,
@@ -302,7 +371,7 @@ impl ParseElement<MjIncludeBody> for MrmlParser<'_> {
                 MjIncludeBodyKind::Mjml => {
                     let mut sub = cursor.new_child(&attributes.path, child.as_str());
                     let children = self.parse_children(&mut sub)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.with_child(sub);
                     children
                 }
             }
@@ -330,6 +399,7 @@ impl crate::prelude::parser::AsyncParseElement<MjIncludeBody> for AsyncMrmlParse
 
         // if a mj-include has some content, we don't load it
         let children: Vec<MjIncludeBodyChild> = if children.is_empty() {
+            cursor.assert_not_included(&attributes.path, tag)?;
             let child = self
                 .options
                 .include_loader
@@ -338,7 +408,8 @@ impl crate::prelude::parser::AsyncParseElement<MjIncludeBody> for AsyncMrmlParse
                 .map_err(|source| Error::IncludeLoaderError {
                     origin: cursor.origin(),
                     position: tag.into(),
-                    source,
+                    location: cursor.location(tag),
+                    source: Box::new(source),
                 })?;
             match attributes.kind {
                 MjIncludeBodyKind::Html => {
@@ -352,7 +423,7 @@ impl crate::prelude::parser::AsyncParseElement<MjIncludeBody> for AsyncMrmlParse
                 MjIncludeBodyKind::Mjml => {
                     let mut sub = cursor.new_child(&attributes.path, child.as_str());
                     let children = self.async_parse_children(&mut sub).await?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.with_child(sub);
                     children
                 }
             }
@@ -370,7 +441,7 @@ mod tests {
 
     use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyKind};
     use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
-    use crate::prelude::parser::{MrmlCursor, MrmlParser, ParserOptions, WarningKind};
+    use crate::prelude::parser::{Error, MrmlCursor, MrmlParser, ParserOptions, WarningKind};
 
     #[test]
     fn kind_parser() {
@@ -389,14 +460,14 @@ mod tests {
         invalid_kind,
         MjIncludeBody,
         r#"<mj-include type="foo" path="basic.mjml" />"#,
-        "InvalidAttribute { origin: Root, position: Span { start: 18, end: 21 } }"
+        "InvalidAttribute { origin: Root, position: Span { start: 18, end: 21 }, location: Location { offset: 18, line: 1, column: 19 } }"
     );
 
     crate::should_not_parse!(
         not_found,
         MjIncludeBody,
         r#"<mj-include path="basic.mjml" />"#,
-        "IncludeLoaderError { origin: Root, position: Span { start: 1, end: 11 }, source: IncludeLoaderError { path: \"basic.mjml\", reason: NotFound, message: None, cause: None } }"
+        "IncludeLoaderError { origin: Root, position: Span { start: 1, end: 11 }, location: Location { offset: 1, line: 1, column: 2 }, source: IncludeLoaderError { path: \"basic.mjml\", reason: NotFound, message: None, cause: None } }"
     );
 
     crate::should_parse!(
@@ -411,6 +482,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-button>Hello</mj-button>")]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -428,6 +500,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-button>Hello</mj-button>")]);
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -439,11 +512,105 @@ mod tests {
         let _content = include.0.children.first().unwrap();
     }
 
+    #[test]
+    fn should_build_custom_element_in_included_template() {
+        use crate::mj_body::MjBodyChild;
+        use crate::mj_include::body::MjIncludeBodyChild;
+        use crate::mj_text::MjText;
+        use crate::node::Node;
+
+        let resolver = MemoryIncludeLoader::from(vec![
+            ("widget.mjml", "<my-widget />"),
+            ("other.mjml", "<other-widget />"),
+        ]);
+        let mut opts = ParserOptions {
+            include_loader: Box::new(resolver),
+            ..Default::default()
+        };
+        opts.custom_elements
+            .register("my-widget", |_: Node<MjBodyChild>| {
+                MjBodyChild::MjText(MjText::default())
+            });
+        let parser = MrmlParser::new(&opts);
+        let mut cursor = MrmlCursor::new(r#"<mj-include path="widget.mjml" />"#);
+        let include: MjIncludeBody = parser.parse_root(&mut cursor).unwrap();
+        assert!(matches!(
+            include.0.children.as_slice(),
+            [MjIncludeBodyChild::MjText(_)]
+        ));
+
+        let mut cursor = MrmlCursor::new(r#"<mj-include path="other.mjml" />"#);
+        let err = parser.parse_root::<MjIncludeBody>(&mut cursor).unwrap_err();
+        assert!(matches!(err, Error::UnexpectedElement { .. }));
+    }
+
+    #[test]
+    fn include_cycle_sync() {
+        let resolver = MemoryIncludeLoader::from(vec![
+            (
+                "first.mjml",
+                "<mj-section><mj-column><mj-include path=\"second.mjml\" /></mj-column></mj-section>",
+            ),
+            ("second.mjml", "<mj-wrapper><mj-include path=\"first.mjml\" /></mj-wrapper>"),
+        ]);
+        let opts = ParserOptions {
+            include_loader: Box::new(resolver),
+            ..Default::default()
+        };
+        let raw = r#"<mj-include path="first.mjml" />"#;
+        let mut cursor = MrmlCursor::new(raw);
+        let err = MrmlParser::new(&opts)
+            .parse_root::<MjIncludeBody>(&mut cursor)
+            .unwrap_err();
+        assert!(matches!(err, Error::IncludeCycle { ref path, .. } if path == "first.mjml"));
+        assert_eq!(
+            err.to_string(),
+            "include cycle with \"first.mjml\" in template from \"second.mjml\" at line 1, column 14"
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn include_cycle_async() {
+        use crate::prelude::parser::{AsyncMrmlParser, AsyncParserOptions};
+
+        let resolver = MemoryIncludeLoader::from(vec![(
+            "partial.html",
+            "<div><mj-include path=\"partial.html\" type=\"html\" /></div>",
+        )]);
+        let opts = AsyncParserOptions {
+            include_loader: Box::new(resolver),
+            ..Default::default()
+        };
+        let raw = r#"<mj-include path="partial.html" type="html" />"#;
+        let mut cursor = MrmlCursor::new(raw);
+        let err = AsyncMrmlParser::new(opts.into())
+            .parse_root::<MjIncludeBody>(&mut cursor)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::IncludeCycle { ref path, .. } if path == "partial.html"));
+    }
+
+    #[test]
+    fn include_same_template_twice_sync() {
+        let resolver =
+            MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-button>Hello</mj-button>")]);
+        let opts = ParserOptions {
+            include_loader: Box::new(resolver),
+            ..Default::default()
+        };
+        let raw = r#"<mj-wrapper><mj-include path="basic.mjml" /><mj-include path="basic.mjml" /></mj-wrapper>"#;
+        let mut cursor = MrmlCursor::new(raw);
+        let _: crate::mj_wrapper::MjWrapper =
+            MrmlParser::new(&opts).parse_root(&mut cursor).unwrap();
+    }
+
     #[test]
     fn type_html_in_memory_resolver_sync() {
         let resolver = MemoryIncludeLoader::from(vec![("partial.html", "<h1>Hello World!</h1>")]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="partial.html" type="html" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -460,6 +627,7 @@ mod tests {
         let resolver = MemoryIncludeLoader::from(vec![("partial.html", "<h1>Hello World!</h1>")]);
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="partial.html" type="html" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -482,7 +650,7 @@ mod tests {
     <mj-divider />
     <mj-group />
     <mj-hero />
-    <mj-image path="./here.png" />
+    <mj-image path="./here.png" alt="" />
     <mj-navbar />
     <mj-raw />
     <mj-section />
@@ -502,7 +670,7 @@ mod tests {
         r#"<mj-include path="partial.html">
     <foo />
 </mj-include>"#,
-        "UnexpectedElement { origin: Root, position: Span { start: 38, end: 41 } }"
+        "UnexpectedElement { origin: Root, position: Span { start: 38, end: 41 }, location: Location { offset: 38, line: 2, column: 6 } }"
     );
 
     crate::should_parse!(
@@ -516,7 +684,7 @@ mod tests {
         missing_path,
         MjIncludeBody,
         r#"<mj-include><!-- empty --></mj-include>"#,
-        "MissingAttribute { name: \"path\", origin: Root, position: Span { start: 1, end: 11 } }"
+        "MissingAttribute { name: \"path\", origin: Root, position: Span { start: 1, end: 11 }, location: Location { offset: 1, line: 1, column: 2 } }"
     );
 
     #[test]
@@ -527,6 +695,7 @@ mod tests {
         )]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="partial.html" type="html" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -20,6 +20,7 @@ pub enum MjIncludeHeadChild {
     MjAttributes(crate::mj_attributes::MjAttributes),
     MjBreakpoint(crate::mj_breakpoint::MjBreakpoint),
     MjFont(crate::mj_font::MjFont),
+    MjHtmlAttributes(crate::mj_html_attributes::MjHtmlAttributes),
     MjPreview(crate::mj_preview::MjPreview),
     MjRaw(crate::mj_raw::MjRaw),
     MjStyle(crate::mj_style::MjStyle),
@@ -27,13 +28,16 @@ pub enum MjIncludeHeadChild {
     Text(crate::text::Text),
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "json", serde(rename_all = "snake_case"))]
 pub enum MjIncludeHeadKind {
+    #[default]
     Mjml,
     Html,
-    Css { inline: bool },
+    Css {
+        inline: bool,
+    },
 }
 
 impl AsRef<str> for MjIncludeHeadKind {
@@ -53,12 +57,6 @@ impl MjIncludeHeadKind {
     }
 }
 
-impl Default for MjIncludeHeadKind {
-    fn default() -> Self {
-        Self::Mjml
-    }
-}
-
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 pub struct MjIncludeHeadAttributes {
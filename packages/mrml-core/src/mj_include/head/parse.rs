@@ -5,6 +5,7 @@ use crate::comment::Comment;
 use crate::mj_attributes::NAME as MJ_ATTRIBUTES;
 use crate::mj_breakpoint::NAME as MJ_BREAKPOINT;
 use crate::mj_font::NAME as MJ_FONT;
+use crate::mj_html_attributes::NAME as MJ_HTML_ATTRIBUTES;
 use crate::mj_preview::NAME as MJ_PREVIEW;
 use crate::mj_raw::NAME as MJ_RAW;
 use crate::mj_style::NAME as MJ_STYLE;
@@ -13,7 +14,6 @@ use crate::mj_title::NAME as MJ_TITLE;
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
     Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
-    WarningKind,
 };
 use crate::text::Text;
 
@@ -31,6 +31,9 @@ impl ParseElement<MjIncludeHeadChild> for MrmlParser<'_> {
                 .parse(cursor, tag)
                 .map(MjIncludeHeadChild::MjBreakpoint),
             MJ_FONT => self.parse(cursor, tag).map(MjIncludeHeadChild::MjFont),
+            MJ_HTML_ATTRIBUTES => self
+                .parse(cursor, tag)
+                .map(MjIncludeHeadChild::MjHtmlAttributes),
             MJ_PREVIEW => self.parse(cursor, tag).map(MjIncludeHeadChild::MjPreview),
             MJ_RAW => self.parse(cursor, tag).map(MjIncludeHeadChild::MjRaw),
             MJ_STYLE => self.parse(cursor, tag).map(MjIncludeHeadChild::MjStyle),
@@ -38,6 +41,7 @@ impl ParseElement<MjIncludeHeadChild> for MrmlParser<'_> {
             _ => Err(Error::UnexpectedElement {
                 origin: cursor.origin(),
                 position: tag.into(),
+                location: cursor.location(tag),
             }),
         }
     }
@@ -65,6 +69,10 @@ impl AsyncParseElement<MjIncludeHeadChild> for AsyncMrmlParser {
                 .async_parse(cursor, tag)
                 .await
                 .map(MjIncludeHeadChild::MjFont),
+            MJ_HTML_ATTRIBUTES => self
+                .async_parse(cursor, tag)
+                .await
+                .map(MjIncludeHeadChild::MjHtmlAttributes),
             MJ_PREVIEW => self
                 .async_parse(cursor, tag)
                 .await
@@ -84,6 +92,7 @@ impl AsyncParseElement<MjIncludeHeadChild> for AsyncMrmlParser {
             _ => Err(Error::UnexpectedElement {
                 origin: cursor.origin(),
                 position: tag.into(),
+                location: cursor.location(tag),
             }),
         }
     }
@@ -105,7 +114,7 @@ fn parse_attributes(
                 kind = Some(MjIncludeHeadKind::parse(cursor, value)?);
             }
             _ => {
-                cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span);
+                cursor.add_unknown_attribute(crate::mj_include::NAME, &attr)?;
             }
         }
     }
@@ -114,6 +123,7 @@ fn parse_attributes(
             name: "path",
             origin: cursor.origin(),
             position: tag.into(),
+            location: cursor.location(tag),
         })?,
         kind: kind.unwrap_or_default(),
     })
@@ -154,10 +164,15 @@ impl ParseChildren<Vec<MjIncludeHeadChild>> for MrmlParser<'_> {
                     )));
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjIncludeHeadChild::Text(Text::from(inner.text.as_str())));
+                    result.push(MjIncludeHeadChild::Text(Text::from(
+                        cursor.text_content(inner.text.as_str()),
+                    )));
                 }
                 MrmlToken::ElementStart(inner) => {
-                    result.push(self.parse(cursor, inner.local)?);
+                    let child = self.parse(cursor, inner.local);
+                    if let Some(child) = cursor.skip_unexpected_result(inner.local, child)? {
+                        result.push(child);
+                    }
                 }
                 MrmlToken::ElementClose(close) => {
                     cursor.rewind(MrmlToken::ElementClose(close));
@@ -167,6 +182,7 @@ impl ParseChildren<Vec<MjIncludeHeadChild>> for MrmlParser<'_> {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -192,10 +208,15 @@ impl AsyncParseChildren<Vec<MjIncludeHeadChild>> for AsyncMrmlParser {
                     )));
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjIncludeHeadChild::Text(Text::from(inner.text.as_str())));
+                    result.push(MjIncludeHeadChild::Text(Text::from(
+                        cursor.text_content(inner.text.as_str()),
+                    )));
                 }
                 MrmlToken::ElementStart(inner) => {
-                    result.push(self.async_parse(cursor, inner.local).await?);
+                    let child = self.async_parse(cursor, inner.local).await;
+                    if let Some(child) = cursor.skip_unexpected_result(inner.local, child)? {
+                        result.push(child);
+                    }
                 }
                 MrmlToken::ElementClose(close) => {
                     cursor.rewind(MrmlToken::ElementClose(close));
@@ -205,6 +226,7 @@ impl AsyncParseChildren<Vec<MjIncludeHeadChild>> for AsyncMrmlParser {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -231,7 +253,8 @@ impl ParseElement<MjIncludeHead> for MrmlParser<'_> {
                 .map_err(|source| Error::IncludeLoaderError {
                     origin: cursor.origin(),
                     position: tag.into(),
-                    source,
+                    location: cursor.location(tag),
+                    source: Box::new(source),
                 })?;
 
             match attributes.kind {
@@ -244,7 +267,7 @@ impl ParseElement<MjIncludeHead> for MrmlParser<'_> {
                 MjIncludeHeadKind::Mjml => {
                     let mut sub = cursor.new_child(&attributes.path, child.as_str());
                     let children = self.parse_children(&mut sub)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.with_child(sub);
                     children
                 }
                 MjIncludeHeadKind::Html => todo!(),
@@ -279,7 +302,8 @@ impl AsyncParseElement<MjIncludeHead> for AsyncMrmlParser {
                 .map_err(|source| Error::IncludeLoaderError {
                     origin: cursor.origin(),
                     position: tag.into(),
-                    source,
+                    location: cursor.location(tag),
+                    source: Box::new(source),
                 })?;
 
             match attributes.kind {
@@ -292,7 +316,7 @@ impl AsyncParseElement<MjIncludeHead> for AsyncMrmlParser {
                 MjIncludeHeadKind::Mjml => {
                     let mut sub = cursor.new_child(&attributes.path, child.as_str());
                     let children = self.async_parse_children(&mut sub).await?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.with_child(sub);
                     children
                 }
                 MjIncludeHeadKind::Html => unimplemented!(),
@@ -319,6 +343,7 @@ impl MjIncludeHeadKind {
         Self::maybe_parse(&span).ok_or_else(|| Error::InvalidAttribute {
             origin: cursor.origin(),
             position: span.into(),
+            location: cursor.location(span),
         })
     }
 }
@@ -352,14 +377,14 @@ mod tests {
         should_error_when_no_path,
         MjIncludeHead,
         "<mj-include />",
-        "MissingAttribute { name: \"path\", origin: Root, position: Span { start: 1, end: 11 } }"
+        "MissingAttribute { name: \"path\", origin: Root, position: Span { start: 1, end: 11 }, location: Location { offset: 1, line: 1, column: 2 } }"
     );
 
     crate::should_not_parse!(
         should_error_when_unknown_attribute,
         MjIncludeHead,
         r#"<mj-include unknown="yep" />"#,
-        "MissingAttribute { name: \"path\", origin: Root, position: Span { start: 1, end: 11 } }"
+        "MissingAttribute { name: \"path\", origin: Root, position: Span { start: 1, end: 11 }, location: Location { offset: 1, line: 1, column: 2 } }"
     );
 
     crate::should_parse!(
@@ -381,14 +406,14 @@ mod tests {
         should_error_unknown_children,
         MjIncludeHead,
         r#"<mj-include path="inmemory"><div /></mj-include>"#,
-        "UnexpectedElement { origin: Root, position: Span { start: 29, end: 32 } }"
+        "UnexpectedElement { origin: Root, position: Span { start: 29, end: 32 }, location: Location { offset: 29, line: 1, column: 30 } }"
     );
 
     crate::should_not_parse!(
         basic_in_noop_resolver,
         MjIncludeHead,
         r#"<mj-include path="basic.mjml" />"#,
-        "IncludeLoaderError { origin: Root, position: Span { start: 1, end: 11 }, source: IncludeLoaderError { path: \"basic.mjml\", reason: NotFound, message: None, cause: None } }"
+        "IncludeLoaderError { origin: Root, position: Span { start: 1, end: 11 }, location: Location { offset: 1, line: 1, column: 2 }, source: IncludeLoaderError { path: \"basic.mjml\", reason: NotFound, message: None, cause: None } }"
     );
 
     #[test]
@@ -397,6 +422,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-title>Hello</mj-title>")]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let parser = MrmlParser::new(&opts);
@@ -415,6 +441,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-title>Hello</mj-title>")]);
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let parser = AsyncMrmlParser::new(opts.into());
@@ -431,6 +458,7 @@ mod tests {
         let raw = r#"<mj-include path="partial.css" type="css" />"#;
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let parser = MrmlParser::new(&opts);
         let mut cursor = MrmlCursor::new(raw);
@@ -452,6 +480,7 @@ mod tests {
         let raw = r#"<mj-include path="partial.css" type="css" />"#;
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let parser = AsyncMrmlParser::new(opts.into());
         let mut cursor = MrmlCursor::new(raw);
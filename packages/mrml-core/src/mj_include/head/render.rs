@@ -51,6 +51,14 @@ impl super::MjIncludeHead {
                 })
             })
     }
+
+    pub(crate) fn mj_html_attributes_iter(&self) -> impl Iterator<Item = (&str, &str, &str)> {
+        self.0
+            .children
+            .iter()
+            .filter_map(|child| child.as_mj_html_attributes())
+            .flat_map(|child| child.mj_html_attributes_iter())
+    }
 }
 
 impl super::MjIncludeHeadKind {
@@ -26,6 +26,7 @@ mod tests {
                     "style.css",
                     ".container { background-color: #fffaee; padding: 48px 0px; }",
                 )])),
+                ..Default::default()
             },
         )
         .unwrap();
@@ -67,6 +68,7 @@ mod tests {
 .container { background-color: #fffaee; padding: 48px 0px; }
 </mj-style>"#,
                 )])),
+                ..Default::default()
             },
         )
         .unwrap();
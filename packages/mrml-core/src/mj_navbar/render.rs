@@ -238,4 +238,44 @@ mod tests {
     crate::should_render!(basic, "mj-navbar");
     crate::should_render!(align_class, "mj-navbar-align-class");
     crate::should_render!(ico, "mj-navbar-ico");
+
+    #[cfg(feature = "parse")]
+    fn render(navbar: &str) -> String {
+        let template = format!(
+            r#"<mjml><mj-head><mj-breakpoint width="400px" /></mj-head><mj-body><mj-section><mj-column>{navbar}</mj-column></mj-section></mj-body></mjml>"#
+        );
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        root.element.render(&Default::default()).unwrap()
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_hamburger_only_when_enabled() {
+        let result = render(
+            r#"<mj-navbar hamburger="hamburger"><mj-navbar-link href="/a">A</mj-navbar-link></mj-navbar>"#,
+        );
+        assert!(result.contains(r#"type="checkbox" class="mj-menu-checkbox""#));
+        assert!(result.contains(r#"<label align="center" for="#));
+        assert!(result.contains(r#"class="mj-menu-label""#));
+
+        for navbar in [
+            r#"<mj-navbar><mj-navbar-link href="/a">A</mj-navbar-link></mj-navbar>"#,
+            r#"<mj-navbar hamburger="true"><mj-navbar-link href="/a">A</mj-navbar-link></mj-navbar>"#,
+        ] {
+            let result = render(navbar);
+            assert!(!result.contains("<input"));
+            assert!(!result.contains("<label"));
+            assert!(result.contains(r#"<div class="mj-inline-links">"#));
+        }
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_use_header_breakpoint_for_hamburger() {
+        let result = render(
+            r#"<mj-navbar hamburger="hamburger"><mj-navbar-link href="/a">A</mj-navbar-link></mj-navbar>"#,
+        );
+        assert!(result
+            .contains("@media only screen and (max-width:399px) {\n          .mj-menu-checkbox"));
+    }
 }
@@ -1,3 +1,19 @@
+use super::MjRawAttributes;
+use crate::prelude::json::JsonAttributes;
+
+impl JsonAttributes for MjRawAttributes {
+    fn has_attributes(&self) -> bool {
+        self.mso_only
+    }
+
+    fn try_from_serde<Err: serde::de::Error>(this: Option<Self>) -> Result<Self, Err>
+    where
+        Self: Sized,
+    {
+        Ok(this.unwrap_or_default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::mj_raw::{MjRaw, MjRawChild};
@@ -22,4 +38,12 @@ mod tests {
         let next = serde_json::to_string(&res).unwrap();
         assert_eq!(next, json);
     }
+
+    #[test]
+    fn mso_only() {
+        let json = r#"{"type":"mj-raw","attributes":{"mso-only":true},"children":["Hello"]}"#;
+        let res: MjRaw = serde_json::from_str(json).unwrap();
+        assert!(res.attributes.mso_only);
+        assert_eq!(serde_json::to_string(&res).unwrap(), json);
+    }
 }
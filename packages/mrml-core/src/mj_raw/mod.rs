@@ -24,4 +24,20 @@ impl StaticTag for MjRawTag {
     }
 }
 
-pub type MjRaw = Component<PhantomData<MjRawTag>, (), Vec<MjRawChild>>;
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct MjRawAttributes {
+    /// Wraps the content in a conditional comment so that only Outlook renders
+    /// it, set with `mso-only="true"`.
+    #[cfg_attr(
+        feature = "json",
+        serde(
+            rename = "mso-only",
+            default,
+            skip_serializing_if = "std::ops::Not::not"
+        )
+    )]
+    pub mso_only: bool,
+}
+
+pub type MjRaw = Component<PhantomData<MjRawTag>, MjRawAttributes, Vec<MjRawChild>>;
@@ -1,6 +1,6 @@
 use htmlparser::StrSpan;
 
-use super::MjRawChild;
+use super::{MjRawAttributes, MjRawChild};
 use crate::comment::Comment;
 use crate::node::Node;
 use crate::prelude::is_void_element;
@@ -11,6 +11,43 @@ use crate::prelude::parser::{
 };
 use crate::text::Text;
 
+#[inline]
+fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjRawAttributes, Error> {
+    let mut result = MjRawAttributes::default();
+    while let Some(attr) = cursor.next_attribute()? {
+        match (attr.local.as_str(), attr.value) {
+            ("mso-only", Some(value)) => {
+                result.mso_only = value.as_str() == "true";
+            }
+            _ => {
+                cursor.add_unknown_attribute(super::NAME, &attr)?;
+            }
+        }
+    }
+    Ok(result)
+}
+
+impl ParseAttributes<MjRawAttributes> for MrmlParser<'_> {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        _tag: &StrSpan<'_>,
+    ) -> Result<MjRawAttributes, Error> {
+        parse_attributes(cursor)
+    }
+}
+
+#[cfg(feature = "async")]
+impl ParseAttributes<MjRawAttributes> for AsyncMrmlParser {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        _tag: &StrSpan<'_>,
+    ) -> Result<MjRawAttributes, Error> {
+        parse_attributes(cursor)
+    }
+}
+
 impl ParseElement<Node<MjRawChild>> for MrmlParser<'_> {
     fn parse<'a>(
         &self,
@@ -81,7 +118,13 @@ impl ParseChildren<Vec<MjRawChild>> for MrmlParser<'_> {
                     children.push(MjRawChild::Node(self.parse(cursor, elt.local)?));
                 }
                 MrmlToken::Text(inner) => {
-                    children.push(MjRawChild::Text(Text::from(inner.text.as_str())));
+                    children.push(MjRawChild::Text(Text::from(
+                        cursor.text_content(inner.text.as_str()),
+                    )));
+                }
+                MrmlToken::ConditionalCommentStart(inner) => {
+                    let raw = cursor.read_conditional_comment(inner)?;
+                    children.push(MjRawChild::Text(Text::from(cursor.text_content(raw))));
                 }
                 MrmlToken::ElementClose(close) => {
                     cursor.rewind(MrmlToken::ElementClose(close));
@@ -91,6 +134,7 @@ impl ParseChildren<Vec<MjRawChild>> for MrmlParser<'_> {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
@@ -117,7 +161,13 @@ impl AsyncParseChildren<Vec<MjRawChild>> for AsyncMrmlParser {
                     children.push(MjRawChild::Node(self.async_parse(cursor, elt.local).await?));
                 }
                 MrmlToken::Text(inner) => {
-                    children.push(MjRawChild::Text(Text::from(inner.text.as_str())));
+                    children.push(MjRawChild::Text(Text::from(
+                        cursor.text_content(inner.text.as_str()),
+                    )));
+                }
+                MrmlToken::ConditionalCommentStart(inner) => {
+                    let raw = cursor.read_conditional_comment(inner)?;
+                    children.push(MjRawChild::Text(Text::from(cursor.text_content(raw))));
                 }
                 MrmlToken::ElementClose(close) => {
                     cursor.rewind(MrmlToken::ElementClose(close));
@@ -127,9 +177,46 @@ impl AsyncParseChildren<Vec<MjRawChild>> for AsyncMrmlParser {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_raw::MjRaw;
+
+    crate::should_parse!(
+        conditional_comment,
+        MjRaw,
+        "<mj-raw><!--[if mso]><table><tr><td><![endif]--><p>Hello</p></mj-raw>"
+    );
+
+    crate::should_parse!(
+        nested_conditional_comment,
+        MjRaw,
+        "<mj-raw><div><!--[if !mso]><!--><p>Hello</p><!--<![endif]--></div></mj-raw>"
+    );
+
+    crate::should_parse!(
+        mso_only,
+        MjRaw,
+        r#"<mj-raw mso-only="true"><p>Hello</p></mj-raw>"#
+    );
+
+    crate::should_parse!(
+        unexpected_attribute,
+        MjRaw,
+        r#"<mj-raw foo="bar"><p>Hello</p></mj-raw>"#,
+        1
+    );
+
+    crate::should_not_parse!(
+        unclosed_conditional_comment,
+        MjRaw,
+        "<mj-raw><!--[if mso]><table></mj-raw>"
+    );
+}
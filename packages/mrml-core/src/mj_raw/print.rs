@@ -1,3 +1,14 @@
+use crate::prelude::print::PrintableAttributes;
+
+impl PrintableAttributes for super::MjRawAttributes {
+    fn print<P: crate::prelude::print::Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        if self.mso_only {
+            printer.push_attribute("mso-only", "true")?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::print::Printable;
@@ -7,4 +18,11 @@ mod tests {
         let item = crate::mj_raw::MjRaw::default();
         assert_eq!("<mj-raw />", item.print_dense().unwrap());
     }
+
+    #[test]
+    fn mso_only() {
+        let mut item = crate::mj_raw::MjRaw::default();
+        item.attributes.mso_only = true;
+        assert_eq!("<mj-raw mso-only=\"true\" />", item.print_dense().unwrap());
+    }
 }
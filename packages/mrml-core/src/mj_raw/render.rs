@@ -8,7 +8,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjRawChild {
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
         match self {
-            Self::Comment(elt) => elt.renderer(context),
+            Self::Comment(elt) => elt.kept_renderer(context),
             Self::Node(elt) => elt.renderer(context),
             Self::Text(elt) => elt.renderer(context),
         }
@@ -29,6 +29,10 @@ impl<'root> Render<'root> for Renderer<'root, MjRaw, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let mso_only = self.element.attributes.mso_only;
+        if mso_only {
+            cursor.buffer.start_mso_conditional_tag();
+        }
         let siblings = self.element.children.len();
         for (index, child) in self.element.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
@@ -38,6 +42,9 @@ impl<'root> Render<'root> for Renderer<'root, MjRaw, ()> {
             renderer.set_container_width(self.container_width);
             renderer.render(cursor)?;
         }
+        if mso_only {
+            cursor.buffer.end_conditional_tag();
+        }
         Ok(())
     }
 }
@@ -55,4 +62,100 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjRaw {
 mod tests {
     crate::should_render!(basic, "mj-raw");
     crate::should_render!(in_head, "mj-raw-head");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_keep_comments_when_disabled() {
+        let opts = crate::prelude::render::RenderOptions {
+            disable_comments: true,
+            ..Default::default()
+        };
+        let template = r#"<mjml>
+  <mj-body>
+    <!-- body comment -->
+    <mj-raw>
+      <!-- raw comment -->
+      <!--[if mso]><table><tr><td><![endif]-->
+      <div><!-- nested comment --></div>
+    </mj-raw>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(!result.contains("body comment"));
+        assert!(result.contains("<!-- raw comment -->"));
+        assert!(result.contains("<!--[if mso]><table><tr><td><![endif]-->"));
+        assert!(result.contains("<div><!-- nested comment --></div>"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_keep_conditional_comments() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-raw>
+      <!--[if mso]><style>.outlook { display: none; }</style><![endif]-->
+    </mj-raw>
+  </mj-head>
+  <mj-body>
+    <mj-raw>
+      <!--[if mso]><table><tr><td width="600"><![endif]-->
+    </mj-raw>
+    <mj-raw>
+      <!--[if mso]></td></tr></table><![endif]-->
+      <!--[if !mso]><!--><img src="https://example.com/pixel.png" /><!--<![endif]-->
+    </mj-raw>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(
+            result.contains("<!--[if mso]><style>.outlook { display: none; }</style><![endif]-->")
+        );
+        assert!(result.contains(r#"<!--[if mso]><table><tr><td width="600"><![endif]-->"#));
+        assert!(result.contains("<!--[if mso]></td></tr></table><![endif]-->"));
+        assert!(result.contains(
+            r#"<!--[if !mso]><!--><img src="https://example.com/pixel.png" /><!--<![endif]-->"#
+        ));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_keep_attributes_order() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-raw>
+      <a title="Home" href="https://example.com" data-id="1" class="link">Home</a>
+      <a class="link" data-id="2" href="https://example.com" title="Home">Home</a>
+    </mj-raw>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains(
+            r#"<a title="Home" href="https://example.com" data-id="1" class="link">Home</a>"#
+        ));
+        assert!(result.contains(
+            r#"<a class="link" data-id="2" href="https://example.com" title="Home">Home</a>"#
+        ));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_wrap_mso_only_content() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-raw mso-only="true"><table><tr><td>outlook</td></tr></table></mj-raw>
+    <mj-raw><p>everyone</p></mj-raw>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        assert!(root.warnings.is_empty());
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(
+            result.contains("<!--[if mso]><table><tr><td>outlook</td></tr></table><![endif]-->")
+        );
+        assert!(result.contains("<p>everyone</p>"));
+        assert!(!result.contains("<!--[if mso]><p>everyone</p>"));
+    }
 }
@@ -220,13 +220,31 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
         self.attribute_exists("full-width")
     }
 
+    /// Checks if some columns should be reordered when stacked, with the
+    /// `mobile-order` attribute.
+    fn has_mobile_order(&self) -> bool {
+        fn contains_mobile_order(children: &[crate::mj_body::MjBodyChild]) -> bool {
+            children.iter().any(|child| match child {
+                crate::mj_body::MjBodyChild::MjColumn(column) => {
+                    column.attributes.contains_key("mobile-order")
+                }
+                crate::mj_body::MjBodyChild::MjGroup(group) => {
+                    contains_mobile_order(&group.children)
+                }
+                _ => false,
+            })
+        }
+
+        contains_mobile_order(self.children())
+    }
+
     fn render_with_background<F>(&self, cursor: &mut RenderCursor, content: F) -> Result<(), Error>
     where
         F: Fn(&mut RenderCursor) -> Result<(), Error>,
     {
         let full_width = self.is_full_width();
         let vrect = Tag::new("v:rect")
-            .maybe_add_attribute(
+            .maybe_add_style(
                 "mso-width-percent",
                 if full_width { Some("1000") } else { None },
             )
@@ -324,6 +342,8 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
     fn render_wrapped_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let siblings = self.get_siblings();
         let raw_siblings = self.get_raw_siblings();
+        let auto_width =
+            crate::mj_column::auto_width(self.context(), self.children(), *self.container_width());
         let tr = Tag::tr();
 
         tr.render_open(&mut cursor.buffer)?;
@@ -332,6 +352,7 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
             renderer.set_siblings(siblings);
             renderer.set_raw_siblings(raw_siblings);
             renderer.set_container_width(*self.container_width());
+            renderer.set_auto_width(auto_width);
             if child.is_raw() {
                 cursor.buffer.end_conditional_tag();
                 renderer.render(cursor)?;
@@ -415,7 +436,10 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
         );
         let tbody = Tag::tbody();
         let tr = Tag::tr();
-        let td = self.set_style_section_td(Tag::td());
+        let td = self.set_style_section_td(Tag::td()).maybe_add_class(
+            self.has_mobile_order()
+                .then_some(crate::mj_column::MOBILE_REORDER_CLASS),
+        );
         let inner_table = Tag::table_presentation();
 
         let has_bg = self.has_background();
@@ -480,6 +504,7 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
         td.render_open(&mut cursor.buffer)?;
         //
         if self.has_background() {
+            cursor.buffer.start_conditional_tag();
             self.render_with_background(cursor, |cursor| {
                 self.render_wrap(cursor, |cursor| {
                     cursor.buffer.end_conditional_tag();
@@ -488,6 +513,7 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
                     Ok(())
                 })
             })?;
+            cursor.buffer.end_conditional_tag();
         } else {
             self.render_wrap(cursor, |cursor| {
                 cursor.buffer.end_conditional_tag();
@@ -540,7 +566,7 @@ impl<'root> Render<'root> for Renderer<'root, MjSection, ()> {
             "background-position" => Some(DEFAULT_BACKGROUND_POSITION),
             "background-repeat" => Some(DEFAULT_BACKGROUND_REPEAT),
             "background-size" => Some(DEFAULT_BACKGROUND_SIZE),
-            "direction" => Some("ltr"),
+            "direction" => Some(self.context.header.direction()),
             "padding" => Some("20px 0"),
             "text-align" => Some("center"),
             "text-padding" => Some("4px 4px 4px 0"),
@@ -602,4 +628,99 @@ mod tests {
     crate::should_render!(full_width, "mj-section-full-width");
     crate::should_render!(padding, "mj-section-padding");
     crate::should_render!(text_align, "mj-section-text-align");
+
+    #[test]
+    fn should_render_full_width_background_url() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r#"<mjml>
+  <mj-body>
+    <mj-section full-width="full-width" background-url="https://example.com/bg.png" background-size="contain" background-repeat="no-repeat">
+      <mj-column>
+        <mj-text>Hello World!</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains(
+            r#"<v:rect xmlns:v="urn:schemas-microsoft-com:vml" fill="true" stroke="false" style="mso-width-percent:1000;">"#
+        ));
+        assert_eq!(
+            output.matches("<!--[if ").count(),
+            output.matches("<![endif]-->").count()
+        );
+    }
+
+    #[test]
+    fn should_size_vml_with_percentage_columns() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r#"<mjml><mj-body width="500px"><mj-section background-url="https://example.com/bg.png"><mj-column width="50%"><mj-text>a</mj-text></mj-column><mj-column width="50%"><mj-text>b</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains(
+            r#"<v:rect xmlns:v="urn:schemas-microsoft-com:vml" fill="true" stroke="false" style="width:500px;">"#
+        ));
+        assert_eq!(output.matches("width:250px;").count(), 2);
+    }
+
+    #[test]
+    fn should_render_background_size_and_position() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r#"<mjml><mj-body><mj-section background-url="https://example.com/bg.png" background-size="cover" background-position="left bottom"><mj-column><mj-text>a</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains(
+            r#"<v:fill position="0, 1" origin="0, 1" src="https://example.com/bg.png" size="1,1" type="tile" aspect="atleast" />"#
+        ));
+        assert!(output.contains("background:url('https://example.com/bg.png') left bottom / cover repeat;background-position:left bottom;background-repeat:repeat;background-size:cover;"));
+    }
+
+    #[test]
+    fn should_add_outer_table_in_full_width() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let render = |attributes: &str| {
+            let source = format!(
+                "<mjml><mj-body><mj-section background-color=\"red\"{attributes}><mj-column><mj-text>a</mj-text></mj-column></mj-section></mj-body></mjml>"
+            );
+            let root = Mjml::parse(source).unwrap();
+            root.element.render(&RenderOptions::default()).unwrap()
+        };
+        let outer = r#"<table border="0" cellpadding="0" cellspacing="0" role="presentation" align="center" style="background:red;background-color:red;width:100%;">"#;
+        let normal = render("");
+        let full_width = render(r#" full-width="full-width""#);
+        // the background table wraps the centered content only in full width
+        let wrapper = "max-width:600px;";
+        assert!(normal.find(outer).unwrap() > normal.find(wrapper).unwrap());
+        assert!(full_width.find(outer).unwrap() < full_width.find(wrapper).unwrap());
+        assert_eq!(
+            full_width.matches("<table").count(),
+            normal.matches("<table").count() + 1
+        );
+        assert!(full_width.contains("margin:0px auto;max-width:600px;"));
+    }
+
+    #[test]
+    fn should_merge_adjacent_conditional_comments() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r#"<mjml><mj-body><mj-wrapper><mj-section><mj-column><mj-text>a</mj-text></mj-column><mj-column><mj-text>b</mj-text></mj-column></mj-section><mj-section><mj-column><mj-text>c</mj-text></mj-column></mj-section></mj-wrapper></mj-body></mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(!output.contains("<![endif]--><!--[if mso | IE]>"));
+        // the closing of the first section and the opening of the second one
+        // are in the same conditional comment
+        assert!(output.contains(
+            r#"<!--[if mso | IE]></td></tr></table></td></tr><tr><td width="600px"><table "#
+        ));
+    }
 }
@@ -0,0 +1,44 @@
+use crate::prelude::json::JsonAttributes;
+
+impl JsonAttributes for super::MjSelectorAttributes {
+    fn has_attributes(&self) -> bool {
+        true
+    }
+
+    fn try_from_serde<Err: serde::de::Error>(this: Option<Self>) -> Result<Self, Err>
+    where
+        Self: Sized,
+    {
+        this.ok_or_else(|| serde::de::Error::missing_field("attributes"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_html_attribute::MjHtmlAttribute;
+    use crate::mj_selector::{MjSelector, MjSelectorAttributes};
+
+    #[test]
+    fn serialize() {
+        let elt = MjSelector::new(
+            MjSelectorAttributes {
+                path: ".cta a".into(),
+            },
+            vec![MjHtmlAttribute::build("data-id", "42")],
+        );
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-selector","attributes":{"path":".cta a"},"children":[{"type":"mj-html-attribute","attributes":{"name":"data-id"},"children":"42"}]}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-selector","attributes":{"path":".cta a"},"children":[{"type":"mj-html-attribute","attributes":{"name":"data-id"},"children":"42"}]}"#;
+        let res: MjSelector = serde_json::from_str(json).unwrap();
+        assert_eq!(res.path(), ".cta a");
+        assert_eq!(res.children.len(), 1);
+        let next = serde_json::to_string(&res).unwrap();
+        assert_eq!(next, json);
+    }
+}
@@ -0,0 +1,152 @@
+use htmlparser::StrSpan;
+
+use super::MjSelectorAttributes;
+use crate::mj_html_attribute::{MjHtmlAttribute, NAME as MJ_HTML_ATTRIBUTE};
+#[cfg(feature = "async")]
+use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
+use crate::prelude::parser::{
+    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
+};
+
+#[inline(always)]
+fn parse_attributes(
+    cursor: &mut MrmlCursor<'_>,
+    tag: &StrSpan<'_>,
+) -> Result<MjSelectorAttributes, Error> {
+    let mut path = None;
+
+    while let Some(attrs) = cursor.next_attribute()? {
+        match (attrs.local.as_str(), attrs.value) {
+            ("path", Some(value)) => path = Some(value.to_string()),
+            _ => cursor.add_unknown_attribute(super::NAME, &attrs)?,
+        }
+    }
+
+    Ok(MjSelectorAttributes {
+        path: path.ok_or_else(|| Error::MissingAttribute {
+            name: "path",
+            origin: cursor.origin(),
+            position: tag.into(),
+            location: cursor.location(tag),
+        })?,
+    })
+}
+
+impl ParseAttributes<MjSelectorAttributes> for MrmlParser<'_> {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        tag: &StrSpan<'_>,
+    ) -> Result<MjSelectorAttributes, Error> {
+        parse_attributes(cursor, tag)
+    }
+}
+
+#[cfg(feature = "async")]
+impl ParseAttributes<MjSelectorAttributes> for AsyncMrmlParser {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        tag: &StrSpan<'_>,
+    ) -> Result<MjSelectorAttributes, Error> {
+        parse_attributes(cursor, tag)
+    }
+}
+
+impl ParseChildren<Vec<MjHtmlAttribute>> for MrmlParser<'_> {
+    fn parse_children(&self, cursor: &mut MrmlCursor<'_>) -> Result<Vec<MjHtmlAttribute>, Error> {
+        let mut result = Vec::new();
+
+        loop {
+            match cursor.assert_next()? {
+                MrmlToken::ElementStart(inner) if inner.local.as_str() == MJ_HTML_ATTRIBUTE => {
+                    result.push(self.parse(cursor, inner.local)?);
+                }
+                MrmlToken::ElementStart(inner) => {
+                    cursor.skip_unexpected_element(Error::UnexpectedElement {
+                        origin: cursor.origin(),
+                        position: inner.local.into(),
+                        location: cursor.location(inner.local),
+                    })?;
+                }
+                MrmlToken::ElementClose(inner) => {
+                    cursor.rewind(MrmlToken::ElementClose(inner));
+                    return Ok(result);
+                }
+                other => {
+                    return Err(Error::UnexpectedToken {
+                        origin: cursor.origin(),
+                        position: other.span(),
+                        location: cursor.location(other.span()),
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AsyncParseChildren<Vec<MjHtmlAttribute>> for AsyncMrmlParser {
+    async fn async_parse_children<'a>(
+        &self,
+        cursor: &mut MrmlCursor<'a>,
+    ) -> Result<Vec<MjHtmlAttribute>, Error> {
+        let mut result = Vec::new();
+
+        loop {
+            match cursor.assert_next()? {
+                MrmlToken::ElementStart(inner) if inner.local.as_str() == MJ_HTML_ATTRIBUTE => {
+                    result.push(self.async_parse(cursor, inner.local).await?);
+                }
+                MrmlToken::ElementStart(inner) => {
+                    cursor.skip_unexpected_element(Error::UnexpectedElement {
+                        origin: cursor.origin(),
+                        position: inner.local.into(),
+                        location: cursor.location(inner.local),
+                    })?;
+                }
+                MrmlToken::ElementClose(inner) => {
+                    cursor.rewind(MrmlToken::ElementClose(inner));
+                    return Ok(result);
+                }
+                other => {
+                    return Err(Error::UnexpectedToken {
+                        origin: cursor.origin(),
+                        position: other.span(),
+                        location: cursor.location(other.span()),
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_selector::MjSelector;
+
+    crate::should_parse!(
+        success,
+        MjSelector,
+        r#"<mj-selector path=".cta a">
+    <mj-html-attribute name="data-id">42</mj-html-attribute>
+    <mj-html-attribute name="data-name">button</mj-html-attribute>
+</mj-selector>"#
+    );
+
+    crate::should_not_sync_parse!(
+        should_have_path,
+        MjSelector,
+        r#"<mj-selector></mj-selector>"#,
+        "MissingAttribute { name: \"path\", origin: Root, position: Span { start: 1, end: 12 }, location: Location { offset: 1, line: 1, column: 2 } }"
+    );
+
+    crate::should_not_sync_parse!(
+        unexpected_element,
+        MjSelector,
+        r#"<mj-selector path=".cta"><mj-title>Hello</mj-title></mj-selector>"#,
+        "UnexpectedElement { origin: Root, position: Span { start: 26, end: 34 }, location: Location { offset: 26, line: 1, column: 27 } }"
+    );
+}
@@ -0,0 +1,28 @@
+use crate::prelude::print::PrintableAttributes;
+
+impl PrintableAttributes for super::MjSelectorAttributes {
+    fn print<P: crate::prelude::print::Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        printer.push_attribute("path", self.path.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_html_attribute::MjHtmlAttribute;
+    use crate::mj_selector::{MjSelector, MjSelectorAttributes};
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn normal() {
+        let item = MjSelector::new(
+            MjSelectorAttributes {
+                path: ".cta a".into(),
+            },
+            vec![MjHtmlAttribute::build("data-id", "42")],
+        );
+        assert_eq!(
+            r#"<mj-selector path=".cta a"><mj-html-attribute name="data-id">42</mj-html-attribute></mj-selector>"#,
+            item.print_dense().unwrap()
+        );
+    }
+}
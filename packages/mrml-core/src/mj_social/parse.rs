@@ -22,10 +22,11 @@ impl ParseChildren<Vec<MjSocialChild>> for MrmlParser<'_> {
                             self.parse(cursor, inner.local)?,
                         ));
                     } else {
-                        return Err(Error::UnexpectedElement {
+                        cursor.skip_unexpected_element(Error::UnexpectedElement {
                             origin: cursor.origin(),
                             position: inner.span.into(),
-                        });
+                            location: cursor.location(inner.span),
+                        })?;
                     }
                 }
                 MrmlToken::ElementClose(inner) => {
@@ -36,6 +37,7 @@ impl ParseChildren<Vec<MjSocialChild>> for MrmlParser<'_> {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
@@ -64,10 +66,11 @@ impl AsyncParseChildren<Vec<MjSocialChild>> for AsyncMrmlParser {
                             self.async_parse(cursor, inner.local).await?,
                         ));
                     } else {
-                        return Err(Error::UnexpectedElement {
+                        cursor.skip_unexpected_element(Error::UnexpectedElement {
                             origin: cursor.origin(),
                             position: inner.span.into(),
-                        });
+                            location: cursor.location(inner.span),
+                        })?;
                     }
                 }
                 MrmlToken::ElementClose(inner) => {
@@ -78,6 +81,7 @@ impl AsyncParseChildren<Vec<MjSocialChild>> for AsyncMrmlParser {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     })
                 }
             }
@@ -111,12 +115,12 @@ mod tests {
     assert_fail!(
         should_error_with_text,
         "<mj-social>Hello</mj-social>",
-        "UnexpectedToken { origin: Root, position: Span { start: 11, end: 16 } }"
+        "UnexpectedToken { origin: Root, position: Span { start: 11, end: 16 }, location: Location { offset: 11, line: 1, column: 12 } }"
     );
 
     assert_fail!(
         should_error_with_other_element,
         "<mj-social><span /></mj-social>",
-        "UnexpectedElement { origin: Root, position: Span { start: 11, end: 16 } }"
+        "UnexpectedElement { origin: Root, position: Span { start: 11, end: 16 }, location: Location { offset: 11, line: 1, column: 12 } }"
     );
 }
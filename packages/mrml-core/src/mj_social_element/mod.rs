@@ -11,6 +11,9 @@ mod render;
 
 use std::marker::PhantomData;
 
+#[cfg(feature = "render")]
+pub use network::{default_social_networks, SocialNetwork};
+
 use crate::mj_raw::MjRawChild;
 use crate::prelude::{Component, StaticTag};
 
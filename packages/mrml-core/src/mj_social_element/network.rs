@@ -1,222 +1,144 @@
-pub struct SocialNetwork {
-    background_color: &'static str,
-    share_url: Option<&'static str>,
-    icon: &'static str,
-}
-
-impl SocialNetwork {
-    pub fn background_color(&self) -> &str {
-        self.background_color
-    }
+use std::borrow::Cow;
+use std::collections::HashMap;
 
-    pub fn share_url(&self, url: &str) -> Option<String> {
-        self.share_url
-            .as_ref()
-            .map(|share_url| share_url.replace("[[URL]]", url))
-    }
+/// Suffix of the name of a network to link to the profile instead of sharing
+/// the link, like `facebook-noshare`.
+const NOSHARE_SUFFIX: &str = "-noshare";
 
-    pub fn icon_src(&self, origin: &str) -> String {
-        format!("{}{}", origin, self.icon)
-    }
+/// Icon, color and share link of a network used by the `mj-social-element`
+/// with the matching `name`, see
+/// [`RenderOptions::social_networks`](crate::prelude::render::RenderOptions::social_networks).
+#[derive(Clone, Debug)]
+pub struct SocialNetwork {
+    background_color: Cow<'static, str>,
+    share_url: Option<Cow<'static, str>>,
+    icon: Cow<'static, str>,
 }
 
 impl SocialNetwork {
-    // convert to a TryFrom with proper error handling
-    pub fn find(name: &str) -> Option<Self> {
-        let (name, noshare) = if name.ends_with("-noshare") {
-            let (label, _noshare) = name.split_at(name.len() - 8);
-            (label, true)
-        } else {
-            (name, false)
-        };
-
-        match name {
-            "dribbble" => Some(Self::dribbble()),
-            "facebook" => Some(Self::facebook(noshare)),
-            "github" => Some(Self::github()),
-            "google" => Some(Self::google(noshare)),
-            "instagram" => Some(Self::instagram()),
-            "linkedin" => Some(Self::linkedin(noshare)),
-            "medium" => Some(Self::medium()),
-            "pinterest" => Some(Self::pinterest(noshare)),
-            "snapchat" => Some(Self::snapchat()),
-            "soundcloud" => Some(Self::soundcloud()),
-            "tumblr" => Some(Self::tumblr(noshare)),
-            "twitter" => Some(Self::twitter(noshare)),
-            "vimeo" => Some(Self::vimeo()),
-            "web" => Some(Self::web()),
-            "xing" => Some(Self::xing(noshare)),
-            "youtube" => Some(Self::youtube()),
-            "x" => Some(Self::x(noshare)),
-            _ => None,
-        }
-    }
-
-    fn dribbble() -> Self {
-        Self {
-            background_color: "#D95988",
+    /// The `icon` is either the name of a file hosted with the default icons,
+    /// at the [`social_icon_origin`](crate::prelude::render::RenderOptions::social_icon_origin),
+    /// or an absolute url.
+    pub fn new<C: Into<Cow<'static, str>>, I: Into<Cow<'static, str>>>(
+        background_color: C,
+        icon: I,
+    ) -> Self {
+        Self {
+            background_color: background_color.into(),
             share_url: None,
-            icon: "dribbble.png",
+            icon: icon.into(),
         }
     }
 
-    fn facebook(noshare: bool) -> Self {
-        Self {
-            background_color: "#3b5998",
-            share_url: if noshare {
-                None
-            } else {
-                Some("https://www.facebook.com/sharer/sharer.php?u=[[URL]]")
-            },
-            icon: "facebook.png",
-        }
-    }
-
-    fn github() -> Self {
-        Self {
-            background_color: "#000000",
-            share_url: None,
-            icon: "github.png",
-        }
-    }
-
-    fn google(noshare: bool) -> Self {
-        Self {
-            background_color: "#dc4e41",
-            share_url: if noshare {
-                None
-            } else {
-                Some("https://plus.google.com/share?url=[[URL]]")
-            },
-            icon: "google-plus.png",
-        }
-    }
-
-    fn instagram() -> Self {
-        Self {
-            background_color: "#3f729b",
-            share_url: None,
-            icon: "instagram.png",
-        }
-    }
-
-    fn linkedin(noshare: bool) -> Self {
-        Self {
-            background_color: "#0077b5",
-            share_url: if noshare {
-                None
-            } else {
-                Some("https://www.linkedin.com/shareArticle?mini=true&url=[[URL]]&title=&summary=&source=")
-            },
-            icon: "linkedin.png",
-        }
-    }
-
-    fn medium() -> Self {
-        Self {
-            background_color: "#000000",
-            share_url: None,
-            icon: "medium.png",
-        }
+    /// Link used to share the `href` of the element, where `[[URL]]` is
+    /// replaced by the `href`.
+    pub fn with_share_url<V: Into<Cow<'static, str>>>(mut self, value: V) -> Self {
+        self.share_url = Some(value.into());
+        self
     }
 
-    fn pinterest(noshare: bool) -> Self {
-        Self {
-            background_color: "#bd081c",
-            share_url: if noshare {
-                None
-            } else {
-                Some("https://pinterest.com/pin/create/button/?url=[[URL]]&media=&description=")
-            },
-            icon: "pinterest.png",
-        }
-    }
-
-    fn snapchat() -> Self {
-        Self {
-            background_color: "#FFFA54",
-            share_url: None,
-            icon: "snapchat.png",
-        }
-    }
-
-    fn soundcloud() -> Self {
-        Self {
-            background_color: "#EF7F31",
-            share_url: None,
-            icon: "soundcloud.png",
-        }
-    }
-
-    fn tumblr(noshare: bool) -> Self {
-        Self {
-            background_color: "#344356",
-            share_url: if noshare {
-                None
-            } else {
-                Some("https://www.tumblr.com/widgets/share/tool?canonicalUrl=[[URL]]")
-            },
-            icon: "tumblr.png",
-        }
-    }
-
-    fn twitter(noshare: bool) -> Self {
-        Self {
-            background_color: "#55acee",
-            share_url: if noshare {
-                None
-            } else {
-                Some("https://twitter.com/home?status=[[URL]]")
-            },
-            icon: "twitter.png",
-        }
-    }
-
-    fn x(noshare: bool) -> Self {
-        Self {
-            background_color: "#000000",
-            share_url: if noshare {
-                None
-            } else {
-                Some("https://twitter.com/home?status=[[URL]]")
-            },
-            icon: "twitter-x.png",
-        }
+    pub fn background_color(&self) -> &str {
+        &self.background_color
     }
 
-    fn vimeo() -> Self {
-        Self {
-            background_color: "#53B4E7",
-            share_url: None,
-            icon: "vimeo.png",
-        }
+    pub fn share_url(&self, url: &str) -> Option<String> {
+        self.share_url
+            .as_ref()
+            .map(|share_url| share_url.replace("[[URL]]", url))
     }
 
-    fn web() -> Self {
-        Self {
-            background_color: "#4BADE9",
-            share_url: None,
-            icon: "web.png",
+    pub fn icon_src(&self, origin: &str) -> String {
+        if self.icon.starts_with("https://")
+            || self.icon.starts_with("http://")
+            || self.icon.starts_with("//")
+        {
+            self.icon.to_string()
+        } else {
+            format!("{}{}", origin, self.icon)
         }
     }
+}
 
-    fn xing(noshare: bool) -> Self {
-        Self {
-            background_color: "#296366",
-            share_url: if noshare {
-                None
-            } else {
-                Some("https://www.xing.com/app/user?op=share&url=[[URL]]")
-            },
-            icon: "xing.png",
-        }
+/// Finds the network of an element given its name, with `true` when the link
+/// shouldn't be shared.
+pub(crate) fn find_social_network<'a>(
+    networks: &'a HashMap<String, SocialNetwork>,
+    name: &str,
+) -> Option<(&'a SocialNetwork, bool)> {
+    match name.strip_suffix(NOSHARE_SUFFIX) {
+        Some(label) => networks.get(label).map(|network| (network, true)),
+        None => networks.get(name).map(|network| (network, false)),
     }
+}
 
-    fn youtube() -> Self {
-        Self {
-            background_color: "#EB3323",
-            share_url: None,
-            icon: "youtube.png",
-        }
-    }
+/// The networks supported by mjml.
+pub fn default_social_networks() -> HashMap<String, SocialNetwork> {
+    HashMap::from([
+        (
+            "dribbble".into(),
+            SocialNetwork::new("#D95988", "dribbble.png"),
+        ),
+        (
+            "facebook".into(),
+            SocialNetwork::new("#3b5998", "facebook.png")
+                .with_share_url("https://www.facebook.com/sharer/sharer.php?u=[[URL]]"),
+        ),
+        ("github".into(), SocialNetwork::new("#000000", "github.png")),
+        (
+            "google".into(),
+            SocialNetwork::new("#dc4e41", "google-plus.png")
+                .with_share_url("https://plus.google.com/share?url=[[URL]]"),
+        ),
+        (
+            "instagram".into(),
+            SocialNetwork::new("#3f729b", "instagram.png"),
+        ),
+        (
+            "linkedin".into(),
+            SocialNetwork::new("#0077b5", "linkedin.png").with_share_url(
+                "https://www.linkedin.com/shareArticle?mini=true&url=[[URL]]&title=&summary=&source=",
+            ),
+        ),
+        ("medium".into(), SocialNetwork::new("#000000", "medium.png")),
+        (
+            "pinterest".into(),
+            SocialNetwork::new("#bd081c", "pinterest.png").with_share_url(
+                "https://pinterest.com/pin/create/button/?url=[[URL]]&media=&description=",
+            ),
+        ),
+        (
+            "snapchat".into(),
+            SocialNetwork::new("#FFFA54", "snapchat.png"),
+        ),
+        (
+            "soundcloud".into(),
+            SocialNetwork::new("#EF7F31", "soundcloud.png"),
+        ),
+        (
+            "tumblr".into(),
+            SocialNetwork::new("#344356", "tumblr.png")
+                .with_share_url("https://www.tumblr.com/widgets/share/tool?canonicalUrl=[[URL]]"),
+        ),
+        (
+            "twitter".into(),
+            SocialNetwork::new("#55acee", "twitter.png")
+                .with_share_url("https://twitter.com/home?status=[[URL]]"),
+        ),
+        (
+            "x".into(),
+            SocialNetwork::new("#000000", "twitter-x.png")
+                .with_share_url("https://twitter.com/home?status=[[URL]]"),
+        ),
+        ("vimeo".into(), SocialNetwork::new("#53B4E7", "vimeo.png")),
+        ("web".into(), SocialNetwork::new("#4BADE9", "web.png")),
+        (
+            "xing".into(),
+            SocialNetwork::new("#296366", "xing.png")
+                .with_share_url("https://www.xing.com/app/user?op=share&url=[[URL]]"),
+        ),
+        (
+            "youtube".into(),
+            SocialNetwork::new("#EB3323", "youtube.png"),
+        ),
+    ])
 }
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use super::network::SocialNetwork;
+use super::network::{find_social_network, SocialNetwork};
 use super::{MjSocialElement, NAME};
 use crate::helper::size::{Pixel, Size};
 use crate::prelude::hash::Map;
@@ -11,14 +11,16 @@ const DEFAULT_ICON_ORIGIN: &str = "https://www.mailjet.com/images/theme/v1/icons
 #[derive(Default)]
 struct MjSocialElementExtra<'a> {
     attributes: Map<&'a str, &'a str>,
-    network: Option<SocialNetwork>,
+    network: Option<&'a SocialNetwork>,
+    noshare: bool,
 }
 
-impl MjSocialElementExtra<'_> {
-    pub fn new(network: Option<SocialNetwork>) -> Self {
+impl<'a> MjSocialElementExtra<'a> {
+    pub fn new(network: Option<(&'a SocialNetwork, bool)>) -> Self {
         Self {
             attributes: Map::new(),
-            network,
+            network: network.map(|(network, _)| network),
+            noshare: network.map(|(_, noshare)| noshare).unwrap_or(false),
         }
     }
 }
@@ -27,7 +29,7 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
     fn get_background_color(&'root self) -> Option<&'root str> {
         if let Some(value) = self.attribute("background-color") {
             Some(value)
-        } else if let Some(net) = self.extra.network.as_ref() {
+        } else if let Some(net) = self.extra.network {
             Some(net.background_color())
         } else {
             None
@@ -46,19 +48,20 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
     where
         'root: 'a,
     {
-        self.attribute("src").map(Cow::Borrowed).or_else(|| {
-            self.extra
-                .network
-                .as_ref()
-                .map(|net| {
-                    if let Some(ref origin) = self.context.options.social_icon_origin {
-                        net.icon_src(origin)
-                    } else {
-                        net.icon_src(DEFAULT_ICON_ORIGIN)
-                    }
-                })
-                .map(Cow::Owned)
-        })
+        self.attribute("src")
+            .map(|src| self.context.options.with_image_base_url(Cow::Borrowed(src)))
+            .or_else(|| {
+                self.extra
+                    .network
+                    .map(|net| {
+                        if let Some(ref origin) = self.context.options.social_icon_origin {
+                            net.icon_src(origin)
+                        } else {
+                            net.icon_src(DEFAULT_ICON_ORIGIN)
+                        }
+                    })
+                    .map(Cow::Owned)
+            })
     }
 
     fn set_style_img<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
@@ -137,13 +140,23 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
     where
         'root: 'a,
     {
-        self.attribute("href").and_then(|href| {
-            self.extra
-                .network
-                .as_ref()
-                .and_then(|net| net.share_url(href))
-                .map(Cow::Owned)
-                .or(Some(Cow::Borrowed(href)))
+        self.attribute("href").map(|href| {
+            // the link params apply to the shared link, not to the share url
+            let shared = self.context.options.with_link_params(Cow::Borrowed(href));
+            let Some(network) = self.extra.network.filter(|_| !self.extra.noshare) else {
+                return shared;
+            };
+            // the shared link is encoded to keep its params in the share url, the links
+            // without params being shared as they are, like mjml does
+            let encoded = (shared != href).then(|| {
+                let mut result = String::with_capacity(shared.len() * 2);
+                encode_query_component(&mut result, &shared);
+                result
+            });
+            match network.share_url(encoded.as_deref().unwrap_or(&shared)) {
+                Some(share_url) => Cow::Owned(share_url),
+                None => shared,
+            }
         })
     }
 
@@ -174,7 +187,9 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
             .maybe_add_attribute(
                 "width",
                 self.get_icon_size().map(|size| size.value().to_string()),
-            );
+            )
+            .maybe_add_attribute("sizes", self.attribute("sizes"))
+            .maybe_add_attribute("srcset", self.attribute("srcset"));
 
         table.render_open(&mut cursor.buffer)?;
         tbody.render_open(&mut cursor.buffer)?;
@@ -293,7 +308,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSocialElement {
             self.attributes
                 .get("name")
                 .and_then(|v| v.as_deref())
-                .and_then(SocialNetwork::find),
+                .and_then(|name| find_social_network(&context.options.social_networks, name)),
         );
         Box::new(Renderer::new(context, self, extra))
     }
@@ -302,4 +317,92 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSocialElement {
 #[cfg(test)]
 mod tests {
     crate::should_render!(render_ending_tag, "mj-social-element-ending");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_srcset_and_sizes() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-social mode="horizontal" icon-size="30px">
+          <mj-social-element name="facebook" href="https://mjml.io/">Facebook</mj-social-element>
+          <mj-social-element name="linkedin-noshare" href="https://www.linkedin.com/company/mailjet" src="https://example.com/linkedin.png" srcset="https://example.com/linkedin.png 1x, https://example.com/linkedin@2x.png 2x" sizes="30px">LinkedIn</mj-social-element>
+        </mj-social>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains(r#"<img height="30" src="https://example.com/linkedin.png" width="30" sizes="30px" srcset="https://example.com/linkedin.png 1x, https://example.com/linkedin@2x.png 2x" style="border-radius:3px;display:block;" />"#));
+        assert_eq!(result.matches("srcset=").count(), 1);
+        assert_eq!(result.matches("sizes=").count(), 1);
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_use_icon_origin_from_options() {
+        let template = include_str!("../../resources/compare/success/mj-social.mjml");
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions::default();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains(
+            r#"src="https://www.mailjet.com/images/theme/v1/icons/ico-social/facebook.png""#
+        ));
+        let opts = crate::prelude::render::RenderOptions::builder()
+            .social_icon_origin("https://example.com/icons/")
+            .build()
+            .unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains(r#"src="https://example.com/icons/facebook.png""#));
+        assert!(result.contains(r#"src="https://example.com/icons/twitter.png""#));
+        assert!(!result.contains("mailjet.com"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_custom_network() {
+        use crate::mj_social_element::SocialNetwork;
+
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-social>
+          <mj-social-element name="mastodon" href="https://example.com">Share</mj-social-element>
+          <mj-social-element name="mastodon-noshare" href="https://mastodon.social/@mrml">Follow</mj-social-element>
+          <mj-social-element name="github" href="https://github.com/jdrouet/mrml">GitHub</mj-social-element>
+        </mj-social>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions::builder()
+            .social_icon_origin("https://example.com/icons/")
+            .add_social_network(
+                "mastodon",
+                SocialNetwork::new("#6364FF", "mastodon.png")
+                    .with_share_url("https://mastodon.social/share?text=[[URL]]"),
+            )
+            .add_social_network(
+                "github",
+                SocialNetwork::new("#24292f", "https://cdn.example.com/github.png"),
+            )
+            .build()
+            .unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains(r#"href="https://mastodon.social/share?text=https://example.com""#));
+        assert!(result.contains(r#"href="https://mastodon.social/@mrml""#));
+        assert_eq!(
+            result
+                .matches(r#"src="https://example.com/icons/mastodon.png""#)
+                .count(),
+            2
+        );
+        assert_eq!(result.matches("background:#6364FF;").count(), 2);
+        assert!(result.contains(r#"src="https://cdn.example.com/github.png""#));
+        assert!(result.contains("background:#24292f;"));
+    }
 }
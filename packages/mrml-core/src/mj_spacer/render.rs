@@ -50,4 +50,41 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSpacer {
 #[cfg(test)]
 mod tests {
     crate::should_render!(basic, "mj-spacer");
+
+    #[test]
+    fn should_apply_mj_attributes_and_mj_class() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <mj-spacer height="40px" />
+      <mj-class name="tall" height="80px" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-spacer />
+        <mj-spacer mj-class="tall" />
+        <mj-spacer height="10px" />
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        let heights = ["40px", "80px", "10px"]
+            .iter()
+            .map(|height| {
+                output
+                    .find(&format!(
+                        r#"<div style="height:{height};line-height:{height};">"#
+                    ))
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert!(heights.windows(2).all(|pair| pair[0] < pair[1]));
+    }
 }
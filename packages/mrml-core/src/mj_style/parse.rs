@@ -3,7 +3,7 @@ use htmlparser::StrSpan;
 use super::MjStyleAttributes;
 #[cfg(feature = "async")]
 use crate::prelude::parser::AsyncMrmlParser;
-use crate::prelude::parser::{Error, MrmlCursor, MrmlParser, ParseAttributes, WarningKind};
+use crate::prelude::parser::{Error, MrmlCursor, MrmlParser, ParseAttributes};
 
 #[inline(always)]
 fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjStyleAttributes, Error> {
@@ -12,7 +12,7 @@ fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjStyleAttributes, Er
         if attr.local.as_str() == "inline" {
             result.inline = attr.value.map(|v| v.to_string());
         } else {
-            cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span);
+            cursor.add_unknown_attribute(super::NAME, &attr)?;
         }
     }
     Ok(result)
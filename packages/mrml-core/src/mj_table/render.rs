@@ -1,6 +1,8 @@
 use super::{MjTable, NAME};
-use crate::helper::size::Pixel;
+use crate::helper::size::{Pixel, Size};
+use crate::mj_body::MjBodyChild;
 use crate::mj_section::WithMjSectionBackground;
+use crate::node::Node;
 use crate::prelude::render::*;
 
 impl<'root> WithMjSectionBackground<'root> for Renderer<'root, MjTable, ()> {}
@@ -19,6 +21,94 @@ impl<'root> Renderer<'root, MjTable, ()> {
             .maybe_add_style("width", self.attribute("width"))
             .maybe_add_style("border", self.attribute("border"))
     }
+
+    /// The `width` html attribute doesn't have a unit when it's in pixels.
+    fn get_width(&self) -> Option<String> {
+        match self.attribute_as_size("width") {
+            Some(Size::Pixel(value)) => Some(value.value().to_string()),
+            _ => self.attribute("width").map(String::from),
+        }
+    }
+
+    /// Background color of the even rows when the table is `striped`.
+    fn get_stripe_color(&self) -> Option<&str> {
+        if self.attribute("striped") == Some("true") {
+            self.attribute("stripe-color")
+        } else {
+            None
+        }
+    }
+
+    /// Renders the row with the stripe color added before its own style, the
+    /// email clients not supporting `:nth-child` selectors.
+    fn render_striped_row(
+        &self,
+        row: &'root Node<MjBodyChild>,
+        color: &str,
+        cursor: &mut RenderCursor,
+    ) -> Result<(), Error> {
+        let style = match row.attributes.get("style") {
+            Some(Some(style)) => format!("background-color:{color};{style}"),
+            _ => format!("background-color:{color};"),
+        };
+        cursor.buffer.open_tag(&row.tag);
+        for (key, value) in row.attributes.iter().filter(|(key, _)| *key != "style") {
+            cursor
+                .buffer
+                .push_attribute(key.as_str(), value.as_deref())?;
+        }
+        cursor
+            .buffer
+            .push_attribute("style", Some(style.as_str()))?;
+        cursor.buffer.close_tag();
+        for (index, child) in row.children.iter().enumerate() {
+            let mut renderer = child.renderer(self.context());
+            renderer.set_index(index);
+            renderer.render(cursor)?;
+        }
+        cursor.buffer.end_tag(&row.tag);
+        Ok(())
+    }
+
+    /// Renders the rows of the table, or of its `tbody`, alternating the
+    /// background color of the rows when a stripe color is given.
+    fn render_rows(
+        &self,
+        children: &'root [MjBodyChild],
+        stripe_color: Option<&str>,
+        row_index: &mut usize,
+        cursor: &mut RenderCursor,
+    ) -> Result<(), Error> {
+        for (index, child) in children.iter().enumerate() {
+            match (child, stripe_color) {
+                (MjBodyChild::Node(node), Some(color)) if node.tag.eq_ignore_ascii_case("tr") => {
+                    if *row_index % 2 == 1 {
+                        self.render_striped_row(node, color, cursor)?;
+                    } else {
+                        child.renderer(self.context()).render(cursor)?;
+                    }
+                    *row_index += 1;
+                }
+                (MjBodyChild::Node(node), Some(_)) if node.tag.eq_ignore_ascii_case("tbody") => {
+                    cursor.buffer.open_tag(&node.tag);
+                    for (key, value) in node.attributes.iter() {
+                        cursor
+                            .buffer
+                            .push_attribute(key.as_str(), value.as_deref())?;
+                    }
+                    cursor.buffer.close_tag();
+                    self.render_rows(&node.children, stripe_color, row_index, cursor)?;
+                    cursor.buffer.end_tag(&node.tag);
+                }
+                _ => {
+                    let mut renderer = child.renderer(self.context());
+                    renderer.set_index(index);
+                    renderer.render(cursor)?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<'root> Render<'root> for Renderer<'root, MjTable, ()> {
@@ -33,6 +123,7 @@ impl<'root> Render<'root> for Renderer<'root, MjTable, ()> {
             "font-size" => Some("13px"),
             "line-height" => Some("22px"),
             "padding" => Some("10px 25px"),
+            "stripe-color" => Some("#f2f2f2"),
             "table-layout" => Some("auto"),
             "width" => Some("100%"),
             _ => None,
@@ -67,13 +158,15 @@ impl<'root> Render<'root> for Renderer<'root, MjTable, ()> {
             .add_attribute("border", "0")
             .maybe_add_attribute("cellpadding", self.attribute("cellpadding"))
             .maybe_add_attribute("cellspacing", self.attribute("cellspacing"))
-            .maybe_add_attribute("width", self.attribute("width"));
+            .maybe_add_attribute("role", self.attribute("role"))
+            .maybe_add_attribute("width", self.get_width());
         table.render_open(&mut cursor.buffer)?;
-        for (index, child) in self.element.children.iter().enumerate() {
-            let mut renderer = child.renderer(self.context());
-            renderer.set_index(index);
-            renderer.render(cursor)?;
-        }
+        self.render_rows(
+            &self.element.children,
+            self.get_stripe_color(),
+            &mut 0,
+            cursor,
+        )?;
         table.render_close(&mut cursor.buffer);
         Ok(())
     }
@@ -94,4 +187,76 @@ mod tests {
     crate::should_render!(table, "mj-table-table");
     crate::should_render!(text, "mj-table-text");
     crate::should_render!(other, "mj-table-other");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_apply_role_and_width() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-table width="300px" role="presentation" cellpadding="4">
+          <tr><th>Language</th><th>Year</th></tr>
+          <tr><td>PHP</td><td>1995</td></tr>
+        </mj-table>
+        <mj-table width="auto">
+          <tr><td>Rust</td><td>2015</td></tr>
+        </mj-table>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let output = root.element.render(&Default::default()).unwrap();
+        assert!(output.contains(r#"<table border="0" cellpadding="4" cellspacing="0" role="presentation" width="300" style="color:#000000;font-family:Ubuntu, Helvetica, Arial, sans-serif;font-size:13px;line-height:22px;table-layout:auto;width:300px;border:none;">"#));
+        assert!(output.contains(r#"<table border="0" cellpadding="0" cellspacing="0" width="auto" style="color:#000000;font-family:Ubuntu, Helvetica, Arial, sans-serif;font-size:13px;line-height:22px;table-layout:auto;width:auto;border:none;">"#));
+        assert!(output.contains("<tr><th>Language</th><th>Year</th></tr>"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_striped_rows() {
+        let template = r##"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-table striped="true" stripe-color="#eeeeee">
+          <tr><td>1</td></tr>
+          <tr style="color:red;"><td>2</td></tr>
+          <tr><td>3</td></tr>
+          <tr class="last"><td>4</td></tr>
+        </mj-table>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"##;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains("<tr><td>1</td></tr>"));
+        assert!(
+            result.contains(r##"<tr style="background-color:#eeeeee;color:red;"><td>2</td></tr>"##)
+        );
+        assert!(result.contains("<tr><td>3</td></tr>"));
+        assert!(result
+            .contains(r##"<tr class="last" style="background-color:#eeeeee;"><td>4</td></tr>"##));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_not_stripe_rows_by_default() {
+        let template = r##"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-table stripe-color="#eeeeee">
+          <tbody><tr><td>1</td></tr><tr><td>2</td></tr></tbody>
+        </mj-table>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"##;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(!result.contains("#eeeeee"));
+    }
 }
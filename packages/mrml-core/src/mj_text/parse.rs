@@ -4,4 +4,16 @@ mod tests {
 
     crate::should_parse!(self_closing, MjText, "<mj-text />");
     crate::should_parse!(normal, MjText, "<mj-text>Hello World!</mj-text>");
+    crate::should_parse!(
+        valid_sizes,
+        MjText,
+        r#"<mj-text font-size="12px" line-height="1.5" letter-spacing="-1px" height="auto">Hello World!</mj-text>"#
+    );
+    // the sizes are only checked when validating the template
+    crate::should_parse!(
+        invalid_sizes,
+        MjText,
+        r#"<mj-text font-size="big" line-height="" height>Hello World!</mj-text>"#,
+        0
+    );
 }
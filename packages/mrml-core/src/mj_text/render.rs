@@ -16,12 +16,29 @@ impl<'root> Renderer<'root, MjText, ()> {
             .maybe_add_style("text-align", self.attribute("align"))
             .maybe_add_style("text-decoration", self.attribute("text-decoration"))
             .maybe_add_style("text-transform", self.attribute("text-transform"))
+            .maybe_add_style("word-break", self.attribute("word-break"))
+            .maybe_add_style("overflow-wrap", self.attribute("overflow-wrap"))
             .maybe_add_style("color", self.attribute("color"))
+            .maybe_add_style("background-color", self.attribute("background-color"))
             .maybe_add_style("height", self.attribute("height"))
+            .maybe_add_style("direction", self.attribute("dir"))
+            .maybe_add_style("unicode-bidi", self.attribute("unicode-bidi"))
+    }
+
+    /// Text direction of the element, its `dir` attribute or the one of the
+    /// document.
+    fn direction(&self) -> &str {
+        match self.attribute("dir") {
+            Some(value) if value.eq_ignore_ascii_case("rtl") => "rtl",
+            Some(_) => "ltr",
+            None => self.context.header.direction(),
+        }
     }
 
     fn render_content(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let root = self.set_style_text(Tag::div());
+        let root = self
+            .set_style_text(Tag::div())
+            .maybe_add_attribute("dir", self.attribute("dir"));
         root.render_open(&mut cursor.buffer)?;
         for child in self.element.children.iter() {
             child.renderer(self.context()).render(cursor)?;
@@ -56,7 +73,10 @@ impl<'root> Renderer<'root, MjText, ()> {
 impl<'root> Render<'root> for Renderer<'root, MjText, ()> {
     fn default_attribute(&self, key: &str) -> Option<&'static str> {
         match key {
-            "align" => Some("left"),
+            "align" => Some(match self.direction() {
+                "rtl" => "right",
+                _ => "left",
+            }),
             "color" => Some("#000000"),
             "font-family" => Some("Ubuntu, Helvetica, Arial, sans-serif"),
             "font-size" => Some("13px"),
@@ -118,6 +138,105 @@ mod tests {
     crate::should_render!(font_style, "mj-text-font-style");
     crate::should_render!(font_weight, "mj-text-font-weight");
     crate::should_render!(height, "mj-text-height");
+    crate::should_render!(letter_spacing, "mj-text-letter-spacing");
     crate::should_render!(line_height, "mj-text-line-height");
     crate::should_render!(padding, "mj-text-padding");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_keep_negative_letter_spacing() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-text letter-spacing="-0.5px">tight</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains("letter-spacing:-0.5px;"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_keep_unitless_line_height() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-text line-height="1.4"><p style="margin:0 0 12px;">first</p><p>second</p></mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        assert!(root.warnings.is_empty());
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains("line-height:1.4;"));
+        assert!(!result.contains("line-height:1.4px"));
+        assert!(result.contains(r#"<p style="margin:0 0 12px;">first</p><p>second</p>"#));
+    }
+
+    #[test]
+    fn should_separate_background_and_container_background() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r#"<mjml><mj-body><mj-section><mj-column><mj-text background-color="blue" container-background-color="red" padding="20px">Hello</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains(
+            r#"<td align="left" style="background:red;font-size:0px;padding:20px;word-break:break-word;">"#
+        ));
+        assert!(output.contains("color:#000000;background-color:blue;\">Hello</div>"));
+        assert_eq!(output.matches("blue").count(), 1);
+        assert_eq!(output.matches("red").count(), 1);
+    }
+
+    #[test]
+    fn should_pass_word_break_through() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r#"<mjml><mj-body><mj-section><mj-column><mj-text word-break="break-all" overflow-wrap="anywhere">https://example.com/a/very/long/url</mj-text><mj-button href="https://example.com" word-break="break-all">Go</mj-button></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains("text-align:left;word-break:break-all;overflow-wrap:anywhere;color:#000000;\">https://example.com/a/very/long/url</div>"));
+        assert!(output.contains("text-transform:none;word-break:break-all;padding:10px 25px;"));
+        assert_eq!(output.matches("overflow-wrap").count(), 1);
+    }
+
+    #[test]
+    fn should_render_rtl_text_in_ltr_document() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r#"<mjml dir="ltr"><mj-body><mj-section><mj-column><mj-text>Hello</mj-text><mj-text dir="rtl" unicode-bidi="embed">مرحبا بالعالم</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains(
+            r#"<td align="right" style="font-size:0px;padding:10px 25px;word-break:break-word;">"#
+        ));
+        assert!(output.contains(r#"<div dir="rtl" style="font-family:Ubuntu, Helvetica, Arial, sans-serif;font-size:13px;line-height:1;text-align:right;color:#000000;direction:rtl;unicode-bidi:embed;">مرحبا بالعالم</div>"#));
+        assert!(output.contains(r#"text-align:left;color:#000000;">Hello</div>"#));
+        assert_eq!(output.matches("unicode-bidi").count(), 1);
+    }
+
+    #[test]
+    fn should_normalize_line_endings() {
+        use crate::mjml::Mjml;
+        use crate::prelude::parser::ParserOptions;
+        use crate::prelude::render::RenderOptions;
+
+        let source = "<mjml>\r\n<mj-body><mj-section><mj-column><mj-text>Hello\r\nWorld<br />\r\n<b>!</b></mj-text></mj-column></mj-section></mj-body></mjml>\r\n";
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains(">Hello\nWorld<br />\n<b>!</b></div>"));
+        assert!(!output.contains('\r'));
+
+        let options = ParserOptions {
+            keep_line_endings: true,
+            ..Default::default()
+        };
+        let root = Mjml::parse_with_options(source, &options).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains(">Hello\r\nWorld<br />\r\n<b>!</b></div>"));
+    }
+
+    #[test]
+    fn should_keep_inline_tags() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r#"<mjml><mj-body><mj-section><mj-column><mj-text>Hello <b>bold</b>, <i>italic</i> &amp; <a href="x" target="_blank">link</a> <span style="color:red;">red</span></mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains(r#"color:#000000;">Hello <b>bold</b>, <i>italic</i> &amp; <a href="x" target="_blank">link</a> <span style="color:red;">red</span></div>"#));
+    }
 }
@@ -7,7 +7,7 @@ impl Renderer<'_, MjWrapper, ()> {
     fn current_width(&self) -> Option<Pixel> {
         self.container_width.as_ref().map(|width| {
             let hborder = self.get_border_horizontal();
-            let hpadding = self.get_padding_horizontal();
+            let hpadding = self.get_padding_horizontal(width);
             Pixel::new(width.value() - hborder.value() - hpadding.value())
         })
     }
@@ -62,7 +62,7 @@ impl<'root> Render<'root> for Renderer<'root, MjWrapper, ()> {
             "background-position" => Some("top center"),
             "background-repeat" => Some("repeat"),
             "background-size" => Some("auto"),
-            "direction" => Some("ltr"),
+            "direction" => Some(self.context.header.direction()),
             "padding" => Some("20px 0"),
             "text-align" => Some("center"),
             "text-padding" => Some("4px 4px 4px 0"),
@@ -114,4 +114,43 @@ mod tests {
     crate::should_render!(border, "mj-wrapper-border");
     crate::should_render!(other, "mj-wrapper-other");
     crate::should_render!(padding, "mj-wrapper-padding");
+
+    #[test]
+    fn should_render_sections_with_full_width_background_url() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let source = r##"<mjml>
+  <mj-body>
+    <mj-wrapper full-width="full-width" background-url="https://example.com/bg.png" background-size="cover" padding="10px 0">
+      <mj-section background-color="#ffffff">
+        <mj-column>
+          <mj-text>First section</mj-text>
+        </mj-column>
+      </mj-section>
+      <mj-section>
+        <mj-column>
+          <mj-text>Left</mj-text>
+        </mj-column>
+        <mj-column>
+          <mj-text>Right</mj-text>
+        </mj-column>
+      </mj-section>
+    </mj-wrapper>
+  </mj-body>
+</mjml>"##;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains(
+            r#"<v:rect xmlns:v="urn:schemas-microsoft-com:vml" fill="true" stroke="false" style="mso-width-percent:1000;">"#
+        ));
+        assert_eq!(
+            output.matches("<!--[if ").count(),
+            output.matches("<![endif]-->").count()
+        );
+        let first = output.find("First section").unwrap();
+        let left = output.find("Left").unwrap();
+        let right = output.find("Right").unwrap();
+        assert!(first < left && left < right);
+    }
 }
@@ -18,7 +18,7 @@ fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjmlAttributes, Error
             "owa" => attrs.owa = token.value.map(|v| v.to_string()),
             "lang" => attrs.lang = token.value.map(|v| v.to_string()),
             "dir" => attrs.dir = token.value.map(|v| v.to_string()),
-            _ => cursor.add_warning(WarningKind::UnexpectedAttribute, token.span),
+            _ => cursor.add_unknown_attribute(super::NAME, &token)?,
         }
     }
     Ok(attrs)
@@ -47,27 +47,44 @@ impl ParseChildren<MjmlChildren> for MrmlParser<'_> {
                 MrmlToken::Text(inner) if inner.text.trim().is_empty() => {
                     // ignoring empty text
                 }
-                MrmlToken::Comment(_) => {
+                MrmlToken::Comment(inner) => {
                     // ignoring comment on purpose
+                    cursor.add_warning(WarningKind::IgnoredComment, inner.span);
                 }
                 MrmlToken::ElementStart(start) => match start.local.as_str() {
                     MJ_HEAD => {
+                        if children.head.is_some() {
+                            return Err(Error::DuplicateElement {
+                                origin: cursor.origin(),
+                                position: start.span.into(),
+                                location: cursor.location(start.span),
+                            });
+                        }
                         children.head = Some(self.parse(cursor, start.local)?);
                     }
                     MJ_BODY => {
+                        if children.body.is_some() {
+                            return Err(Error::DuplicateElement {
+                                origin: cursor.origin(),
+                                position: start.span.into(),
+                                location: cursor.location(start.span),
+                            });
+                        }
                         children.body = Some(self.parse(cursor, start.local)?);
                     }
                     _ => {
-                        return Err(Error::UnexpectedElement {
+                        cursor.skip_unexpected_element(Error::UnexpectedElement {
                             origin: cursor.origin(),
                             position: start.span.into(),
-                        });
+                            location: cursor.location(start.span),
+                        })?;
                     }
                 },
                 other => {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -102,24 +119,47 @@ impl AsyncParseChildren<MjmlChildren> for AsyncMrmlParser {
                     cursor.rewind(MrmlToken::ElementClose(close));
                     return Ok(children);
                 }
+                MrmlToken::Text(inner) if inner.text.trim().is_empty() => {
+                    // ignoring empty text
+                }
+                MrmlToken::Comment(inner) => {
+                    // ignoring comment on purpose
+                    cursor.add_warning(WarningKind::IgnoredComment, inner.span);
+                }
                 MrmlToken::ElementStart(start) => match start.local.as_str() {
                     MJ_HEAD => {
+                        if children.head.is_some() {
+                            return Err(Error::DuplicateElement {
+                                origin: cursor.origin(),
+                                position: start.span.into(),
+                                location: cursor.location(start.span),
+                            });
+                        }
                         children.head = Some(self.async_parse(cursor, start.local).await?);
                     }
                     MJ_BODY => {
+                        if children.body.is_some() {
+                            return Err(Error::DuplicateElement {
+                                origin: cursor.origin(),
+                                position: start.span.into(),
+                                location: cursor.location(start.span),
+                            });
+                        }
                         children.body = Some(self.async_parse(cursor, start.local).await?);
                     }
                     _ => {
-                        return Err(Error::UnexpectedElement {
+                        cursor.skip_unexpected_element(Error::UnexpectedElement {
                             origin: cursor.origin(),
                             position: start.span.into(),
-                        });
+                            location: cursor.location(start.span),
+                        })?;
                     }
                 },
                 other => {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -144,6 +184,7 @@ impl Mjml {
     ///
     /// let options = ParserOptions {
     ///     include_loader: Box::new(MemoryIncludeLoader::default()),
+    ///     ..Default::default()
     /// };
     /// match Mjml::parse_with_options("<mjml><mj-head /><mj-body /></mjml>", &options) {
     ///     Ok(_) => println!("Success!"),
@@ -207,6 +248,10 @@ impl Mjml {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+    #[cfg(feature = "async")]
+    use crate::prelude::parser::AsyncParserOptions;
+    use crate::prelude::parser::{Limit, Origin, Span};
 
     #[test]
     fn should_parse_with_options_sync() {
@@ -296,9 +341,30 @@ mod tests {
         let _output = Mjml::parse(template).unwrap();
     }
 
+    #[test]
+    fn should_warn_about_ignored_comment_sync() {
+        let template = "<mjml>\n  <!-- Hello -->\n  <mj-body />\n</mjml>";
+        let output = Mjml::parse(template).unwrap();
+        assert!(output.element.children.body.is_some());
+        assert_eq!(output.warnings.len(), 1);
+        assert_eq!(output.warnings[0].kind, WarningKind::IgnoredComment);
+        assert_eq!(output.warnings[0].span.start, 9);
+        assert_eq!(output.warnings[0].span.end, 23);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_warn_about_ignored_comment_async() {
+        let template = "<mjml>\n  <!-- Hello -->\n  <mj-body />\n</mjml>";
+        let output = Mjml::async_parse(template).await.unwrap();
+        assert!(output.element.children.body.is_some());
+        assert_eq!(output.warnings.len(), 1);
+        assert_eq!(output.warnings[0].kind, WarningKind::IgnoredComment);
+    }
+
     #[test]
     #[should_panic(
-        expected = "UnexpectedToken { origin: Root, position: Span { start: 6, end: 11 } }"
+        expected = "UnexpectedToken { origin: Root, position: Span { start: 6, end: 11 }, location: Location { offset: 6, line: 1, column: 7 } }"
     )]
     fn should_fail_with_text_as_child() {
         let template = "<mjml>Hello</mjml>";
@@ -307,10 +373,186 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "UnexpectedElement { origin: Root, position: Span { start: 6, end: 10 } }"
+        expected = "UnexpectedElement { origin: Root, position: Span { start: 6, end: 10 }, location: Location { offset: 6, line: 1, column: 7 } }"
     )]
     fn should_fail_with_other_child() {
         let template = "<mjml><div /></mjml>";
         let _ = Mjml::parse(template).unwrap();
     }
+
+    #[test]
+    fn should_fail_with_duplicate_body_sync() {
+        let template = "<mjml><mj-body></mj-body><mj-body></mj-body></mjml>";
+        let err = Mjml::parse(template).err().unwrap();
+        assert!(matches!(
+            err,
+            Error::DuplicateElement {
+                origin: Origin::Root,
+                position: Span { start: 25, .. },
+                ..
+            }
+        ));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_fail_with_duplicate_body_async() {
+        let template = "<mjml><mj-body></mj-body><mj-body></mj-body></mjml>";
+        let err = Mjml::async_parse(template).await.err().unwrap();
+        assert!(matches!(err, Error::DuplicateElement { .. }));
+    }
+
+    #[test]
+    fn should_fail_with_duplicate_head() {
+        let template = "<mjml><mj-head /><mj-body /><mj-head /></mjml>";
+        let err = Mjml::parse(template).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "duplicate element in root template at line 1, column 29"
+        );
+    }
+
+    #[test]
+    fn should_fail_when_exceeding_max_depth() {
+        let template = "<mjml><mj-body><mj-wrapper><mj-section><mj-column><mj-text>Hello <b>World</b></mj-text></mj-column></mj-section></mj-wrapper></mj-body></mjml>";
+        let options = ParserOptions {
+            max_depth: 6,
+            ..Default::default()
+        };
+        let err = Mjml::parse_with_options(template, &options).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "depth limit exceeded in root template at line 1, column 66"
+        );
+        let options = ParserOptions {
+            max_depth: 7,
+            ..Default::default()
+        };
+        assert!(Mjml::parse_with_options(template, &options).is_ok());
+    }
+
+    #[test]
+    fn should_fail_when_exceeding_max_elements() {
+        let template = "<mjml><mj-body><mj-text>1</mj-text><mj-text>2<br/></mj-text><mj-text>3</mj-text></mj-body></mjml>";
+        let options = ParserOptions {
+            max_elements: 6,
+            ..Default::default()
+        };
+        assert!(Mjml::parse_with_options(template, &options).is_ok());
+        let options = ParserOptions {
+            max_elements: 5,
+            ..Default::default()
+        };
+        let err = Mjml::parse_with_options(template, &options).err().unwrap();
+        assert!(matches!(
+            err,
+            Error::LimitExceeded {
+                limit: Limit::Elements,
+                ..
+            }
+        ));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_fail_when_exceeding_max_depth_async() {
+        let template = "<mjml><mj-body><mj-section><mj-column /></mj-section></mj-body></mjml>";
+        let options = std::sync::Arc::new(AsyncParserOptions {
+            max_depth: 3,
+            ..Default::default()
+        });
+        let err = Mjml::async_parse_with_options(template, options)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            Error::LimitExceeded {
+                limit: Limit::Depth,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn should_count_included_elements() {
+        let loader = MemoryIncludeLoader::from(vec![(
+            "partial.mjml",
+            "<mj-section><mj-column><mj-text>Hello</mj-text></mj-column></mj-section>",
+        )]);
+        let template = r#"<mjml><mj-body><mj-include path="partial.mjml" /><mj-include path="partial.mjml" /></mj-body></mjml>"#;
+        let options = ParserOptions {
+            include_loader: Box::new(loader),
+            max_elements: 8,
+            ..Default::default()
+        };
+        let err = Mjml::parse_with_options(template, &options).err().unwrap();
+        assert!(matches!(
+            err,
+            Error::LimitExceeded {
+                limit: Limit::Elements,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn should_fail_on_unknown_attribute_when_strict() {
+        let template =
+            r#"<mjml><mj-head><mj-breakpoint width="320px" align="left" /></mj-head></mjml>"#;
+        let output = Mjml::parse_with_options(template, &ParserOptions::default()).unwrap();
+        assert_eq!(output.warnings.len(), 1);
+        let options = ParserOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let err = Mjml::parse_with_options(template, &options).err().unwrap();
+        assert!(matches!(
+            err,
+            Error::UnknownAttribute { ref element, ref name, .. } if element == "mj-breakpoint" && name == "align"
+        ));
+        assert_eq!(
+            err.to_string(),
+            "unknown attribute \"align\" for mj-breakpoint in root template at line 1, column 45"
+        );
+    }
+
+    #[test]
+    fn should_fail_on_unknown_body_attribute_when_strict() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-text align="left">Hello</mj-text><mj-spacer align="left" /></mj-column></mj-section></mj-body></mjml>"#;
+        let output = Mjml::parse_with_options(template, &ParserOptions::default()).unwrap();
+        assert!(output.warnings.is_empty());
+        let options = ParserOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let err = Mjml::parse_with_options(template, &options).err().unwrap();
+        assert!(matches!(
+            err,
+            Error::UnknownAttribute { ref element, ref name, .. } if element == "mj-spacer" && name == "align"
+        ));
+        assert_eq!(
+            err.to_string(),
+            "unknown attribute \"align\" for mj-spacer in root template at line 1, column 87"
+        );
+    }
+
+    #[test]
+    fn should_fail_on_unknown_attribute_in_include_when_strict() {
+        let loader = MemoryIncludeLoader::from(vec![(
+            "partial.mjml",
+            r#"<mj-raw foo="bar"><p>Hello</p></mj-raw>"#,
+        )]);
+        let template = r#"<mjml><mj-body><mj-include path="partial.mjml" /></mj-body></mjml>"#;
+        let options = ParserOptions {
+            include_loader: Box::new(loader),
+            strict: true,
+            ..Default::default()
+        };
+        let err = Mjml::parse_with_options(template, &options).err().unwrap();
+        assert!(matches!(
+            err,
+            Error::UnknownAttribute { ref element, ref name, .. } if element == "mj-raw" && name == "foo"
+        ));
+    }
 }
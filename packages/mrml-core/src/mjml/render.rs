@@ -1,7 +1,13 @@
 use super::Mjml;
+use crate::helper::size::Pixel;
 use crate::mj_head::MjHead;
+use crate::prelude::render::text::decode_entities;
 use crate::prelude::render::*;
 
+/// Rough size of a rendered head, with its default styles, fonts and media
+/// queries.
+const HEAD_CAPACITY: usize = 2048;
+
 impl<'root> Render<'root> for Renderer<'root, Mjml, ()> {
     fn context(&self) -> &'root RenderContext<'root> {
         self.context
@@ -13,13 +19,20 @@ impl<'root> Render<'root> for Renderer<'root, Mjml, ()> {
         } else {
             cursor.buffer.push_str("<body></body>");
         }
-        let mut body = RenderBuffer::default();
-        std::mem::swap(&mut body, &mut cursor.buffer);
-        cursor.buffer.push_str("<!doctype html>");
+        // the document is made of the head followed by the body, reserving the
+        // space for both avoids growing the buffer while copying the body
+        let capacity = cursor.buffer.len() + HEAD_CAPACITY;
+        let body = std::mem::replace(&mut cursor.buffer, RenderBuffer::with_capacity(capacity));
+        cursor
+            .buffer
+            .push_str(self.context.options.doctype.as_str());
         cursor.buffer.open_tag("html");
         if let Some(ref lang) = self.element.attributes.lang {
             cursor.buffer.push_attribute("lang", lang.as_str())?;
         }
+        if let Some(ref dir) = self.element.attributes.dir {
+            cursor.buffer.push_attribute("dir", dir.as_str())?;
+        }
         cursor
             .buffer
             .push_attribute("xmlns", "http://www.w3.org/1999/xhtml")?;
@@ -52,30 +65,134 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for Mjml {
 
 impl Mjml {
     pub fn render(&self, opts: &RenderOptions) -> Result<String, Error> {
-        let header = Header::new(self.children.head.as_ref(), self.attributes.lang.as_deref());
+        let header = Header::new(
+            self.children.head.as_ref(),
+            self.attributes.lang.as_deref(),
+            self.attributes.dir.as_deref(),
+            opts.breakpoint()?,
+        );
         let context = RenderContext::new(opts, header);
         let mut cursor = RenderCursor::default();
         self.renderer(&context).render(&mut cursor)?;
-        Ok(cursor.buffer.into())
+        let output: String = cursor.buffer.into();
+        let output = if context.header.html_attributes().is_empty() {
+            output
+        } else {
+            add_html_attributes(&output, context.header.html_attributes())
+        };
+        let mut styles = context.header.inline_styles().concat();
+        if opts.inline_css {
+            if let Some(head) = self.head() {
+                styles.extend(head.mj_style_iter());
+            }
+        }
+        let stylesheet = Stylesheet::parse(&styles);
+        let output = if stylesheet.is_empty() {
+            output
+        } else {
+            inline(&output, &stylesheet)
+        };
+        let output = match opts.output_format {
+            OutputFormat::Html => output,
+            OutputFormat::Amp => amp(&output),
+        };
+        let output = if opts.fragment {
+            fragment(&output)
+        } else {
+            output
+        };
+        if opts.minify {
+            Ok(minify(&output))
+        } else if opts.pretty {
+            Ok(prettify(&output))
+        } else {
+            Ok(output)
+        }
     }
 
+    /// Renders the template and writes it into the provided writer.
+    ///
+    /// This is a convenience wrapper around [`Mjml::render`]: the head
+    /// depends on what has been collected while rendering the body (used
+    /// fonts, media queries, styles) and the post-rendering steps, like the
+    /// css inlining or the minification, work on the whole document, so it is
+    /// still built in memory before being written.
+    pub fn write_to<W: std::io::Write>(
+        &self,
+        opts: &RenderOptions,
+        writer: &mut W,
+    ) -> Result<(), WriteError> {
+        let output = self.render(opts)?;
+        writer.write_all(output.as_bytes())?;
+        Ok(())
+    }
+
+    /// Content of the `mj-title` as plain text, to be used as the subject of
+    /// the email: the html entities, like `&amp;`, are decoded. The rendered
+    /// `<title>` keeps the content as it is written in the template.
     pub fn get_title(&self) -> Option<String> {
         self.head()
             .and_then(|head| head.title())
-            .map(|title| title.content().to_string())
+            .map(|title| decode_entities(title.content()))
     }
 
+    /// Content of the `mj-preview` as plain text, the html entities being
+    /// decoded like in [`Mjml::get_title`].
     pub fn get_preview(&self) -> Option<String> {
         self.head()
             .and_then(|head| head.preview())
-            .map(|preview| preview.content().to_string())
+            .map(|preview| decode_entities(preview.content()))
+    }
+
+    /// Returns the breakpoint used to render the template, like `480px`: the
+    /// `mj-breakpoint` when it's a pixel value, then the one from the options.
+    /// It can be used to write media queries in the `mj-style` elements, the
+    /// column widths being applied above it with a `min-width` media query.
+    ///
+    /// Like [`Mjml::render`], it fails with [`Error::InvalidBreakpoint`] when
+    /// the breakpoint from the options is not a pixel value.
+    pub fn get_breakpoint(&self, opts: &RenderOptions) -> Result<String, Error> {
+        let fallback = opts.breakpoint()?;
+        Ok(self
+            .head()
+            .and_then(|head| head.breakpoint())
+            .and_then(|breakpoint| Pixel::try_from(breakpoint.value()).ok())
+            .or(fallback)
+            .unwrap_or_else(|| Pixel::new(480.0))
+            .to_string())
+    }
+
+    /// Renders a plain text version of the body, meant to be used as the
+    /// text alternative of the email. The preview is not included, use
+    /// [`Mjml::get_preview`] to get it.
+    pub fn get_text(&self) -> String {
+        use crate::prelude::render::text::{RenderText, TextBuffer};
+
+        let mut buffer = TextBuffer::default();
+        if let Some(body) = self.body() {
+            body.render_text(&mut buffer);
+        }
+        buffer.into()
+    }
+}
+
+/// Renders the template into html with the default [`RenderOptions`]: the
+/// comments are kept, the output is not minified and only the inline
+/// `mj-style` rules are inlined. When the rendering fails, an html comment
+/// containing the error is written instead, use [`Mjml::render`] to handle it.
+impl std::fmt::Display for Mjml {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.render(&RenderOptions::default()) {
+            Ok(html) => f.write_str(&html),
+            Err(error) => write!(f, "<!-- {error} -->"),
+        }
     }
 }
 
 #[cfg(all(test, feature = "parse"))]
 mod tests {
     use crate::mjml::Mjml;
-    use crate::prelude::render::RenderOptions;
+    use crate::prelude::render::{DocType, OutputFormat, RenderOptions};
 
     crate::should_render!(empty, "mjml");
 
@@ -96,6 +213,695 @@ mod tests {
         html_compare::assert_similar(expected, root.element.render(&opts).unwrap().as_str());
     }
 
+    #[test]
+    fn should_write_to_writer() {
+        let source = "<mjml><mj-body><mj-section><mj-column><mj-text>hi</mj-text></mj-column></mj-section></mj-body></mjml>";
+        let options = RenderOptions::default();
+        let root = Mjml::parse(source).unwrap();
+
+        let mut output = Vec::new();
+        root.element.write_to(&options, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            root.element.render(&options).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_render_text_without_preview() {
+        let source = r#"<mjml>
+  <mj-head><mj-preview>Preview</mj-preview></mj-head>
+  <mj-body>
+    <mj-section><mj-column>
+      <mj-text><p>Hello World</p></mj-text>
+      <mj-button href="https://example.com">Go</mj-button>
+    </mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        assert_eq!(
+            root.element.get_text(),
+            "Hello World\n\nGo (https://example.com)"
+        );
+        assert_eq!(root.element.get_preview().as_deref(), Some("Preview"));
+    }
+
+    #[test]
+    fn should_handle_entities() {
+        let source = r#"<mjml>
+  <mj-head>
+    <mj-title>Tom &amp; Jerry</mj-title>
+    <mj-preview>Cats &amp; mice &#8212; 1 &lt; 2</mj-preview>
+  </mj-head>
+  <mj-body>
+    <mj-text>1 &lt; 2 &amp; 3 &gt; 2</mj-text>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        assert_eq!(root.element.get_title().as_deref(), Some("Tom & Jerry"));
+        assert_eq!(
+            root.element.get_preview().as_deref(),
+            Some("Cats & mice \u{2014} 1 < 2")
+        );
+        assert_eq!(root.element.get_text(), "1 < 2 & 3 > 2");
+        // the html output keeps the entities
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(output.contains("<title>Tom &amp; Jerry</title>"));
+        assert!(output.contains(">Cats &amp; mice &#8212; 1 &lt; 2</div>"));
+        assert!(output.contains(">1 &lt; 2 &amp; 3 &gt; 2</div>"));
+    }
+
+    #[test]
+    fn should_render_empty_text_without_body() {
+        let root = Mjml::parse("<mjml></mjml>").unwrap();
+        assert_eq!(root.element.get_text(), "");
+    }
+
+    #[test]
+    fn should_minify_section_with_columns() {
+        let source = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text>Hello
+          World</mj-text>
+      </mj-column>
+      <mj-column>
+        <mj-raw><pre>  keep
+  this</pre></mj-raw>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let normal = root.element.render(&RenderOptions::default()).unwrap();
+        let minified = root
+            .element
+            .render(&RenderOptions {
+                minify: true,
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(minified.len() < normal.len());
+        assert!(minified.contains("Hello World</div>"));
+        assert!(minified.contains("<pre>  keep\n  this</pre>"));
+        assert!(minified.contains("<!--[if mso | IE]><table"));
+        assert!(!minified.contains("\n</style>"));
+        assert!(!minified.contains(" style=\"\""));
+        html_compare::assert_similar(&normal, &minified);
+    }
+
+    #[test]
+    fn should_render_amp() {
+        let source = r#"<mjml>
+  <mj-head>
+    <mj-font name="Roboto" href="https://fonts.googleapis.com/css?family=Roboto" />
+    <mj-style>.title { color: red !important; }</mj-style>
+  </mj-head>
+  <mj-body>
+    <mj-section background-url="https://example.com/background.png">
+      <mj-column>
+        <mj-text css-class="title" font-family="Roboto">Hello World</mj-text>
+        <mj-image src="https://example.com/logo.png" width="100px" height="50px" usemap="map" />
+      </mj-column>
+      <mj-column>
+        <mj-text>Second</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let options = RenderOptions::builder()
+            .output_format(OutputFormat::Amp)
+            .build()
+            .unwrap();
+        let output = root.element.render(&options).unwrap();
+        assert!(output.starts_with(
+            "<!doctype html><html ⚡4email data-css-strict><head><meta charset=\"utf-8\">"
+        ));
+        assert!(
+            output.contains(r#"<script async src="https://cdn.ampproject.org/v0.js"></script>"#)
+        );
+        assert_eq!(output.matches("<style").count(), 2);
+        assert!(output.contains("<style amp-custom>"));
+        assert!(output.contains(".title { color: red; }"));
+        assert!(output.contains("@media only screen and (min-width:480px) { .mj-column-per-50 { width:50%; max-width:50%; }"));
+        assert!(!output.contains("!important"));
+        assert!(!output.contains("<!--[if"));
+        assert!(!output.contains("@import"));
+        assert!(!output.contains("<link"));
+        assert!(!output.contains("<img"));
+        assert!(output.contains(r#"<amp-img src="https://example.com/logo.png" width="100" height="50" layout="responsive"></amp-img>"#));
+        assert!(!output.contains(" background="));
+        assert!(!output.contains(" usemap="));
+        assert!(!output.contains("xmlns"));
+        assert!(output.contains(">Hello World</div>"));
+    }
+
+    #[test]
+    fn should_render_fragment() {
+        let source = r#"<mjml>
+  <mj-head>
+    <mj-title>Hello</mj-title>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text>Hello World</mj-text>
+      </mj-column>
+      <mj-column>
+        <mj-text>Second</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let options = RenderOptions::builder().fragment(true).build().unwrap();
+        let output = root.element.render(&options).unwrap();
+        assert!(!output.contains("<!doctype"));
+        assert!(!output.contains("<html"));
+        assert!(!output.contains("<head>"));
+        assert!(!output.contains("<title>"));
+        assert!(!output.contains("<body"));
+        assert!(!output.contains("</html>"));
+        assert!(output.starts_with("<style"));
+        assert!(output.contains(".mj-column-per-50 { width:50% !important; max-width:50%; }"));
+        assert!(output.contains(">Hello World</div>"));
+        assert!(output.trim_end().ends_with("</div>"));
+    }
+
+    #[test]
+    fn should_use_default_attributes_from_options() {
+        let options = RenderOptions::builder()
+            .add_default_attribute("mj-text", "font-family", "Raleway, Arial")
+            .add_default_attribute("mj-all", "color", "#333333")
+            .build()
+            .unwrap();
+        let root = Mjml::parse(
+            "<mjml><mj-body><mj-text>Hello</mj-text><mj-button>Click</mj-button></mj-body></mjml>",
+        )
+        .unwrap();
+        let output = root.element.render(&options).unwrap();
+        assert!(output.contains("font-family:Raleway, Arial;"));
+        assert!(output.contains("color:#333333;"));
+
+        let root = Mjml::parse(
+            r#"<mjml><mj-head><mj-attributes><mj-text font-family="Lato" /></mj-attributes></mj-head><mj-body><mj-text>Hello</mj-text></mj-body></mjml>"#,
+        )
+        .unwrap();
+        let output = root.element.render(&options).unwrap();
+        assert!(output.contains("font-family:Lato;"));
+        assert!(!output.contains("Raleway"));
+    }
+
+    #[test]
+    fn should_prefix_relative_image_urls() {
+        let source = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-image src="images/logo.png" />
+        <mj-image src="https://example.com/banner.png" />
+        <mj-image src="data:image/gif;base64,R0lGODlhAQABAAAAACw=" />
+        <mj-social>
+          <mj-social-element name="facebook" href="https://example.com" src="icons/facebook.png" />
+          <mj-social-element name="twitter" href="https://example.com" />
+        </mj-social>
+        <mj-carousel>
+          <mj-carousel-image src="slides/first.png" />
+        </mj-carousel>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let options = RenderOptions::builder()
+            .image_base_url("https://cdn.example.com")
+            .build()
+            .unwrap();
+        let output = root.element.render(&options).unwrap();
+        assert!(output.contains(r#" src="https://cdn.example.com/images/logo.png" "#));
+        assert!(output.contains(r#" src="https://example.com/banner.png" "#));
+        assert!(output.contains(r#" src="data:image/gif;base64,R0lGODlhAQABAAAAACw=" "#));
+        assert!(output.contains(r#" src="https://cdn.example.com/icons/facebook.png" "#));
+        assert!(output.contains(
+            r#" src="https://www.mailjet.com/images/theme/v1/icons/ico-social/twitter.png" "#
+        ));
+        assert!(output.contains(r#" src="https://cdn.example.com/slides/first.png" "#));
+        assert!(!output.contains(r#"src="images/"#));
+        assert!(!output.contains(r#"src="slides/"#));
+    }
+
+    #[test]
+    fn should_prettify() {
+        let source = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text>Hello <b>World</b></mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let compact = root.element.render(&RenderOptions::default()).unwrap();
+        let pretty = root
+            .element
+            .render(&RenderOptions {
+                pretty: true,
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(pretty.len() > compact.len());
+        assert!(pretty.starts_with("<!doctype html>\n<html "));
+        assert!(pretty.contains("\n  <head>\n    <title>\n    </title>\n"));
+        assert!(pretty.contains("Hello <b>World</b>\n"));
+        assert!(pretty.contains("<!--[if mso | IE]><table"));
+        html_compare::assert_similar(&compact, &pretty);
+    }
+
+    #[test]
+    fn should_inline_css() {
+        let source = r#"<mjml>
+  <mj-head>
+    <mj-style>
+      .btn { color:red }
+      .btn:hover { color:blue }
+    </mj-style>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-raw><p class="btn" style="font-size:12px;">Hello</p><p>World</p></mj-raw>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let normal = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(normal.contains(r#"<p class="btn" style="font-size:12px;">Hello</p>"#));
+
+        let opts = RenderOptions::builder().inline_css(true).build().unwrap();
+        let inlined = root.element.render(&opts).unwrap();
+        assert!(inlined.contains(r#"<p class="btn" style="color:red;font-size:12px;">Hello</p>"#));
+        assert!(inlined.contains("<p>World</p>"));
+        assert!(inlined.contains(".btn { color:red }"));
+
+        let opts = RenderOptions::builder()
+            .inline_css(true)
+            .strip_inlined(true)
+            .build()
+            .unwrap();
+        let stripped = root.element.render(&opts).unwrap();
+        assert!(stripped.contains(r#"<p class="btn" style="color:red;font-size:12px;">Hello</p>"#));
+        assert!(!stripped.contains(".btn { color:red }"));
+        assert!(stripped.contains(".btn:hover {color:blue}"));
+    }
+
+    #[test]
+    fn should_inline_mj_style_inline() {
+        let source = r#"<mjml>
+  <mj-head>
+    <mj-style inline="inline">
+      .btn { color: red; }
+      @media (max-width: 480px) { .btn { color: blue; } }
+    </mj-style>
+    <mj-style>.head { color: green; }</mj-style>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-raw><p class="btn">Hello</p><p class="head">World</p></mj-raw>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let result = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(result.contains(r#"<p class="btn" style="color:red;">Hello</p>"#));
+        assert!(result.contains(r#"<p class="head">World</p>"#));
+        assert!(result.contains(".head { color: green; }"));
+        assert!(!result.contains(".btn {"));
+    }
+
+    #[test]
+    fn should_add_html_attributes() {
+        let source = r#"<mjml>
+  <mj-head>
+    <mj-html-attributes>
+      <mj-selector path=".cta">
+        <mj-html-attribute name="data-id">42</mj-html-attribute>
+      </mj-selector>
+      <mj-selector path=".cta a">
+        <mj-html-attribute name="target">_self</mj-html-attribute>
+      </mj-selector>
+    </mj-html-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-button css-class="cta" href="https://example.com">First</mj-button>
+        <mj-button href="https://example.com">Second</mj-button>
+        <mj-text css-class="cta">Third</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let result = root.element.render(&RenderOptions::default()).unwrap();
+        assert_eq!(result.matches(r#"data-id="42""#).count(), 2);
+        assert!(result.contains(
+            r#"class="cta" style="font-size:0px;padding:10px 25px;word-break:break-word;" data-id="42">"#
+        ));
+        assert_eq!(result.matches(r#"target="_self""#).count(), 1);
+        assert_eq!(result.matches(r#"target="_blank""#).count(), 1);
+        assert!(!result.contains("mj-html-attribute"));
+    }
+
+    #[test]
+    fn should_render_used_google_fonts() {
+        let source = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text font-family="Roboto Condensed, sans-serif">Hello</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let opts = RenderOptions::builder()
+            .with_google_fonts(&["Open Sans", "Roboto Condensed"])
+            .build()
+            .unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains(r#"<link href="https://fonts.googleapis.com/css?family=Roboto+Condensed:300,400,500,700" rel="stylesheet" type="text/css">"#));
+        assert!(!result.contains("family=Open+Sans"));
+    }
+
+    #[test]
+    fn should_render_fonts_of_a_font_stack() {
+        let source = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text font-family="'Roboto Condensed', Helvetica Neue, Arial, sans-serif">Hello</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let opts = RenderOptions::builder()
+            .disable_default_fonts()
+            .add_google_font("Roboto Condensed")
+            .build()
+            .unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains(r#"<link href="https://fonts.googleapis.com/css?family=Roboto+Condensed:300,400,500,700" rel="stylesheet" type="text/css">"#));
+        assert_eq!(result.matches("<link ").count(), 1);
+    }
+
+    #[test]
+    fn should_only_render_used_fonts() {
+        let source = r#"<mjml>
+  <mj-head>
+    <mj-font name="Raleway" href="https://fonts.googleapis.com/css?family=Raleway" />
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text font-family="Lato, sans-serif">Hello</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let opts = RenderOptions::builder()
+            .disable_default_fonts()
+            .with_google_fonts(&["Lato", "Roboto"])
+            .build()
+            .unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains("family=Lato"));
+        assert!(!result.contains("family=Roboto"));
+        assert!(!result.contains("family=Raleway"));
+
+        let opts = RenderOptions::builder()
+            .disable_default_fonts()
+            .with_google_fonts(&["Lato", "Roboto"])
+            .always_include_fonts(true)
+            .build()
+            .unwrap();
+        let result = root.element.render(&opts).unwrap();
+        let lato = result.find("family=Lato").unwrap();
+        let raleway = result.find("family=Raleway").unwrap();
+        let roboto = result.find("family=Roboto").unwrap();
+        assert!(lato < raleway && raleway < roboto);
+    }
+
+    #[test]
+    fn should_render_doctype() {
+        let source = "<mjml><mj-body><mj-section><mj-column><mj-text>hi</mj-text></mj-column></mj-section></mj-body></mjml>";
+        let root = Mjml::parse(source).unwrap();
+        for (doctype, expected) in [
+            (DocType::Html5, "<!doctype html><html "),
+            (
+                DocType::Transitional,
+                r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Transitional//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd"><html "#,
+            ),
+            (
+                DocType::Strict,
+                r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd"><html "#,
+            ),
+        ] {
+            let opts = RenderOptions::builder().doctype(doctype).build().unwrap();
+            let result = root.element.render(&opts).unwrap();
+            assert!(result.starts_with(expected), "{doctype:?}: {result}");
+            let minified = root
+                .element
+                .render(&RenderOptions {
+                    doctype,
+                    minify: true,
+                    ..Default::default()
+                })
+                .unwrap();
+            assert!(minified.starts_with(expected), "{doctype:?}: {minified}");
+        }
+    }
+
+    #[test]
+    fn should_render_rtl_template() {
+        let source = r#"<mjml lang="ar" dir="rtl">
+  <mj-body>
+    <mj-section>
+      <mj-column><mj-text>مرحبا</mj-text></mj-column>
+      <mj-column><mj-text align="left">Hello</mj-text></mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let result = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(result.starts_with(r#"<!doctype html><html lang="ar" dir="rtl" "#));
+        assert!(result.contains(r#"<div lang="ar" dir="rtl">"#));
+        assert!(result.contains("direction:rtl;font-size:0px;padding:20px 0;text-align:center;"));
+        assert!(!result.contains("direction:ltr"));
+        assert!(result.contains(r#"<td align="right" "#));
+        assert!(result.contains(r#"<td align="left" "#));
+    }
+
+    #[test]
+    fn should_render_ltr_template_by_default() {
+        let source = "<mjml><mj-body><mj-section><mj-column><mj-text>Hello</mj-text></mj-column></mj-section></mj-body></mjml>";
+        let root = Mjml::parse(source).unwrap();
+        let result = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(!result.contains(" dir="));
+        assert!(!result.contains("direction:rtl"));
+        assert!(result.contains(r#"<td align="left" "#));
+    }
+
+    #[test]
+    fn should_use_breakpoint_from_options() {
+        let source = "<mjml><mj-body><mj-section><mj-column><mj-text>hi</mj-text></mj-column></mj-section></mj-body></mjml>";
+        let options = RenderOptions::builder()
+            .breakpoint("320px")
+            .build()
+            .unwrap();
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&options).unwrap();
+        assert!(output.contains("@media only screen and (min-width:320px)"));
+    }
+
+    #[test]
+    fn should_prefer_breakpoint_from_template() {
+        let source = "<mjml><mj-head><mj-breakpoint width=\"400px\" /></mj-head><mj-body><mj-section><mj-column><mj-text>hi</mj-text></mj-column></mj-section></mj-body></mjml>";
+        let options = RenderOptions::builder()
+            .breakpoint("320px")
+            .build()
+            .unwrap();
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&options).unwrap();
+        assert!(output.contains("@media only screen and (min-width:400px)"));
+    }
+
+    #[test]
+    fn should_deduplicate_media_queries() {
+        let source = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column><mj-image src="a.png" full-width="full-width" /></mj-column>
+      <mj-column><mj-text>a</mj-text></mj-column>
+    </mj-section>
+    <mj-section>
+      <mj-column><mj-image src="b.png" full-width="full-width" /></mj-column>
+      <mj-column><mj-text>b</mj-text></mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&RenderOptions::default()).unwrap();
+        assert_eq!(
+            output
+                .matches("@media only screen and (min-width:480px)")
+                .count(),
+            1
+        );
+        assert!(output.contains(
+            "@media only screen and (min-width:480px) { .mj-column-per-50 { width:50% !important; max-width:50%; }  }"
+        ));
+        assert_eq!(
+            output
+                .matches("table.mj-full-width-mobile { width: 100% !important; }")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn should_get_breakpoint() {
+        let options = RenderOptions::builder()
+            .breakpoint("320px")
+            .build()
+            .unwrap();
+        let root = Mjml::parse("<mjml><mj-body /></mjml>").unwrap();
+        assert_eq!(
+            root.element.get_breakpoint(&Default::default()).unwrap(),
+            "480px"
+        );
+        assert_eq!(root.element.get_breakpoint(&options).unwrap(), "320px");
+        let root = Mjml::parse(
+            r#"<mjml><mj-head><mj-breakpoint width="400px" /></mj-head><mj-body /></mjml>"#,
+        )
+        .unwrap();
+        assert_eq!(root.element.get_breakpoint(&options).unwrap(), "400px");
+        let root = Mjml::parse(
+            r#"<mjml><mj-head><mj-breakpoint width="50%" /></mj-head><mj-body /></mjml>"#,
+        )
+        .unwrap();
+        assert_eq!(root.element.get_breakpoint(&options).unwrap(), "320px");
+    }
+
+    #[test]
+    fn should_reject_invalid_breakpoint_from_options() {
+        let options = RenderOptions {
+            breakpoint: Some("50%".into()),
+            ..Default::default()
+        };
+        let root = Mjml::parse("<mjml><mj-body /></mjml>").unwrap();
+        assert!(matches!(
+            root.element.get_breakpoint(&options),
+            Err(crate::prelude::render::Error::InvalidBreakpoint(value)) if value == "50%"
+        ));
+        assert!(matches!(
+            root.element.render(&options),
+            Err(crate::prelude::render::Error::InvalidBreakpoint(value)) if value == "50%"
+        ));
+    }
+
+    #[test]
+    fn should_add_link_params() {
+        let source = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-image src="logo.png" href="https://example.com/logo" />
+        <mj-button href="https://example.com/shop?page=2">Shop</mj-button>
+        <mj-button href="mailto:hello@example.com">Contact</mj-button>
+        <mj-social>
+          <mj-social-element name="github-noshare" href="https://github.com/jdrouet/mrml">GitHub</mj-social-element>
+          <mj-social-element name="facebook" href="https://example.com/news">Share</mj-social-element>
+        </mj-social>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let options = RenderOptions::builder()
+            .add_link_param("utm_source", "newsletter")
+            .add_link_param("utm_campaign", "spring")
+            .build()
+            .unwrap();
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.render(&options).unwrap();
+        assert!(output.contains(
+            r#"href="https://example.com/logo?utm_source=newsletter&amp;utm_campaign=spring""#
+        ));
+        assert!(output.contains(
+            r#"href="https://example.com/shop?page=2&amp;utm_source=newsletter&amp;utm_campaign=spring""#
+        ));
+        assert!(output.contains(r#"href="mailto:hello@example.com""#));
+        assert!(output.contains(
+            r#"href="https://github.com/jdrouet/mrml?utm_source=newsletter&amp;utm_campaign=spring""#
+        ));
+        assert!(output.contains(
+            r#"href="https://www.facebook.com/sharer/sharer.php?u=https%3A%2F%2Fexample.com%2Fnews%3Futm_source%3Dnewsletter%26utm_campaign%3Dspring""#
+        ));
+    }
+
+    #[test]
+    fn should_display_html() {
+        let source = "<mjml><mj-body><mj-section><mj-column><mj-text>hi</mj-text></mj-column></mj-section></mj-body></mjml>";
+        let root = Mjml::parse(source).unwrap();
+        let output = root.element.to_string();
+        assert!(output.starts_with("<!doctype html>"));
+        assert_eq!(
+            output,
+            root.element.render(&RenderOptions::default()).unwrap()
+        );
+        assert_eq!(format!("{}", root.element), output);
+    }
+
+    #[test]
+    fn stable_styles_with_merged_attributes() {
+        let source = r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <mj-all font-family="Arial" />
+      <mj-class name="big" font-size="20px" line-height="24px" />
+      <mj-text color="red" />
+    </mj-attributes>
+    <mj-style inline="inline">.red { color: blue; text-align: center; }</mj-style>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text mj-class="big" css-class="red" padding="4px">hi</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let outputs = (0..3)
+            .map(|_| {
+                let root = Mjml::parse(source).unwrap();
+                root.element.render(&RenderOptions::default()).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(outputs[0], outputs[1]);
+        assert_eq!(outputs[0], outputs[2]);
+        assert!(outputs[0].contains(
+            "font-family:Arial;font-size:20px;line-height:24px;text-align:left;color:red;"
+        ));
+    }
+
     #[test]
     fn stable_output() {
         let source = "<mjml><mj-body><mj-section><mj-column><mj-text>hi</mj-text></mj-column></mj-section></mj-body></mjml>";
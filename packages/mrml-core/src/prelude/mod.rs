@@ -10,6 +10,8 @@ pub mod print;
 pub mod render;
 
 pub mod hash;
+pub mod transform;
+pub mod visit;
 
 pub trait StaticTag {
     fn static_tag() -> &'static str;
@@ -0,0 +1,421 @@
+//! Attributes supported by the body components, used to report the unknown
+//! attributes when validating a template or in
+//! [`strict`](super::ParserOptions::strict) mode.
+
+/// Attributes supported by every component.
+const GLOBAL: &[&str] = &["css-class", "mj-class"];
+
+const PADDING: &[&str] = &[
+    "padding",
+    "padding-bottom",
+    "padding-left",
+    "padding-right",
+    "padding-top",
+];
+
+const BORDER: &[&str] = &[
+    "border",
+    "border-bottom",
+    "border-left",
+    "border-radius",
+    "border-right",
+    "border-top",
+];
+
+const ICON: &[&str] = &[
+    "icon-align",
+    "icon-height",
+    "icon-position",
+    "icon-unwrapped-alt",
+    "icon-unwrapped-url",
+    "icon-width",
+    "icon-wrapped-alt",
+    "icon-wrapped-url",
+];
+
+const MJ_ACCORDION: &[&[&str]] = &[
+    PADDING,
+    ICON,
+    &["border", "container-background-color", "font-family"],
+];
+
+const MJ_ACCORDION_ELEMENT: &[&[&str]] = &[ICON, &["background-color", "border", "font-family"]];
+
+const MJ_ACCORDION_TEXT: &[&[&str]] = &[
+    PADDING,
+    &[
+        "background-color",
+        "border",
+        "color",
+        "font-family",
+        "font-size",
+        "font-weight",
+        "letter-spacing",
+        "line-height",
+    ],
+];
+
+const MJ_ACCORDION_TITLE: &[&[&str]] = &[
+    PADDING,
+    ICON,
+    &[
+        "background-color",
+        "border",
+        "color",
+        "font-family",
+        "font-size",
+        "font-weight",
+    ],
+];
+
+const MJ_BODY: &[&[&str]] = &[&["background-color", "width"]];
+
+const MJ_BUTTON: &[&[&str]] = &[
+    PADDING,
+    BORDER,
+    &[
+        "align",
+        "aria-label",
+        "background-color",
+        "color",
+        "container-background-color",
+        "font-family",
+        "font-size",
+        "font-style",
+        "font-weight",
+        "height",
+        "href",
+        "inner-padding",
+        "inner-padding-left",
+        "inner-padding-right",
+        "letter-spacing",
+        "line-height",
+        "name",
+        "overflow-wrap",
+        "rel",
+        "role",
+        "target",
+        "text-align",
+        "text-decoration",
+        "text-transform",
+        "title",
+        "vertical-align",
+        "width",
+        "word-break",
+    ],
+];
+
+const MJ_CAROUSEL: &[&[&str]] = &[
+    PADDING,
+    &[
+        "align",
+        "border-radius",
+        "container-background-color",
+        "icon-width",
+        "left-icon",
+        "right-icon",
+        "tb-border",
+        "tb-border-radius",
+        "tb-hover-border-color",
+        "tb-selected-border-color",
+        "tb-width",
+        "thumbnails",
+    ],
+];
+
+const MJ_CAROUSEL_IMAGE: &[&[&str]] = &[&[
+    "alt",
+    "border-radius",
+    "href",
+    "rel",
+    "src",
+    "target",
+    "tb-border",
+    "tb-border-radius",
+    "thumbnails-src",
+    "title",
+]];
+
+const MJ_COLUMN: &[&[&str]] = &[
+    PADDING,
+    BORDER,
+    &[
+        "background-color",
+        "direction",
+        "inner-background-color",
+        "inner-border",
+        "inner-border-bottom",
+        "inner-border-left",
+        "inner-border-radius",
+        "inner-border-right",
+        "inner-border-top",
+        "mobile-order",
+        "mobile-width",
+        "vertical-align",
+        "width",
+    ],
+];
+
+const MJ_DIVIDER: &[&[&str]] = &[
+    PADDING,
+    &[
+        "align",
+        "border-color",
+        "border-style",
+        "border-width",
+        "container-background-color",
+        "width",
+    ],
+];
+
+const MJ_GROUP: &[&[&str]] = &[&[
+    "background-color",
+    "direction",
+    "mobile-width",
+    "vertical-align",
+    "width",
+]];
+
+const MJ_HERO: &[&[&str]] = &[
+    PADDING,
+    &[
+        "background-color",
+        "background-height",
+        "background-position",
+        "background-url",
+        "background-width",
+        "border-radius",
+        "container-background-color",
+        "height",
+        "inner-background-color",
+        "inner-padding",
+        "inner-padding-bottom",
+        "inner-padding-left",
+        "inner-padding-right",
+        "inner-padding-top",
+        "mode",
+        "vertical-align",
+        "width",
+    ],
+];
+
+const MJ_IMAGE: &[&[&str]] = &[
+    PADDING,
+    BORDER,
+    &[
+        "align",
+        "alt",
+        "container-background-color",
+        "fluid-on-mobile",
+        "font-size",
+        "full-width",
+        "height",
+        "href",
+        "max-height",
+        "name",
+        "rel",
+        "retina",
+        "retina-suffix",
+        "sizes",
+        "src",
+        "srcset",
+        "target",
+        "title",
+        "usemap",
+        "width",
+    ],
+];
+
+const MJ_NAVBAR: &[&[&str]] = &[&[
+    "align",
+    "base-url",
+    "hamburger",
+    "ico-align",
+    "ico-close",
+    "ico-color",
+    "ico-font-family",
+    "ico-font-size",
+    "ico-line-height",
+    "ico-open",
+    "ico-padding",
+    "ico-padding-bottom",
+    "ico-padding-left",
+    "ico-padding-right",
+    "ico-padding-top",
+    "ico-text-decoration",
+    "ico-text-transform",
+]];
+
+const MJ_NAVBAR_LINK: &[&[&str]] = &[
+    PADDING,
+    &[
+        "color",
+        "font-family",
+        "font-size",
+        "font-style",
+        "font-weight",
+        "href",
+        "letter-spacing",
+        "line-height",
+        "name",
+        "rel",
+        "target",
+        "text-decoration",
+        "text-transform",
+    ],
+];
+
+const MJ_SECTION: &[&[&str]] = &[
+    PADDING,
+    BORDER,
+    &[
+        "background-color",
+        "background-position",
+        "background-position-x",
+        "background-position-y",
+        "background-repeat",
+        "background-size",
+        "background-url",
+        "direction",
+        "full-width",
+        "text-align",
+    ],
+];
+
+const MJ_SOCIAL: &[&[&str]] = &[
+    PADDING,
+    &[
+        "align",
+        "border-radius",
+        "color",
+        "container-background-color",
+        "font-family",
+        "font-size",
+        "font-style",
+        "font-weight",
+        "icon-height",
+        "icon-padding",
+        "icon-size",
+        "inner-padding",
+        "line-height",
+        "mode",
+        "table-layout",
+        "text-decoration",
+        "text-padding",
+        "vertical-align",
+    ],
+];
+
+const MJ_SOCIAL_ELEMENT: &[&[&str]] = &[
+    PADDING,
+    &[
+        "align",
+        "alt",
+        "background-color",
+        "border-radius",
+        "color",
+        "font-family",
+        "font-size",
+        "font-style",
+        "font-weight",
+        "href",
+        "icon-height",
+        "icon-padding",
+        "icon-size",
+        "line-height",
+        "name",
+        "rel",
+        "sizes",
+        "src",
+        "srcset",
+        "target",
+        "text-decoration",
+        "text-padding",
+        "title",
+        "vertical-align",
+    ],
+];
+
+const MJ_SPACER: &[&[&str]] = &[PADDING, BORDER, &["container-background-color", "height"]];
+
+const MJ_TABLE: &[&[&str]] = &[
+    PADDING,
+    &[
+        "align",
+        "border",
+        "cellpadding",
+        "cellspacing",
+        "color",
+        "container-background-color",
+        "font-family",
+        "font-size",
+        "line-height",
+        "role",
+        "stripe-color",
+        "striped",
+        "table-layout",
+        "vertical-align",
+        "width",
+    ],
+];
+
+const MJ_TEXT: &[&[&str]] = &[
+    PADDING,
+    &[
+        "align",
+        "background-color",
+        "color",
+        "container-background-color",
+        "dir",
+        "font-family",
+        "font-size",
+        "font-style",
+        "font-weight",
+        "height",
+        "letter-spacing",
+        "line-height",
+        "overflow-wrap",
+        "text-decoration",
+        "text-transform",
+        "unicode-bidi",
+        "vertical-align",
+        "word-break",
+    ],
+];
+
+/// Attributes supported by the body component with the given name, `None`
+/// when the element accepts any attribute.
+fn supported_attributes(element: &str) -> Option<&'static [&'static [&'static str]]> {
+    match element {
+        crate::mj_accordion::NAME => Some(MJ_ACCORDION),
+        crate::mj_accordion_element::NAME => Some(MJ_ACCORDION_ELEMENT),
+        crate::mj_accordion_text::NAME => Some(MJ_ACCORDION_TEXT),
+        crate::mj_accordion_title::NAME => Some(MJ_ACCORDION_TITLE),
+        crate::mj_body::NAME => Some(MJ_BODY),
+        crate::mj_button::NAME => Some(MJ_BUTTON),
+        crate::mj_carousel::NAME => Some(MJ_CAROUSEL),
+        crate::mj_carousel_image::NAME => Some(MJ_CAROUSEL_IMAGE),
+        crate::mj_column::NAME => Some(MJ_COLUMN),
+        crate::mj_divider::NAME => Some(MJ_DIVIDER),
+        crate::mj_group::NAME => Some(MJ_GROUP),
+        crate::mj_hero::NAME => Some(MJ_HERO),
+        crate::mj_image::NAME => Some(MJ_IMAGE),
+        crate::mj_navbar::NAME => Some(MJ_NAVBAR),
+        crate::mj_navbar_link::NAME => Some(MJ_NAVBAR_LINK),
+        crate::mj_section::NAME | crate::mj_wrapper::NAME => Some(MJ_SECTION),
+        crate::mj_social::NAME => Some(MJ_SOCIAL),
+        crate::mj_social_element::NAME => Some(MJ_SOCIAL_ELEMENT),
+        crate::mj_spacer::NAME => Some(MJ_SPACER),
+        crate::mj_table::NAME => Some(MJ_TABLE),
+        crate::mj_text::NAME => Some(MJ_TEXT),
+        _ => None,
+    }
+}
+
+/// Checks if the attribute is supported by the element, always true for the
+/// elements accepting any attribute.
+pub(crate) fn is_supported_attribute(element: &str, name: &str) -> bool {
+    supported_attributes(element).is_none_or(|groups| {
+        GLOBAL.contains(&name) || groups.iter().any(|group| group.contains(&name))
+    })
+}
@@ -0,0 +1,133 @@
+//! Custom elements, like `<my-widget>`, replaced by regular elements while
+//! parsing the body of a template.
+
+use crate::mj_body::MjBodyChild;
+use crate::node::Node;
+use crate::prelude::hash::Map;
+
+/// Builds the element replacing a custom element found in the body. The
+/// custom element is given as a [`Node`], with its attributes and its parsed
+/// children, and can be replaced by any element, like a `mj-raw` or a
+/// `mj-section`.
+pub trait CustomElement: Send + Sync {
+    fn build(&self, node: Node<MjBodyChild>) -> MjBodyChild;
+}
+
+impl<F> CustomElement for F
+where
+    F: Fn(Node<MjBodyChild>) -> MjBodyChild + Send + Sync,
+{
+    fn build(&self, node: Node<MjBodyChild>) -> MjBodyChild {
+        self(node)
+    }
+}
+
+/// Registry of the [`CustomElement`]s, by tag name, used by the parser.
+///
+/// Without a registered custom element, the unknown elements of the body are
+/// kept as html elements.
+///
+/// ```rust
+/// use mrml::mj_body::MjBodyChild;
+/// use mrml::node::Node;
+/// use mrml::prelude::parser::ParserOptions;
+///
+/// let mut options = ParserOptions::default();
+/// options.custom_elements.register("my-card", |node: Node<MjBodyChild>| {
+///     let mut card = mrml::mj_column::MjColumn::default();
+///     card.children = node.children;
+///     card.attributes
+///         .insert("background-color".into(), Some("#f0f0f0".into()));
+///     MjBodyChild::MjColumn(card)
+/// });
+/// let root = mrml::parse_with_options(
+///     "<mjml><mj-body><mj-section><my-card><mj-text>Hello</mj-text></my-card></mj-section></mj-body></mjml>",
+///     &options,
+/// )
+/// .unwrap();
+/// let html = root.element.render(&Default::default()).unwrap();
+/// assert!(html.contains("background-color:#f0f0f0;"));
+/// assert!(!html.contains("my-card"));
+/// ```
+pub struct CustomElements {
+    inner: Map<String, Box<dyn CustomElement>>,
+}
+
+impl Default for CustomElements {
+    fn default() -> Self {
+        Self { inner: Map::new() }
+    }
+}
+
+impl std::fmt::Debug for CustomElements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.inner.keys()).finish()
+    }
+}
+
+impl CustomElements {
+    pub fn register<N: Into<String>, E: CustomElement + 'static>(&mut self, name: N, element: E) {
+        self.inner.insert(name.into(), Box::new(element));
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.inner.contains_key(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Replaces the node when a custom element is registered with its tag,
+    /// otherwise keeps it as an html element.
+    pub(crate) fn build(&self, node: Node<MjBodyChild>) -> MjBodyChild {
+        match self.inner.get(node.tag.as_str()) {
+            Some(element) => element.build(node),
+            None => MjBodyChild::Node(node),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CustomElements;
+    use crate::mj_body::MjBodyChild;
+    use crate::mj_raw::{MjRaw, MjRawChild};
+    use crate::node::Node;
+    use crate::prelude::parser::ParserOptions;
+
+    fn fixed_div(_node: Node<MjBodyChild>) -> MjBodyChild {
+        let mut div = Node::<MjRawChild>::from("div");
+        div.attributes.insert("class".into(), Some("widget".into()));
+        div.children
+            .push(MjRawChild::Text(crate::text::Text::from("Widget")));
+        let mut raw = MjRaw::default();
+        raw.children.push(MjRawChild::Node(div));
+        MjBodyChild::MjRaw(raw)
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn should_replace_custom_element() {
+        let mut options = ParserOptions::default();
+        options.custom_elements.register("my-widget", fixed_div);
+        let template = r#"<mjml><mj-body><mj-section><mj-column><my-widget size="big" /><mj-text>Hello</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::parse_with_options(template, &options).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(html.contains(r#"<div class="widget">Widget</div>"#));
+        assert!(!html.contains("my-widget"));
+        assert!(html.contains("Hello"));
+    }
+
+    #[test]
+    fn should_keep_unknown_elements() {
+        let mut elements = CustomElements::default();
+        elements.register("my-widget", fixed_div);
+        assert!(elements.contains("my-widget"));
+        assert!(matches!(
+            elements.build(Node::from("other-widget")),
+            MjBodyChild::Node(node) if node.tag == "other-widget"
+        ));
+        assert_eq!(format!("{elements:?}"), r#"{"my-widget"}"#);
+    }
+}
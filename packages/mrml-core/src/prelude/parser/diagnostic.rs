@@ -0,0 +1,200 @@
+//! Reports all the issues of a template, skipping the unexpected elements
+//! instead of stopping at the first error.
+
+use super::{
+    Error, Location, MrmlCursor, MrmlParser, Origin, ParseChildren, ParserOptions, Span, Warning,
+};
+use crate::root::RootChild;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// The template can't be parsed.
+    Error,
+    /// The template can be parsed but something will be ignored.
+    Warning,
+}
+
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    /// Template where the issue has been found, `None` when the issue concerns
+    /// the whole document.
+    pub origin: Option<Origin>,
+    pub position: Option<Span>,
+    /// Line and column of the issue in the template defined by its origin. For
+    /// the warnings, it's only available for the root template.
+    pub location: Option<Location>,
+}
+
+impl Diagnostic {
+    fn new(
+        severity: Severity,
+        message: String,
+        origin: Option<&Origin>,
+        position: Option<Span>,
+        location: Option<Location>,
+    ) -> Self {
+        Self {
+            severity,
+            message,
+            origin: origin.cloned(),
+            position,
+            location,
+        }
+    }
+
+    fn from_error(error: &Error) -> Self {
+        Self::new(
+            Severity::Error,
+            error.to_string(),
+            error.origin(),
+            error.span(),
+            error.location(),
+        )
+    }
+
+    fn from_warning(warning: &Warning, source: &str) -> Self {
+        let location = match warning.origin {
+            Origin::Root => Some(warning.span.location(source)),
+            Origin::Include { .. } => None,
+        };
+        Self::new(
+            Severity::Warning,
+            warning.to_string(),
+            Some(&warning.origin),
+            Some(warning.span),
+            location,
+        )
+    }
+}
+
+/// Parses the template once, keeping the unexpected elements as errors and
+/// skipping them, until the end of the template or the first error that can't
+/// be skipped.
+pub(crate) fn validate(input: &str, options: &ParserOptions) -> Vec<Diagnostic> {
+    let parser = MrmlParser::new(options);
+    let mut cursor = MrmlCursor::new(input);
+    cursor.set_limits(options.max_depth, options.max_elements);
+    cursor.set_strict(options.strict);
+    cursor.set_keep_line_endings(options.keep_line_endings);
+    cursor.set_validating(true);
+    let result: Result<Vec<RootChild>, Error> = parser.parse_children(&mut cursor);
+    let mut diagnostics = cursor
+        .errors()
+        .iter()
+        .map(Diagnostic::from_error)
+        .collect::<Vec<_>>();
+    diagnostics.extend(
+        cursor
+            .warnings()
+            .iter()
+            .map(|warning| Diagnostic::from_warning(warning, input)),
+    );
+    if let Err(error) = result {
+        diagnostics.push(Diagnostic::from_error(&error));
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Severity;
+    use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+    use crate::prelude::parser::{Origin, ParserOptions};
+
+    #[test]
+    fn should_report_multiple_errors() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-title>First</mj-title>
+    <mj-foo><mj-bar /></mj-foo>
+    <mj-title>Second</mj-title>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column width="half">
+        <mj-social>
+          <mj-text>Hello</mj-text>
+          <mj-social-element name="facebook" icon-size="big" />
+        </mj-social>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let result = crate::validate(template, &ParserOptions::default());
+        let found = result
+            .iter()
+            .map(|item| {
+                let location = item.location.unwrap();
+                (item.severity, location.line, location.column)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                (Severity::Error, 4, 6),
+                (Severity::Error, 11, 11),
+                (Severity::Warning, 5, 5),
+                (Severity::Warning, 9, 18),
+                (Severity::Warning, 12, 46),
+            ]
+        );
+        assert!(result[0].message.starts_with("unexpected element"));
+        assert!(result[2].message.starts_with("duplicate element"));
+        assert!(result[3].message.starts_with("invalid size"));
+    }
+
+    #[test]
+    fn should_warn_about_invalid_sizes() {
+        let template = r#"<mjml><mj-body><mj-text font-size="big" line-height="" height>Hello</mj-text></mj-body></mjml>"#;
+        let result = crate::validate(template, &ParserOptions::default());
+        assert_eq!(result.len(), 3);
+        assert!(result
+            .iter()
+            .all(|item| item.severity == Severity::Warning
+                && item.message.starts_with("invalid size")));
+        let output = crate::parse(template).unwrap();
+        assert!(output.warnings.is_empty());
+    }
+
+    #[test]
+    fn should_skip_unexpected_elements_in_includes() {
+        let resolver = MemoryIncludeLoader::from(vec![(
+            "partial.mjml",
+            "<mj-section><mj-column><mj-social><mj-text>Hello</mj-text></mj-social></mj-column></mj-section>",
+        )]);
+        let options = ParserOptions {
+            include_loader: Box::new(resolver),
+            ..Default::default()
+        };
+        let template = r#"<mjml><mj-body><mj-include path="partial.mjml" /><mj-section><mj-column><mj-social><mj-foo /></mj-social></mj-column></mj-section></mj-body></mjml>"#;
+        let result = crate::validate(template, &options);
+        assert_eq!(result.len(), 2);
+        assert!(matches!(result[0].origin, Some(Origin::Include { .. })));
+        assert!(matches!(result[1].origin, Some(Origin::Root)));
+    }
+
+    #[test]
+    fn should_stop_at_unrecoverable_error() {
+        let template = "<mjml><mj-head><mj-foo></mj-head></mjml>";
+        let result = crate::validate(template, &ParserOptions::default());
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn should_keep_warnings_before_unrecoverable_error() {
+        let template = r#"<mjml><mj-body><mj-text font-size="big">Hello</mj-text><mj-section></mj-body></mjml>"#;
+        let result = crate::validate(template, &ParserOptions::default());
+        let severities = result.iter().map(|item| item.severity).collect::<Vec<_>>();
+        assert_eq!(severities, vec![Severity::Warning, Severity::Error]);
+        assert!(result[0].message.starts_with("invalid size"));
+    }
+
+    #[test]
+    fn should_be_empty_for_valid_template() {
+        let template = "<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>";
+        assert!(crate::validate(template, &ParserOptions::default()).is_empty());
+    }
+}
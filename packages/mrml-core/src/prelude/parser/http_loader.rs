@@ -169,6 +169,7 @@ impl OriginList {
 ///     let resolver = HttpIncludeLoader::<BlockingReqwestFetcher>::new_allow(HashSet::from(["http://localhost".to_string()]));
 ///     let opts = ParserOptions {
 ///         include_loader: Box::new(resolver),
+///         ..Default::default()
 ///     };
 ///     let template = r#"<mjml>
 ///       <mj-body>
@@ -193,6 +194,7 @@ impl OriginList {
 ///     let resolver = HttpIncludeLoader::<UreqFetcher>::new_allow(HashSet::from(["http://localhost".to_string()]));
 ///     let opts = ParserOptions {
 ///         include_loader: Box::new(resolver),
+///         ..Default::default()
 ///     };
 ///     let template = r#"<mjml>
 ///       <mj-body>
@@ -23,6 +23,7 @@ use crate::prelude::parser::loader::IncludeLoader;
 /// let resolver = MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-button>Hello</mj-button>")]);
 /// let opts = ParserOptions {
 ///     include_loader: Box::new(resolver),
+///     ..Default::default()
 /// };
 /// let json = r#"<mjml>
 ///   <mj-body>
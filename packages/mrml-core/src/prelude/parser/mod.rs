@@ -1,4 +1,5 @@
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use htmlparser::{StrSpan, Tokenizer};
 
@@ -14,9 +15,14 @@ pub mod memory_loader;
 pub mod multi_loader;
 pub mod noop_loader;
 
+mod attributes;
+mod custom;
+mod diagnostic;
 mod output;
 mod token;
 
+pub use custom::*;
+pub use diagnostic::*;
 pub use output::*;
 pub use token::*;
 
@@ -35,22 +41,78 @@ impl std::fmt::Display for Origin {
     }
 }
 
+/// Limit of the [`ParserOptions`] exceeded by a template.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Limit {
+    /// See [`ParserOptions::max_depth`].
+    Depth,
+    /// See [`ParserOptions::max_elements`].
+    Elements,
+}
+
+impl std::fmt::Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Depth => write!(f, "depth"),
+            Self::Elements => write!(f, "elements"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum Error {
-    #[error("unexpected element in {origin} at position {position}")]
-    UnexpectedElement { origin: Origin, position: Span },
-    #[error("unexpected token in {origin} at position {position}")]
-    UnexpectedToken { origin: Origin, position: Span },
-    #[error("missing attribute {name:?} in element in {origin} at position {position}")]
+    #[error("unexpected element in {origin} at {location}")]
+    UnexpectedElement {
+        origin: Origin,
+        position: Span,
+        location: Location,
+    },
+    /// An element that can only be defined once, like `mj-body`, is defined
+    /// several times. A duplicate `mj-title` or `mj-preview` is only an error
+    /// in [`strict`](ParserOptions::strict) mode, otherwise it's reported as a
+    /// [`WarningKind::DuplicateElement`] warning and the last one is used, like
+    /// mjml does.
+    #[error("duplicate element in {origin} at {location}")]
+    DuplicateElement {
+        origin: Origin,
+        position: Span,
+        location: Location,
+    },
+    #[error("unexpected token in {origin} at {location}")]
+    UnexpectedToken {
+        origin: Origin,
+        position: Span,
+        location: Location,
+    },
+    #[error("missing attribute {name:?} in element in {origin} at {location}")]
     MissingAttribute {
         name: &'static str,
         origin: Origin,
         position: Span,
+        location: Location,
+    },
+    /// An attribute not supported by the element, only raised in
+    /// [`strict`](ParserOptions::strict) mode.
+    #[error("unknown attribute {name:?} for {element} in {origin} at {location}")]
+    UnknownAttribute {
+        element: String,
+        name: String,
+        origin: Origin,
+        position: Span,
+        location: Location,
+    },
+    #[error("invalid attribute in {origin} at {location}")]
+    InvalidAttribute {
+        origin: Origin,
+        position: Span,
+        location: Location,
+    },
+    #[error("invalid format in {origin} at {location}")]
+    InvalidFormat {
+        origin: Origin,
+        position: Span,
+        location: Location,
     },
-    #[error("invalid attribute in {origin} at position {position}")]
-    InvalidAttribute { origin: Origin, position: Span },
-    #[error("invalid format in {origin} at position {position}")]
-    InvalidFormat { origin: Origin, position: Span },
     #[error("unexpected end of stream in {origin}")]
     EndOfStream { origin: Origin },
     /// The input string should be smaller than 4GiB.
@@ -66,18 +128,150 @@ pub enum Error {
     /// The Mjml document must have at least one element.
     #[error("unable to find mjml element")]
     NoRootNode,
-    #[error("unable to load included template in {origin} at position {position}")]
+    #[error("unable to load included template in {origin} at {location}")]
     IncludeLoaderError {
         origin: Origin,
         position: Span,
+        location: Location,
+        #[source]
+        source: Box<IncludeLoaderError>,
+    },
+    /// The included template is already being included by one of its parents.
+    #[error("include cycle with {path:?} in {origin} at {location}")]
+    IncludeCycle {
+        path: String,
+        origin: Origin,
+        position: Span,
+        location: Location,
+    },
+    /// The template couldn't be read, see [`parse_reader`](crate::parse_reader).
+    #[error("unable to read template")]
+    Io {
         #[source]
-        source: IncludeLoaderError,
+        source: Arc<std::io::Error>,
+    },
+    /// The template is nested too deeply or contains too many elements, see
+    /// the limits of the [`ParserOptions`].
+    #[error("{limit} limit exceeded in {origin} at {location}")]
+    LimitExceeded {
+        limit: Limit,
+        origin: Origin,
+        position: Span,
+        location: Location,
     },
 }
 
+impl Error {
+    /// Template where the error occurred.
+    pub fn origin(&self) -> Option<&Origin> {
+        match self {
+            Self::UnexpectedElement { origin, .. }
+            | Self::DuplicateElement { origin, .. }
+            | Self::UnexpectedToken { origin, .. }
+            | Self::MissingAttribute { origin, .. }
+            | Self::UnknownAttribute { origin, .. }
+            | Self::InvalidAttribute { origin, .. }
+            | Self::InvalidFormat { origin, .. }
+            | Self::EndOfStream { origin }
+            | Self::SizeLimit { origin }
+            | Self::ParserError { origin, .. }
+            | Self::IncludeLoaderError { origin, .. }
+            | Self::IncludeCycle { origin, .. }
+            | Self::LimitExceeded { origin, .. } => Some(origin),
+            Self::NoRootNode | Self::Io { .. } => None,
+        }
+    }
+
+    /// Position of the error in the template defined by its
+    /// [origin](Self::origin).
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::UnexpectedElement { position, .. }
+            | Self::DuplicateElement { position, .. }
+            | Self::UnexpectedToken { position, .. }
+            | Self::MissingAttribute { position, .. }
+            | Self::UnknownAttribute { position, .. }
+            | Self::InvalidAttribute { position, .. }
+            | Self::InvalidFormat { position, .. }
+            | Self::IncludeLoaderError { position, .. }
+            | Self::IncludeCycle { position, .. }
+            | Self::LimitExceeded { position, .. } => Some(*position),
+            Self::EndOfStream { .. }
+            | Self::SizeLimit { .. }
+            | Self::ParserError { .. }
+            | Self::NoRootNode
+            | Self::Io { .. } => None,
+        }
+    }
+
+    /// Line and column of the error in the template defined by its
+    /// [origin](Self::origin).
+    ///
+    /// ```rust
+    /// let source = "<mjml>\n  <mj-head>\n    <mj-foo />\n  </mj-head>\n</mjml>";
+    /// let Err(error) = mrml::parse(source) else {
+    ///     panic!("should fail");
+    /// };
+    /// let location = error.location().unwrap();
+    /// assert_eq!(location.to_string(), "line 3, column 6");
+    /// ```
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Self::UnexpectedElement { location, .. }
+            | Self::DuplicateElement { location, .. }
+            | Self::UnexpectedToken { location, .. }
+            | Self::MissingAttribute { location, .. }
+            | Self::UnknownAttribute { location, .. }
+            | Self::InvalidAttribute { location, .. }
+            | Self::InvalidFormat { location, .. }
+            | Self::IncludeLoaderError { location, .. }
+            | Self::IncludeCycle { location, .. }
+            | Self::LimitExceeded { location, .. } => Some(*location),
+            Self::EndOfStream { .. }
+            | Self::SizeLimit { .. }
+            | Self::ParserError { .. }
+            | Self::NoRootNode
+            | Self::Io { .. } => None,
+        }
+    }
+}
+
+/// Default maximum number of nested elements.
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+/// Default maximum number of elements in a template, included templates
+/// included.
+pub const DEFAULT_MAX_ELEMENTS: usize = 100_000;
+/// Default length of a `mj-preview`, in characters, above which a warning is
+/// raised.
+pub const DEFAULT_MAX_PREVIEW_LENGTH: usize = 150;
+
 #[derive(Debug)]
 pub struct ParserOptions {
     pub include_loader: Box<dyn loader::IncludeLoader>,
+    /// Maximum number of nested elements, exceeding it fails with
+    /// [`Error::LimitExceeded`].
+    pub max_depth: usize,
+    /// Maximum number of elements, exceeding it fails with
+    /// [`Error::LimitExceeded`].
+    pub max_elements: usize,
+    /// Length of a `mj-preview`, in characters, above which a
+    /// [`WarningKind::PreviewTooLong`] warning is raised, email clients
+    /// truncating it.
+    pub max_preview_length: usize,
+    /// Fails with [`Error::UnknownAttribute`] or
+    /// [`Error::DuplicateElement`] instead of adding a warning when an mj
+    /// element, like `mj-font` or `mj-text`, gets an attribute it doesn't
+    /// support, or when an element like `mj-title` is defined several times.
+    /// An `mj-breakpoint` with a width that is not a pixel value fails with
+    /// [`Error::InvalidAttribute`].
+    pub strict: bool,
+    /// Elements replacing the custom elements found in the body, see
+    /// [`CustomElements`].
+    pub custom_elements: CustomElements,
+    /// Keeps the `\r\n` line endings of the text content as they are. By
+    /// default they're replaced with `\n`, for the rendered template to be
+    /// the same whatever the platform the template was written on.
+    pub keep_line_endings: bool,
 }
 
 #[allow(clippy::box_default)]
@@ -85,6 +279,12 @@ impl Default for ParserOptions {
     fn default() -> Self {
         Self {
             include_loader: Box::new(noop_loader::NoopIncludeLoader),
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_elements: DEFAULT_MAX_ELEMENTS,
+            max_preview_length: DEFAULT_MAX_PREVIEW_LENGTH,
+            strict: false,
+            custom_elements: CustomElements::default(),
+            keep_line_endings: false,
         }
     }
 }
@@ -93,6 +293,30 @@ impl Default for ParserOptions {
 #[derive(Debug)]
 pub struct AsyncParserOptions {
     pub include_loader: Box<dyn loader::AsyncIncludeLoader + Send + Sync>,
+    /// Maximum number of nested elements, exceeding it fails with
+    /// [`Error::LimitExceeded`].
+    pub max_depth: usize,
+    /// Maximum number of elements, exceeding it fails with
+    /// [`Error::LimitExceeded`].
+    pub max_elements: usize,
+    /// Length of a `mj-preview`, in characters, above which a
+    /// [`WarningKind::PreviewTooLong`] warning is raised, email clients
+    /// truncating it.
+    pub max_preview_length: usize,
+    /// Fails with [`Error::UnknownAttribute`] or
+    /// [`Error::DuplicateElement`] instead of adding a warning when an mj
+    /// element, like `mj-font` or `mj-text`, gets an attribute it doesn't
+    /// support, or when an element like `mj-title` is defined several times.
+    /// An `mj-breakpoint` with a width that is not a pixel value fails with
+    /// [`Error::InvalidAttribute`].
+    pub strict: bool,
+    /// Elements replacing the custom elements found in the body, see
+    /// [`CustomElements`].
+    pub custom_elements: CustomElements,
+    /// Keeps the `\r\n` line endings of the text content as they are. By
+    /// default they're replaced with `\n`, for the rendered template to be
+    /// the same whatever the platform the template was written on.
+    pub keep_line_endings: bool,
 }
 
 #[cfg(feature = "async")]
@@ -101,6 +325,12 @@ impl Default for AsyncParserOptions {
     fn default() -> Self {
         Self {
             include_loader: Box::new(noop_loader::NoopIncludeLoader),
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_elements: DEFAULT_MAX_ELEMENTS,
+            max_preview_length: DEFAULT_MAX_PREVIEW_LENGTH,
+            strict: false,
+            custom_elements: CustomElements::default(),
+            keep_line_endings: false,
         }
     }
 }
@@ -136,40 +366,242 @@ pub(crate) trait AsyncParseChildren<C> {
 }
 
 pub struct MrmlCursor<'a> {
+    source: &'a str,
     tokenizer: Tokenizer<'a>,
     buffer: Vec<MrmlToken<'a>>,
     origin: Origin,
+    // paths of the templates currently being included, used to detect cycles
+    includes: Vec<String>,
     warnings: Vec<Warning>,
+    // names of the elements currently open, to limit the depth
+    stack: Vec<&'a str>,
+    // depth of the mj-include element when the template is included
+    base_depth: usize,
+    // number of elements parsed so far, included templates included
+    elements: usize,
+    max_depth: usize,
+    max_elements: usize,
+    strict: bool,
+    keep_line_endings: bool,
+    // when validating, the unexpected elements are skipped and kept here
+    validating: bool,
+    errors: Vec<Error>,
 }
 
 impl<'a> MrmlCursor<'a> {
     pub fn new(source: &'a str) -> Self {
         Self {
+            source,
             tokenizer: Tokenizer::from(source),
             buffer: Default::default(),
             origin: Origin::Root,
+            includes: Default::default(),
             warnings: Default::default(),
+            stack: Default::default(),
+            base_depth: 0,
+            elements: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_elements: DEFAULT_MAX_ELEMENTS,
+            strict: false,
+            keep_line_endings: false,
+            validating: false,
+            errors: Default::default(),
         }
     }
 
+    pub(crate) fn set_limits(&mut self, max_depth: usize, max_elements: usize) {
+        self.max_depth = max_depth;
+        self.max_elements = max_elements;
+    }
+
+    pub(crate) fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    pub(crate) fn set_keep_line_endings(&mut self, value: bool) {
+        self.keep_line_endings = value;
+    }
+
+    pub(crate) fn set_validating(&mut self, value: bool) {
+        self.validating = value;
+    }
+
+    /// Text content of the template, with the `\r\n` line endings replaced
+    /// with `\n` unless they should be
+    /// [kept](ParserOptions::keep_line_endings).
+    pub(crate) fn text_content(&self, value: &str) -> String {
+        if self.keep_line_endings {
+            value.to_string()
+        } else {
+            value.replace("\r\n", "\n")
+        }
+    }
+
+    /// Reports an element defined several times when it should only be
+    /// defined once, as a warning or as an error in
+    /// [`strict`](ParserOptions::strict) mode.
+    pub(crate) fn add_duplicate_element(&mut self, span: StrSpan<'_>) -> Result<(), Error> {
+        if self.strict {
+            return Err(Error::DuplicateElement {
+                origin: self.origin(),
+                position: span.into(),
+                location: self.location(span),
+            });
+        }
+        self.add_warning(WarningKind::DuplicateElement, span);
+        Ok(())
+    }
+
+    /// Reports an attribute not supported by the element, as a warning or as
+    /// an error in [`strict`](ParserOptions::strict) mode.
+    pub(crate) fn add_unknown_attribute(
+        &mut self,
+        element: &str,
+        attr: &Attribute<'_>,
+    ) -> Result<(), Error> {
+        if self.strict {
+            return Err(Error::UnknownAttribute {
+                element: element.to_string(),
+                name: attr.local.to_string(),
+                origin: self.origin(),
+                position: attr.span.into(),
+                location: self.location(attr.span),
+            });
+        }
+        self.add_warning(WarningKind::UnexpectedAttribute, attr.span);
+        Ok(())
+    }
+
+    /// Reports an attribute with an invalid size, as a warning or as an
+    /// [`Error::InvalidAttribute`] in [`strict`](ParserOptions::strict) mode.
+    pub(crate) fn add_invalid_size(&mut self, attr: &Attribute<'_>) -> Result<(), Error> {
+        if self.strict {
+            return Err(Error::InvalidAttribute {
+                origin: self.origin(),
+                position: attr.span.into(),
+                location: self.location(attr.span),
+            });
+        }
+        self.add_warning(WarningKind::InvalidSize, attr.span);
+        Ok(())
+    }
+
+    /// Reports an unexpected element, whose start has just been read. When
+    /// [validating](crate::validate), the error is kept and the element is
+    /// skipped, with its children, so the parsing can go on.
+    pub(crate) fn skip_unexpected_element(&mut self, error: Error) -> Result<(), Error> {
+        if !self.validating {
+            return Err(error);
+        }
+        let Some(name) = self.stack.last().copied() else {
+            return Err(error);
+        };
+        let mut open = vec![name];
+        while let Some(last) = open.last().copied() {
+            match self.next_token() {
+                Some(Ok(MrmlToken::ElementStart(inner))) => open.push(inner.local.as_str()),
+                Some(Ok(MrmlToken::ElementEnd(ElementEnd { empty: true, .. }))) => {
+                    open.pop();
+                }
+                Some(Ok(MrmlToken::ElementEnd(_))) if crate::prelude::is_void_element(last) => {
+                    open.pop();
+                }
+                Some(Ok(MrmlToken::ElementClose(inner))) if inner.local.as_str() == last => {
+                    open.pop();
+                }
+                Some(Ok(MrmlToken::ConditionalCommentStart(inner))) => {
+                    self.read_conditional_comment(inner)?;
+                }
+                Some(Ok(MrmlToken::ElementClose(_))) | Some(Err(_)) | None => return Err(error),
+                Some(Ok(_)) => {}
+            }
+        }
+        self.errors.push(error);
+        Ok(())
+    }
+
+    /// Same as [`skip_unexpected_element`](Self::skip_unexpected_element) for
+    /// the result of parsing an element starting with the given tag, when it
+    /// failed because the element itself is unexpected.
+    pub(crate) fn skip_unexpected_result<T>(
+        &mut self,
+        tag: StrSpan<'_>,
+        result: Result<T, Error>,
+    ) -> Result<Option<T>, Error> {
+        match result {
+            Err(error @ Error::UnexpectedElement { .. }) if error.span() == Some(tag.into()) => {
+                self.skip_unexpected_element(error).map(|_| None)
+            }
+            other => other.map(Some),
+        }
+    }
+
+    pub(crate) fn errors(&mut self) -> Vec<Error> {
+        std::mem::take(&mut self.errors)
+    }
+
     pub(crate) fn new_child<'b, O: Into<String>>(
         &self,
         origin: O,
         source: &'b str,
     ) -> MrmlCursor<'b> {
+        let path: String = origin.into();
+        let mut includes = self.includes.clone();
+        includes.push(path.clone());
         MrmlCursor {
+            source,
             tokenizer: Tokenizer::from(source),
             buffer: Default::default(),
-            origin: Origin::Include {
-                path: origin.into(),
-            },
+            origin: Origin::Include { path },
+            includes,
             warnings: Default::default(),
+            stack: Default::default(),
+            base_depth: self.base_depth + self.stack.len(),
+            elements: self.elements,
+            max_depth: self.max_depth,
+            max_elements: self.max_elements,
+            strict: self.strict,
+            keep_line_endings: self.keep_line_endings,
+            validating: self.validating,
+            errors: Default::default(),
         }
     }
 
+    /// Takes back the warnings, the errors and the number of elements of a
+    /// cursor created with [`new_child`](Self::new_child).
+    pub(crate) fn with_child(&mut self, child: MrmlCursor<'_>) {
+        self.elements = child.elements;
+        self.warnings.extend(child.warnings);
+        self.errors.extend(child.errors);
+    }
+
     pub(crate) fn origin(&self) -> Origin {
         self.origin.clone()
     }
+
+    /// Line and column of the beginning of the span in the template being
+    /// parsed.
+    pub(crate) fn location<S: Into<Span>>(&self, span: S) -> Location {
+        span.into().location(self.source)
+    }
+
+    pub(crate) fn assert_not_included<S: Into<Span>>(
+        &self,
+        path: &str,
+        span: S,
+    ) -> Result<(), Error> {
+        if self.includes.iter().any(|item| item == path) {
+            let position = span.into();
+            Err(Error::IncludeCycle {
+                path: path.to_string(),
+                origin: self.origin(),
+                position,
+                location: self.location(position),
+            })
+        } else {
+            Ok(())
+        }
+    }
 }
 
 pub struct MrmlParser<'opts> {
@@ -187,6 +619,9 @@ impl<'opts> MrmlParser<'opts> {
     where
         MrmlParser<'opts>: ParseElement<T>,
     {
+        cursor.set_limits(self.options.max_depth, self.options.max_elements);
+        cursor.set_strict(self.options.strict);
+        cursor.set_keep_line_endings(self.options.keep_line_endings);
         let start = cursor.assert_element_start()?;
         self.parse(cursor, start.local)
     }
@@ -219,9 +654,9 @@ impl ParseAttributes<Map<String, Option<String>>> for MrmlParser<'_> {
     fn parse_attributes(
         &self,
         cursor: &mut MrmlCursor<'_>,
-        _tag: &StrSpan<'_>,
+        tag: &StrSpan<'_>,
     ) -> Result<Map<String, Option<String>>, Error> {
-        parse_attributes_map(cursor)
+        parse_element_attributes_map(cursor, tag)
     }
 }
 
@@ -229,9 +664,9 @@ impl ParseAttributes<()> for MrmlParser<'_> {
     fn parse_attributes(
         &self,
         cursor: &mut MrmlCursor<'_>,
-        _tag: &StrSpan<'_>,
+        tag: &StrSpan<'_>,
     ) -> Result<(), Error> {
-        parse_attributes_empty(cursor)
+        parse_attributes_empty(cursor, tag)
     }
 }
 
@@ -239,7 +674,7 @@ impl ParseChildren<String> for MrmlParser<'_> {
     fn parse_children(&self, cursor: &mut MrmlCursor<'_>) -> Result<String, Error> {
         Ok(cursor
             .next_text()?
-            .map(|inner| inner.text.to_string())
+            .map(|inner| cursor.text_content(inner.text.as_str()))
             .unwrap_or_default())
     }
 }
@@ -263,6 +698,9 @@ impl AsyncMrmlParser {
     where
         AsyncMrmlParser: AsyncParseElement<T>,
     {
+        cursor.set_limits(self.options.max_depth, self.options.max_elements);
+        cursor.set_strict(self.options.strict);
+        cursor.set_keep_line_endings(self.options.keep_line_endings);
         let start = cursor.assert_element_start()?;
         self.async_parse(cursor, start.local).await
     }
@@ -296,9 +734,9 @@ impl ParseAttributes<Map<String, Option<String>>> for AsyncMrmlParser {
     fn parse_attributes(
         &self,
         cursor: &mut MrmlCursor<'_>,
-        _tag: &StrSpan<'_>,
+        tag: &StrSpan<'_>,
     ) -> Result<Map<String, Option<String>>, Error> {
-        parse_attributes_map(cursor)
+        parse_element_attributes_map(cursor, tag)
     }
 }
 
@@ -307,9 +745,9 @@ impl ParseAttributes<()> for AsyncMrmlParser {
     fn parse_attributes(
         &self,
         cursor: &mut MrmlCursor<'_>,
-        _tag: &StrSpan<'_>,
+        tag: &StrSpan<'_>,
     ) -> Result<(), Error> {
-        parse_attributes_empty(cursor)
+        parse_attributes_empty(cursor, tag)
     }
 }
 
@@ -320,11 +758,79 @@ impl AsyncParseChildren<String> for AsyncMrmlParser {
     async fn async_parse_children<'a>(&self, cursor: &mut MrmlCursor<'a>) -> Result<String, Error> {
         Ok(cursor
             .next_text()?
-            .map(|inner| inner.text.to_string())
+            .map(|inner| cursor.text_content(inner.text.as_str()))
             .unwrap_or_default())
     }
 }
 
+/// Attributes of the mj elements expecting a size, like `10px` or `50%`.
+const SIZE_ATTRIBUTES: [&str; 8] = [
+    "font-size",
+    "height",
+    "icon-height",
+    "icon-size",
+    "icon-width",
+    "letter-spacing",
+    "line-height",
+    "width",
+];
+
+/// Keywords accepted by the size attributes on top of the sizes.
+const SIZE_KEYWORDS: [&str; 2] = ["auto", "normal"];
+
+/// Units of the sizes supported by the renderer, a size without unit being a
+/// number too.
+const SIZE_UNITS: [&str; 4] = ["px", "%", "rem", "em"];
+
+/// Checks if the value is a size in pixels, like `480px`.
+pub(crate) fn is_valid_pixel(value: &str) -> bool {
+    value
+        .strip_suffix("px")
+        .is_some_and(|number| number.parse::<f32>().is_ok())
+}
+
+fn is_valid_size(value: &str) -> bool {
+    SIZE_KEYWORDS.contains(&value)
+        || SIZE_UNITS
+            .iter()
+            .find_map(|unit| value.strip_suffix(unit))
+            .unwrap_or(value)
+            .parse::<f32>()
+            .is_ok()
+}
+
+/// Parses the attributes like [`parse_attributes_map`] and, when
+/// [validating](crate::validate) the mj elements, adds a warning for each size
+/// attribute with an invalid value. When validating or in
+/// [`strict`](ParserOptions::strict) mode, the attributes not supported by the
+/// mj element are reported.
+fn parse_element_attributes_map(
+    cursor: &mut MrmlCursor<'_>,
+    tag: &StrSpan<'_>,
+) -> Result<Map<String, Option<String>>, Error> {
+    let check = (cursor.validating || cursor.strict) && tag.starts_with("mj-");
+    let check_sizes = cursor.validating && tag.starts_with("mj-");
+    let mut result = Map::new();
+    while let Some(attr) = cursor.next_attribute()? {
+        if check && !attributes::is_supported_attribute(tag.as_str(), attr.local.as_str()) {
+            cursor.add_unknown_attribute(tag.as_str(), &attr)?;
+        }
+        if check_sizes
+            && SIZE_ATTRIBUTES.contains(&attr.local.as_str())
+            && !attr
+                .value
+                .is_some_and(|value| is_valid_size(value.as_str()))
+        {
+            cursor.add_warning(WarningKind::InvalidSize, attr.span);
+        }
+        result.insert(
+            attr.local.to_string(),
+            attr.value.map(|inner| inner.to_string()),
+        );
+    }
+    Ok(result)
+}
+
 pub(crate) fn parse_attributes_map(
     cursor: &mut MrmlCursor<'_>,
 ) -> Result<Map<String, Option<String>>, Error> {
@@ -338,9 +844,12 @@ pub(crate) fn parse_attributes_map(
     Ok(result)
 }
 
-pub(crate) fn parse_attributes_empty(cursor: &mut MrmlCursor<'_>) -> Result<(), Error> {
+pub(crate) fn parse_attributes_empty(
+    cursor: &mut MrmlCursor<'_>,
+    tag: &StrSpan<'_>,
+) -> Result<(), Error> {
     if let Some(attr) = cursor.next_attribute()? {
-        cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span);
+        cursor.add_unknown_attribute(tag.as_str(), &attr)?;
     }
     Ok(())
 }
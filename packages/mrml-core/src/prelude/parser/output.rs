@@ -6,11 +6,34 @@ pub struct ParseOutput<E> {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WarningKind {
     UnexpectedAttribute,
+    /// A comment has been found where it can't be kept, like at the root of
+    /// the `mjml` element, and has been dropped.
+    IgnoredComment,
+    /// An element that should only be defined once, like `mj-title`, has been
+    /// defined several times and only the last one is used.
+    DuplicateElement,
+    /// The value of an attribute expecting a size, like `width`, is not a
+    /// valid size.
+    InvalidSize,
+    /// The content of the `mj-preview` is longer than the
+    /// [`max_preview_length`](super::ParserOptions::max_preview_length) and
+    /// will be truncated by the email clients.
+    PreviewTooLong,
+    /// An `mj-image` has no `alt` attribute, the screen readers reading its
+    /// url instead. An empty `alt` should be used for the decorative images.
+    MissingAlt,
 }
 
 impl WarningKind {
     pub const fn as_str(&self) -> &'static str {
-        "unexpected-attribute"
+        match self {
+            Self::UnexpectedAttribute => "unexpected-attribute",
+            Self::IgnoredComment => "ignored-comment",
+            Self::DuplicateElement => "duplicate-element",
+            Self::InvalidSize => "invalid-size",
+            Self::PreviewTooLong => "preview-too-long",
+            Self::MissingAlt => "missing-alt",
+        }
     }
 }
 
@@ -18,6 +41,11 @@ impl std::fmt::Display for WarningKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::UnexpectedAttribute => f.write_str("unexpected attribute"),
+            Self::IgnoredComment => f.write_str("ignored comment"),
+            Self::DuplicateElement => f.write_str("duplicate element"),
+            Self::InvalidSize => f.write_str("invalid size"),
+            Self::PreviewTooLong => f.write_str("preview too long"),
+            Self::MissingAlt => f.write_str("missing alt"),
         }
     }
 }
@@ -41,10 +69,6 @@ impl super::MrmlCursor<'_> {
     pub(crate) fn warnings(self) -> Vec<Warning> {
         self.warnings
     }
-
-    pub(crate) fn with_warnings(&mut self, others: Vec<Warning>) {
-        self.warnings.extend(others);
-    }
 }
 
 impl std::fmt::Display for Warning {
@@ -4,7 +4,7 @@ use htmlparser::{StrSpan, Token};
 
 use super::MrmlCursor;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
@@ -16,6 +16,43 @@ impl Display for Span {
     }
 }
 
+impl Span {
+    /// Computes the line and column of the beginning of the span in the
+    /// template it comes from.
+    pub fn location(&self, source: &str) -> Location {
+        Location::new(source, self.start)
+    }
+}
+
+/// Human readable position in a template, lines and columns start at 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(source: &str, offset: usize) -> Self {
+        let offset = offset.min(source.len());
+        let before = source.get(..offset).unwrap_or(source);
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+        Self {
+            offset,
+            line,
+            column,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
 impl<'a> From<&StrSpan<'a>> for Span {
     fn from(value: &StrSpan<'a>) -> Self {
         Self {
@@ -59,6 +96,7 @@ impl<'a> From<Token<'a>> for Span {
 pub(crate) enum MrmlToken<'a> {
     Attribute(Attribute<'a>),
     Comment(Comment<'a>),
+    ConditionalCommentStart(ConditionalCommentStart<'a>),
     ElementClose(ElementClose<'a>),
     ElementEnd(ElementEnd<'a>),
     ElementStart(ElementStart<'a>),
@@ -83,6 +121,9 @@ impl<'a> MrmlToken<'a> {
                 span,
             })),
             Token::Comment { text, span } => Ok(MrmlToken::Comment(Comment { span, text })),
+            Token::ConditionalCommentStart { span, .. } => Ok(MrmlToken::ConditionalCommentStart(
+                ConditionalCommentStart { span },
+            )),
             Token::ElementEnd {
                 end: htmlparser::ElementEnd::Close(prefix, local),
                 span,
@@ -112,6 +153,7 @@ impl<'a> MrmlToken<'a> {
             other => Err(super::Error::UnexpectedToken {
                 origin: cursor.origin(),
                 position: other.into(),
+                location: cursor.location(other),
             }),
         }
     }
@@ -122,6 +164,7 @@ impl MrmlToken<'_> {
         match self {
             Self::Attribute(item) => item.span,
             Self::Comment(item) => item.span,
+            Self::ConditionalCommentStart(item) => item.span,
             Self::ElementClose(item) => item.span,
             Self::ElementEnd(item) => item.span,
             Self::ElementStart(item) => item.span,
@@ -146,6 +189,11 @@ pub(crate) struct Comment<'a> {
     pub text: StrSpan<'a>,
 }
 
+#[derive(Debug)]
+pub(crate) struct ConditionalCommentStart<'a> {
+    pub span: StrSpan<'a>,
+}
+
 #[derive(Debug)]
 pub(crate) struct ElementClose<'a> {
     #[allow(unused)]
@@ -183,6 +231,7 @@ impl<'a> super::MrmlCursor<'a> {
                     source,
                 })
                 .and_then(|token| MrmlToken::parse(self, token))
+                .and_then(|token| self.track_limits(token))
             })
             .and_then(|token| match token {
                 Ok(MrmlToken::Text(inner))
@@ -194,6 +243,45 @@ impl<'a> super::MrmlCursor<'a> {
             })
     }
 
+    /// Keeps track of the open elements and the number of elements to fail
+    /// when the template goes over the depth or elements limits.
+    fn track_limits(&mut self, token: MrmlToken<'a>) -> Result<MrmlToken<'a>, super::Error> {
+        match token {
+            MrmlToken::ElementStart(ref inner) => {
+                self.elements += 1;
+                self.stack.push(inner.local.as_str());
+                let limit = if self.elements > self.max_elements {
+                    Some(super::Limit::Elements)
+                } else if self.base_depth + self.stack.len() > self.max_depth {
+                    Some(super::Limit::Depth)
+                } else {
+                    None
+                };
+                if let Some(limit) = limit {
+                    return Err(super::Error::LimitExceeded {
+                        limit,
+                        origin: self.origin(),
+                        position: inner.span.into(),
+                        location: self.location(inner.span),
+                    });
+                }
+            }
+            MrmlToken::ElementEnd(ElementEnd { empty: true, .. }) | MrmlToken::ElementClose(_) => {
+                self.stack.pop();
+            }
+            MrmlToken::ElementEnd(_)
+                if self
+                    .stack
+                    .last()
+                    .is_some_and(|name| crate::prelude::is_void_element(name)) =>
+            {
+                self.stack.pop();
+            }
+            _ => {}
+        }
+        Ok(token)
+    }
+
     pub(crate) fn next_token(&mut self) -> Option<Result<MrmlToken<'a>, super::Error>> {
         if let Some(item) = self.buffer.pop() {
             Some(Ok(item))
@@ -202,6 +290,37 @@ impl<'a> super::MrmlCursor<'a> {
         }
     }
 
+    /// Reads the raw content of a conditional comment, from its start, like
+    /// `<!--[if mso]>`, to its matching `<![endif]-->` closing, going through
+    /// the tokens of the content without parsing them as elements.
+    pub(crate) fn read_conditional_comment(
+        &mut self,
+        start: ConditionalCommentStart<'a>,
+    ) -> Result<&'a str, super::Error> {
+        let mut depth: usize = 0;
+        loop {
+            let token = self
+                .tokenizer
+                .next()
+                .ok_or_else(|| super::Error::EndOfStream {
+                    origin: self.origin(),
+                })?
+                .map_err(|source| super::Error::ParserError {
+                    origin: self.origin(),
+                    source,
+                })?;
+            match token {
+                Token::ConditionalCommentStart { .. } => depth += 1,
+                Token::ConditionalCommentEnd { span } if depth == 0 => {
+                    let source = self.tokenizer.stream().span().as_str();
+                    return Ok(&source[start.span.start()..span.end()]);
+                }
+                Token::ConditionalCommentEnd { .. } => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+
     pub(crate) fn rewind(&mut self, token: MrmlToken<'a>) {
         self.buffer.push(token);
     }
@@ -234,6 +353,7 @@ impl<'a> super::MrmlCursor<'a> {
             Some(Ok(other)) => Err(super::Error::UnexpectedToken {
                 origin: self.origin(),
                 position: other.span(),
+                location: self.location(other.span()),
             }),
             Some(Err(inner)) => Err(inner),
             None => Err(super::Error::EndOfStream {
@@ -248,6 +368,7 @@ impl<'a> super::MrmlCursor<'a> {
             Some(Ok(other)) => Err(super::Error::UnexpectedToken {
                 origin: self.origin(),
                 position: other.span(),
+                location: self.location(other.span()),
             }),
             Some(Err(inner)) => Err(inner),
             None => Err(super::Error::EndOfStream {
@@ -265,6 +386,7 @@ impl<'a> super::MrmlCursor<'a> {
             Some(Ok(other)) => Err(super::Error::UnexpectedToken {
                 origin: self.origin(),
                 position: other.span(),
+                location: self.location(other.span()),
             }),
             Some(Err(inner)) => Err(inner),
             None => Err(super::Error::EndOfStream {
@@ -287,3 +409,44 @@ impl<'a> super::MrmlCursor<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Location, Span};
+
+    #[test]
+    fn should_compute_location_on_first_line() {
+        let span = Span { start: 6, end: 10 };
+        assert_eq!(
+            span.location("<mjml><div /></mjml>"),
+            Location {
+                offset: 6,
+                line: 1,
+                column: 7
+            }
+        );
+    }
+
+    #[test]
+    fn should_compute_location_on_other_lines() {
+        let source = "<mjml>\n  <mj-body>\n    <mj-foo />\n  </mj-body>\n</mjml>";
+        let location = Location::new(source, 23);
+        assert_eq!(location.line, 3);
+        assert_eq!(location.column, 5);
+        assert_eq!(location.to_string(), "line 3, column 5");
+    }
+
+    #[test]
+    fn should_count_columns_in_chars() {
+        let location = Location::new("<p>é<br /></p>", 5);
+        assert_eq!(location.column, 5);
+    }
+
+    #[test]
+    fn should_handle_offset_out_of_source() {
+        let location = Location::new("<mjml>\n", 42);
+        assert_eq!(location.offset, 7);
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, 1);
+    }
+}
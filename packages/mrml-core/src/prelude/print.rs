@@ -88,6 +88,7 @@ use crate::mj_font::MjFont;
 use crate::mj_group::MjGroup;
 use crate::mj_head::MjHeadChild;
 use crate::mj_hero::MjHero;
+use crate::mj_html_attributes::MjHtmlAttributes;
 use crate::mj_image::MjImage;
 use crate::mj_include::body::MjIncludeBody;
 use crate::mj_include::head::MjIncludeHead;
@@ -293,7 +294,7 @@ impl Printer for PrettyPrinter {
     #[inline]
     fn push_indent(&mut self) {
         self.buffer
-            .extend(std::iter::repeat(' ').take(self.level * self.indent_size));
+            .extend(std::iter::repeat_n(' ', self.level * self.indent_size));
     }
 
     #[inline]
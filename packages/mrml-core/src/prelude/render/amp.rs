@@ -0,0 +1,269 @@
+//! Converts a rendered template into an AMP for Email document.
+//!
+//! The conditional comments for Outlook are removed, the content of the
+//! `<!--[if !mso]><!-->` ones being kept, and all the styles are merged into
+//! the `<style amp-custom>` element, without the `!important` flags and the
+//! `@import` rules that AMP rejects. The stylesheet links are removed, the
+//! images are converted into `<amp-img>` elements and the attributes rejected
+//! by AMP, like the event handlers, are stripped from the other elements.
+
+use super::inline::{at_rule_end, parse_attributes, Attribute};
+use super::minify::{tag_end, tag_name, CONDITIONAL_END, CONDITIONAL_START};
+
+const AMP_HEAD: &str = r#"<meta charset="utf-8"><script async src="https://cdn.ampproject.org/v0.js"></script><style amp4email-boilerplate>body{visibility:hidden}</style>"#;
+/// End of the opening comment of a conditional comment visible by the other
+/// clients, like `<!--[if !mso]><!-->`.
+const REVEALED_START_END: &str = "><!-->";
+const REVEALED_END: &str = "<!--<![endif]-->";
+/// Attributes rejected by AMP on any element, on top of the event handlers.
+const DISALLOWED_ATTRIBUTES: [&str; 2] = ["background", "usemap"];
+/// Attributes kept when converting an `<img>` into an `<amp-img>`, the size
+/// being handled separately.
+const AMP_IMG_ATTRIBUTES: [&str; 7] = ["alt", "class", "id", "sizes", "src", "srcset", "title"];
+
+fn get_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!(" {name}=\""))? + name.len() + 3;
+    let end = tag[start..].find('"')?;
+    Some(&tag[start..start + end])
+}
+
+fn remove_imports(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find("@import") {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        rest = &rest[at_rule_end(rest)..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Removes the rules and flags rejected by AMP from a stylesheet.
+fn push_amp_style(styles: &mut String, content: &str, media: Option<&str>) {
+    let content = remove_imports(content)
+        .replace(" !important", "")
+        .replace("!important", "");
+    let content = content.trim();
+    if content.is_empty() {
+        return;
+    }
+    if let Some(media) = media {
+        styles.push_str("@media ");
+        styles.push_str(media);
+        styles.push_str(" { ");
+        styles.push_str(content);
+        styles.push_str(" }");
+    } else {
+        styles.push_str(content);
+    }
+    styles.push('\n');
+}
+
+fn is_allowed_attribute(attribute: &Attribute<'_>) -> bool {
+    let name = attribute.name.to_ascii_lowercase();
+    if name.starts_with("on") || name.starts_with("xmlns") {
+        return false;
+    }
+    if name == "target" {
+        return matches!(attribute.value, Some("_blank" | "_top"));
+    }
+    !DISALLOWED_ATTRIBUTES.contains(&name.as_str())
+}
+
+fn push_attribute(output: &mut String, name: &str, value: Option<&str>) {
+    output.push(' ');
+    output.push_str(name);
+    if let Some(value) = value {
+        output.push_str("=\"");
+        output.push_str(value);
+        output.push('"');
+    }
+}
+
+fn parse_dimension(attributes: &[Attribute<'_>], name: &str) -> Option<u32> {
+    attributes
+        .iter()
+        .find(|attribute| attribute.name.eq_ignore_ascii_case(name))
+        .and_then(|attribute| attribute.value)
+        .and_then(|value| value.trim().trim_end_matches("px").parse().ok())
+}
+
+/// Converts an image into an `<amp-img>`, which requires its size to reserve
+/// the space of the image. Without a height in pixels, like with the default
+/// `height="auto"` of `mj-image`, the image is considered square and the
+/// `height` of the `mj-image` should be set to keep its ratio.
+fn push_amp_img(output: &mut String, tag: &str) {
+    let attributes = parse_attributes(tag);
+    output.push_str("<amp-img");
+    for attribute in attributes.iter().filter(|attribute| {
+        AMP_IMG_ATTRIBUTES.contains(&attribute.name.to_ascii_lowercase().as_str())
+    }) {
+        push_attribute(output, attribute.name, attribute.value);
+    }
+    match parse_dimension(&attributes, "width") {
+        Some(width) => {
+            let height = parse_dimension(&attributes, "height").unwrap_or(width);
+            push_attribute(output, "width", Some(&width.to_string()));
+            push_attribute(output, "height", Some(&height.to_string()));
+            push_attribute(output, "layout", Some("responsive"));
+        }
+        None => push_attribute(output, "layout", Some("fill")),
+    }
+    output.push_str("></amp-img>");
+}
+
+/// Copies the tag without the attributes rejected by AMP.
+fn push_amp_tag(output: &mut String, tag: &str) {
+    let mut last = 0;
+    for attribute in parse_attributes(tag)
+        .iter()
+        .filter(|attribute| !is_allowed_attribute(attribute))
+    {
+        let start = tag[..attribute.start].trim_end().len();
+        output.push_str(&tag[last..start]);
+        last = attribute.end;
+    }
+    output.push_str(&tag[last..]);
+}
+
+pub(crate) fn amp(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut styles = String::new();
+    let mut rest = input;
+    while !rest.is_empty() {
+        if rest.starts_with(CONDITIONAL_START) {
+            let opening_end = rest.find("]>").map(|index| index + 2).unwrap_or(rest.len());
+            if rest[opening_end - 1..].starts_with(REVEALED_START_END) {
+                // the content is visible by the other clients
+                rest = &rest[opening_end - 1 + REVEALED_START_END.len()..];
+            } else {
+                let end = rest
+                    .find(CONDITIONAL_END)
+                    .map(|index| index + CONDITIONAL_END.len())
+                    .unwrap_or(rest.len());
+                rest = &rest[end..];
+            }
+        } else if let Some(inner) = rest.strip_prefix(REVEALED_END) {
+            rest = inner;
+        } else if rest.starts_with("<!--") {
+            let end = rest
+                .find("-->")
+                .map(|index| index + 3)
+                .unwrap_or(rest.len());
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+        } else if rest.starts_with('<') {
+            let end = tag_end(rest);
+            let tag = &rest[..end];
+            rest = &rest[end..];
+            if tag.starts_with("</") {
+                if tag_name(tag) == "head" {
+                    output.push_str("<style amp-custom>\n");
+                    output.push_str(&styles);
+                    output.push_str("</style>");
+                }
+                output.push_str(tag);
+                continue;
+            }
+            match tag_name(tag).as_str() {
+                "!doctype" => output.push_str("<!doctype html>"),
+                "html" => output.push_str("<html ⚡4email data-css-strict>"),
+                "head" => {
+                    output.push_str(tag);
+                    output.push_str(AMP_HEAD);
+                }
+                "meta" if tag.contains("http-equiv=") => {}
+                "link" if get_attribute(tag, "rel") == Some("stylesheet") => {}
+                "img" => push_amp_img(&mut output, tag),
+                "style" => {
+                    let end = rest.find("</style>").unwrap_or(rest.len());
+                    push_amp_style(&mut styles, &rest[..end], get_attribute(tag, "media"));
+                    rest = &rest[end..];
+                    rest = rest.strip_prefix("</style>").unwrap_or(rest);
+                }
+                _ => push_amp_tag(&mut output, tag),
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::amp;
+
+    #[test]
+    fn should_convert_document() {
+        let input = concat!(
+            r#"<!doctype html><html xmlns="http://www.w3.org/1999/xhtml"><head><title></title>"#,
+            r#"<!--[if !mso]><!--><meta http-equiv="X-UA-Compatible" content="IE=edge"><!--<![endif]-->"#,
+            r#"<style type="text/css">.a { color: red !important; }</style>"#,
+            r#"<!--[if mso]><style>.b { color: blue; }</style><![endif]-->"#,
+            r#"<style media="screen and (min-width:480px)">.c { width:50%!important; }</style>"#,
+            r#"<style type="text/css">@import url(https://example.com/font.css);</style>"#,
+            r#"</head><body><!-- comment --><div>Hello</div></body></html>"#,
+        );
+        assert_eq!(
+            amp(input),
+            concat!(
+                r#"<!doctype html><html ⚡4email data-css-strict><head><meta charset="utf-8">"#,
+                r#"<script async src="https://cdn.ampproject.org/v0.js"></script>"#,
+                r#"<style amp4email-boilerplate>body{visibility:hidden}</style><title></title>"#,
+                "<style amp-custom>\n.a { color: red; }\n@media screen and (min-width:480px) { .c { width:50%; } }\n</style>",
+                "</head><body><!-- comment --><div>Hello</div></body></html>",
+            )
+        );
+    }
+
+    #[test]
+    fn should_remove_imports_and_links() {
+        let input = concat!(
+            r#"<html><head><link href="https://example.com/font.css" rel="stylesheet" type="text/css">"#,
+            r#"<style type="text/css">.a { color: red; } @import url(https://example.com/font.css); .b { color: blue; }</style>"#,
+            r#"</head><body></body></html>"#,
+        );
+        let output = amp(input);
+        assert!(!output.contains("<link"));
+        assert!(!output.contains("@import"));
+        assert!(output.contains(".a { color: red; }  .b { color: blue; }"));
+    }
+
+    #[test]
+    fn should_convert_images() {
+        assert_eq!(
+            amp(
+                r#"<img alt="Logo" height="auto" src="logo.png" style="width:100%;" usemap="map" width="550" />"#
+            ),
+            r#"<amp-img alt="Logo" src="logo.png" width="550" height="550" layout="responsive"></amp-img>"#
+        );
+        assert_eq!(
+            amp(r#"<img src="logo.png" width="120px" height="60">"#),
+            r#"<amp-img src="logo.png" width="120" height="60" layout="responsive"></amp-img>"#
+        );
+        assert_eq!(
+            amp(r#"<img src="logo.png">"#),
+            r#"<amp-img src="logo.png" layout="fill"></amp-img>"#
+        );
+    }
+
+    #[test]
+    fn should_strip_disallowed_attributes() {
+        assert_eq!(
+            amp(concat!(
+                r#"<td background="bg.png" style="color:red;" onclick="alert(1)">"#,
+                r#"<a href="https://example.com" target="_self" onmouseover="go()">Link</a>"#,
+                r#"<a href="https://example.com" target="_blank">Other</a></td>"#,
+            )),
+            concat!(
+                r#"<td style="color:red;">"#,
+                r#"<a href="https://example.com">Link</a>"#,
+                r#"<a href="https://example.com" target="_blank">Other</a></td>"#,
+            )
+        );
+    }
+}
@@ -24,11 +24,49 @@ impl std::fmt::Write for RenderBuffer {
     }
 }
 
+/// Checks if the input starts with a character reference, like `&amp;` or
+/// `&#39;`.
+fn starts_with_entity(input: &str) -> bool {
+    let Some(rest) = input.strip_prefix('&') else {
+        return false;
+    };
+    let name = match rest.find(';') {
+        Some(end) if end > 0 && end <= 32 => &rest[..end],
+        _ => return false,
+    };
+    match name.strip_prefix('#') {
+        Some(code) => match code.strip_prefix(['x', 'X']) {
+            Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+            None => !code.is_empty() && code.chars().all(|c| c.is_ascii_digit()),
+        },
+        None => name.chars().all(|c| c.is_ascii_alphanumeric()),
+    }
+}
+
+/// Value of an attribute escaped to be written between double quotes. The
+/// content of the templates being already html, the `&` starting a character
+/// reference are kept as they are.
+pub(crate) struct AttributeValue<'a>(pub &'a str);
+
+impl std::fmt::Display for AttributeValue<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, c) in self.0.char_indices() {
+            match c {
+                '"' => f.write_str("&quot;")?,
+                '<' => f.write_str("&lt;")?,
+                '&' if !starts_with_entity(&self.0[index..]) => f.write_str("&amp;")?,
+                _ => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 pub(crate) struct RenderAttribute<N, V>(N, V);
 
 impl<'a> std::fmt::Display for RenderAttribute<&'a str, &'a str> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}={:?}", self.0, self.1)
+        write!(f, "{}=\"{}\"", self.0, AttributeValue(self.1))
     }
 }
 
@@ -47,13 +85,24 @@ impl<'a> std::fmt::Display for RenderAttribute<&'a str, &'a Styles<'a>> {
 impl<'a> std::fmt::Display for RenderAttribute<&'a str, Option<&'a str>> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.1 {
-            Some(ref value) => write!(f, "{}={value:?}", self.0),
+            Some(value) => write!(f, "{}=\"{}\"", self.0, AttributeValue(value)),
             None => write!(f, "{}", self.0),
         }
     }
 }
 
 impl RenderBuffer {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: String::with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
     #[inline]
     pub fn push_str(&mut self, value: &str) {
         self.inner.push_str(value);
@@ -104,9 +153,27 @@ const START_MSO_NEGATION_CONDITIONAL_TAG: &str = "<!--[if !mso]><!-->";
 const END_NEGATION_CONDITIONAL_TAG: &str = "<!--<![endif]-->";
 
 impl RenderBuffer {
+    /// Opens a conditional comment, or reopens the previous one when it has
+    /// the same condition and was just closed, to merge the adjacent
+    /// `<![endif]--><!--[if mso | IE]>` sequences like mjml does.
+    fn start_merged_conditional_tag(&mut self, start: &str) {
+        if let Some(content) = self.inner.strip_suffix(END_CONDITIONAL_TAG) {
+            let same_condition = !content.ends_with("<!--")
+                && content
+                    .rfind("<!--[if ")
+                    .is_some_and(|index| content[index..].starts_with(start));
+            if same_condition {
+                let len = content.len();
+                self.inner.truncate(len);
+                return;
+            }
+        }
+        self.inner.push_str(start);
+    }
+
     #[inline]
     pub fn start_conditional_tag(&mut self) {
-        self.inner.push_str(START_CONDITIONAL_TAG);
+        self.start_merged_conditional_tag(START_CONDITIONAL_TAG);
     }
 
     #[inline]
@@ -116,7 +183,7 @@ impl RenderBuffer {
 
     #[inline]
     pub fn start_mso_conditional_tag(&mut self) {
-        self.inner.push_str(START_MSO_CONDITIONAL_TAG);
+        self.start_merged_conditional_tag(START_MSO_CONDITIONAL_TAG);
     }
 
     #[inline]
@@ -146,3 +213,55 @@ impl From<RenderBuffer> for String {
         value.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AttributeValue, RenderBuffer};
+
+    #[test]
+    fn should_merge_adjacent_conditional_comments() {
+        let mut buffer = RenderBuffer::default();
+        buffer.start_conditional_tag();
+        buffer.push_str("<table><tr><td>");
+        buffer.end_conditional_tag();
+        buffer.start_conditional_tag();
+        buffer.push_str("<table><tr><td>");
+        buffer.end_conditional_tag();
+        buffer.push_str("<div></div>");
+        buffer.start_conditional_tag();
+        buffer.push_str("</td></tr></table>");
+        buffer.end_conditional_tag();
+        buffer.start_mso_conditional_tag();
+        buffer.push_str("</td></tr></table>");
+        buffer.end_conditional_tag();
+        buffer.start_negation_conditional_tag();
+        buffer.push_str("<p></p>");
+        buffer.end_negation_conditional_tag();
+        buffer.start_conditional_tag();
+        buffer.end_conditional_tag();
+        assert_eq!(
+            String::from(buffer),
+            "<!--[if mso | IE]><table><tr><td><table><tr><td><![endif]--><div></div><!--[if mso | IE]></td></tr></table><![endif]--><!--[if mso]></td></tr></table><![endif]--><!--[if !mso | IE]><!--><p></p><!--<![endif]--><!--[if mso | IE]><![endif]-->"
+        );
+    }
+
+    #[test]
+    fn should_escape_attribute_value() {
+        assert_eq!(
+            AttributeValue(r#"He said "hi" <b> & left"#).to_string(),
+            "He said &quot;hi&quot; &lt;b> &amp; left"
+        );
+    }
+
+    #[test]
+    fn should_keep_character_references() {
+        assert_eq!(
+            AttributeValue("a=1&amp;b=2&#39;&#x27;&copy;").to_string(),
+            "a=1&amp;b=2&#39;&#x27;&copy;"
+        );
+        assert_eq!(
+            AttributeValue("a=1&b=2 &c;d=3&#;").to_string(),
+            "a=1&amp;b=2 &c;d=3&amp;#;"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Extracts the content of the body from a rendered template, to embed it in
+//! an existing document.
+//!
+//! The `<style>` elements of the head are kept before the content, the media
+//! queries of the columns being required for the responsive layout. The ones
+//! in conditional comments, only used by Outlook or to import the fonts, are
+//! dropped with the rest of the head.
+
+use super::minify::{tag_end, tag_name, CONDITIONAL_END, CONDITIONAL_START};
+
+const STYLE_END: &str = "</style>";
+
+fn push_styles(output: &mut String, head: &str) {
+    let mut rest = head;
+    while !rest.is_empty() {
+        if rest.starts_with(CONDITIONAL_START) {
+            let end = rest
+                .find(CONDITIONAL_END)
+                .map(|index| index + CONDITIONAL_END.len())
+                .unwrap_or(rest.len());
+            rest = &rest[end..];
+        } else if rest.starts_with('<') && tag_name(rest) == "style" {
+            let end = rest
+                .find(STYLE_END)
+                .map(|index| index + STYLE_END.len())
+                .unwrap_or(rest.len());
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+        } else {
+            let end = rest[1..]
+                .find('<')
+                .map(|index| index + 1)
+                .unwrap_or(rest.len());
+            rest = &rest[end..];
+        }
+    }
+}
+
+pub(crate) fn fragment(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let (head, body) = match input.find("</head>") {
+        Some(index) => input.split_at(index),
+        None => ("", input),
+    };
+    push_styles(&mut output, head);
+    let content = match body.find("<body") {
+        Some(start) => {
+            let start = start + tag_end(&body[start..]);
+            let end = body.rfind("</body>").unwrap_or(body.len()).max(start);
+            &body[start..end]
+        }
+        None => body,
+    };
+    output.push_str(content);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fragment;
+
+    #[test]
+    fn should_keep_body_content() {
+        assert_eq!(
+            fragment("<!doctype html><html><head><title></title></head><body style=\"a\"><div>Hello</div></body></html>"),
+            "<div>Hello</div>"
+        );
+    }
+
+    #[test]
+    fn should_keep_head_styles() {
+        assert_eq!(
+            fragment("<html><head><style type=\"text/css\">a { color: red; }</style><!--[if mso]><style>b {}</style><![endif]--><style media=\"screen\">c {}</style></head><body><p>Hi</p></body></html>"),
+            "<style type=\"text/css\">a { color: red; }</style><style media=\"screen\">c {}</style><p>Hi</p>"
+        );
+    }
+}
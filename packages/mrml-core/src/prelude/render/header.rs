@@ -35,7 +35,7 @@ impl VariableHeader {
         for name in value
             .as_ref()
             .split(',')
-            .map(|item| item.trim())
+            .map(|item| item.trim().trim_matches(['"', '\'']).trim())
             .filter(|item| !item.is_empty())
         {
             self.add_used_font_family(name);
@@ -77,12 +77,20 @@ pub(crate) struct Header<'h> {
     attributes_element: Map<&'h str, Map<&'h str, &'h str>>,
     breakpoint: Pixel,
     font_families: Map<&'h str, &'h str>,
+    html_attributes: Vec<(&'h str, &'h str, &'h str)>,
     preview: Option<&'h str>,
+    inline_styles: Vec<&'h str>,
     lang: Option<&'h str>,
+    dir: Option<&'h str>,
 }
 
 impl<'h> Header<'h> {
-    pub(crate) fn new(head: Option<&'h MjHead>, lang: Option<&'h str>) -> Self {
+    pub(crate) fn new(
+        head: Option<&'h MjHead>,
+        lang: Option<&'h str>,
+        dir: Option<&'h str>,
+        breakpoint: Option<Pixel>,
+    ) -> Self {
         Self {
             attributes_all: head
                 .as_ref()
@@ -100,13 +108,22 @@ impl<'h> Header<'h> {
                 .as_ref()
                 .and_then(|h| h.breakpoint())
                 .and_then(|s| Pixel::try_from(s.value()).ok())
+                .or(breakpoint)
                 .unwrap_or_else(|| Pixel::new(480.0)),
             font_families: head
                 .as_ref()
                 .map(|h| h.build_font_families())
                 .unwrap_or_default(),
+            html_attributes: head
+                .as_ref()
+                .map(|h| h.build_html_attributes())
+                .unwrap_or_default(),
             preview: head.and_then(|h| h.preview().map(|t| t.content())),
+            inline_styles: head
+                .map(|h| h.mj_inline_style_iter().collect())
+                .unwrap_or_default(),
             lang,
+            dir,
         }
     }
 
@@ -128,6 +145,30 @@ impl<'h> Header<'h> {
             .copied()
     }
 
+    /// Names of the attributes defined in the `mj-attributes` element for an
+    /// element with the given tag and `mj-class`, through `mj-all`, the tag
+    /// itself or one of the classes.
+    pub fn attribute_names<'a>(
+        &'a self,
+        tag: Option<&str>,
+        mj_classes: Option<&'a str>,
+    ) -> impl Iterator<Item = &'h str> + 'a {
+        let element = tag
+            .and_then(|tag| self.attributes_element.get(tag))
+            .into_iter()
+            .flat_map(|attrs| attrs.keys());
+        let classes = mj_classes
+            .into_iter()
+            .flat_map(|value| value.split_whitespace())
+            .filter_map(|name| self.attributes_class.get(name))
+            .flat_map(|attrs| attrs.keys());
+        self.attributes_all
+            .keys()
+            .chain(element)
+            .chain(classes)
+            .copied()
+    }
+
     pub fn breakpoint(&self) -> &Pixel {
         &self.breakpoint
     }
@@ -136,11 +177,55 @@ impl<'h> Header<'h> {
         &self.font_families
     }
 
+    /// The selector path, attribute name and attribute value defined by the
+    /// `mj-html-attributes` element, in the order of the template.
+    pub fn html_attributes(&self) -> &[(&'h str, &'h str, &'h str)] {
+        &self.html_attributes
+    }
+
     pub fn lang(&self) -> Option<&str> {
         self.lang
     }
 
+    pub fn dir(&self) -> Option<&str> {
+        self.dir
+    }
+
+    /// Default text direction of the elements, depending on the `dir`
+    /// attribute of the `mjml` element.
+    pub fn direction(&self) -> &'static str {
+        match self.dir {
+            Some(value) if value.eq_ignore_ascii_case("rtl") => "rtl",
+            _ => "ltr",
+        }
+    }
+
     pub fn preview(&self) -> Option<&str> {
         self.preview
     }
+
+    /// Content of the `mj-style inline="inline"` elements, that are applied to
+    /// the elements instead of being added to the head.
+    pub fn inline_styles(&self) -> &[&'h str] {
+        &self.inline_styles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VariableHeader;
+
+    #[test]
+    fn should_add_each_font_family_of_the_stack() {
+        let mut header = VariableHeader::default();
+        header.add_font_families(r#""Helvetica Neue", 'Open Sans' , Arial,, sans-serif"#);
+        assert_eq!(
+            header
+                .used_font_families()
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+            vec!["Helvetica Neue", "Open Sans", "Arial", "sans-serif"]
+        );
+    }
 }
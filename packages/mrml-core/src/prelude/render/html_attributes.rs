@@ -0,0 +1,215 @@
+//! Adds the attributes defined with the `mj-html-attributes` element to the
+//! matching elements of a rendered template.
+//!
+//! The selectors are the simple selectors supported by the inliner, like
+//! `td.button`, optionally combined with the descendant combinator, like
+//! `.cta a`. The paths using any other combinator or pseudo class are ignored.
+
+use super::inline::{parse_attributes, Element, Selector, PRESERVED_TAGS};
+use super::minify::{tag_end, tag_name};
+
+const VOID_TAGS: [&str; 13] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// A selector path, like `.cta a`, split on the descendant combinator.
+struct Path(Vec<Selector>);
+
+impl Path {
+    fn parse(input: &str) -> Option<Self> {
+        let parts = input
+            .split_whitespace()
+            .map(Selector::parse)
+            .collect::<Option<Vec<_>>>()?;
+        if parts.is_empty() {
+            None
+        } else {
+            Some(Self(parts))
+        }
+    }
+
+    /// Checks that the last selector matches the element and that the other
+    /// ones match some of its ancestors, in the same order.
+    fn matches(&self, element: &Element<'_>, ancestors: &[Element<'_>]) -> bool {
+        let Some((last, others)) = self.0.split_last() else {
+            return false;
+        };
+        if !last.matches(element) {
+            return false;
+        }
+        let mut ancestors = ancestors.iter().rev();
+        others
+            .iter()
+            .rev()
+            .all(|selector| ancestors.any(|ancestor| selector.matches(ancestor)))
+    }
+}
+
+/// Sets the attribute on the opening tag, replacing the existing value if any.
+fn set_attribute(tag: &str, name: &str, value: &str) -> String {
+    let value = value.replace('"', "&quot;");
+    let attributes = parse_attributes(tag);
+    match attributes
+        .iter()
+        .find(|attr| attr.name.eq_ignore_ascii_case(name))
+    {
+        Some(attr) => format!(
+            "{}{name}=\"{value}\"{}",
+            &tag[..attr.start],
+            &tag[attr.end..]
+        ),
+        None => {
+            let end = tag
+                .trim_end_matches('>')
+                .trim_end_matches('/')
+                .trim_end()
+                .len();
+            format!("{} {name}=\"{value}\"{}", &tag[..end], &tag[end..])
+        }
+    }
+}
+
+/// Adds the attributes, given as selector path, attribute name and attribute
+/// value, to the matching elements of the document. When several selectors
+/// set the same attribute on an element, the last one wins. The content of the
+/// `head` and of the comments is left untouched.
+pub(crate) fn add_html_attributes(input: &str, attributes: &[(&str, &str, &str)]) -> String {
+    let rules = attributes
+        .iter()
+        .filter_map(|(path, name, value)| Path::parse(path).map(|path| (path, *name, *value)))
+        .collect::<Vec<_>>();
+    if rules.is_empty() {
+        return input.to_string();
+    }
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    let mut in_head = false;
+    // the open elements, parsed once when they are opened
+    let mut stack: Vec<Element<'_>> = Vec::new();
+    while let Some(start) = rest.find('<') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if rest.starts_with("<!--") {
+            let end = rest
+                .find("-->")
+                .map(|index| index + 3)
+                .unwrap_or(rest.len());
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+        let end = tag_end(rest);
+        let tag = &rest[..end];
+        rest = &rest[end..];
+        let name = tag_name(tag);
+        if tag.starts_with("</") {
+            if name == "head" {
+                in_head = false;
+            }
+            if let Some(index) = stack
+                .iter()
+                .rposition(|open| open.name.eq_ignore_ascii_case(&name))
+            {
+                stack.truncate(index);
+            }
+            output.push_str(tag);
+            continue;
+        }
+        if tag.starts_with("<!") {
+            output.push_str(tag);
+            continue;
+        }
+        if name == "head" {
+            in_head = true;
+        }
+        let attributes = parse_attributes(tag);
+        let element = Element::new(&tag[1..=name.len()], &attributes);
+        if in_head {
+            output.push_str(tag);
+        } else {
+            let mut result = tag.to_string();
+            for (path, attr_name, attr_value) in rules.iter() {
+                if path.matches(&element, &stack) {
+                    result = set_attribute(&result, attr_name, attr_value);
+                }
+            }
+            output.push_str(&result);
+        }
+        if !tag.ends_with("/>") && !VOID_TAGS.contains(&name.as_str()) {
+            stack.push(element);
+        }
+        if PRESERVED_TAGS.contains(&name.as_str()) {
+            let closing = format!("</{name}");
+            let end = rest
+                .to_ascii_lowercase()
+                .find(&closing)
+                .unwrap_or(rest.len());
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::add_html_attributes;
+
+    #[test]
+    fn should_add_attribute_to_matching_elements() {
+        assert_eq!(
+            add_html_attributes(
+                r#"<div class="cta"><a href="/">a</a></div><p class="cta other">b</p><p>c</p>"#,
+                &[(".cta", "data-id", "42")]
+            ),
+            r#"<div class="cta" data-id="42"><a href="/">a</a></div><p class="cta other" data-id="42">b</p><p>c</p>"#
+        );
+    }
+
+    #[test]
+    fn should_replace_existing_attribute() {
+        assert_eq!(
+            add_html_attributes(
+                r#"<a href="/" target="_self">a</a><img src="a.png" />"#,
+                &[("a", "target", "_blank"), ("img", "alt", "say \"hi\"")]
+            ),
+            r#"<a href="/" target="_blank">a</a><img src="a.png" alt="say &quot;hi&quot;" />"#
+        );
+    }
+
+    #[test]
+    fn should_match_descendants() {
+        assert_eq!(
+            add_html_attributes(
+                r#"<div class="cta"><table><tr><td><a>a</a><br><img src="a.png"></td></tr></table></div><a>b</a>"#,
+                &[(".cta a", "data-id", "42"), (".cta img", "alt", "")]
+            ),
+            r#"<div class="cta"><table><tr><td><a data-id="42">a</a><br><img src="a.png" alt=""></td></tr></table></div><a>b</a>"#
+        );
+    }
+
+    #[test]
+    fn should_match_tag_names_ignoring_case() {
+        assert_eq!(
+            add_html_attributes(
+                r#"<DIV class="cta"><P><A>a</A></P></DIV><a>b</a>"#,
+                &[("div a", "data-id", "42")]
+            ),
+            r#"<DIV class="cta"><P><A data-id="42">a</A></P></DIV><a>b</a>"#
+        );
+    }
+
+    #[test]
+    fn should_ignore_head_comments_and_unsupported_selectors() {
+        let input = r#"<html><head><title class="cta"></title></head><body><!-- <p class="cta"> --><div class="cta"></div></body></html>"#;
+        assert_eq!(
+            add_html_attributes(
+                input,
+                &[(".cta", "data-id", "42"), ("body > div", "id", "a")]
+            ),
+            r#"<html><head><title class="cta"></title></head><body><!-- <p class="cta"> --><div class="cta" data-id="42"></div></body></html>"#
+        );
+    }
+}
@@ -0,0 +1,504 @@
+//! Applies the rules of a stylesheet to the `style` attribute of the matching
+//! elements of a rendered template.
+//!
+//! Only the simple selectors are supported: a tag name, some classes and some
+//! ids, optionally combined like `td.button#main`. The rules using any other
+//! selector and the at-rules, like the media queries, can't be inlined and are
+//! kept aside so that they can stay in a `<style>` element.
+
+use super::minify::{tag_end, tag_name};
+use crate::prelude::hash::Map;
+
+pub(super) const PRESERVED_TAGS: [&str; 2] = ["script", "style"];
+
+fn is_identifier(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_' || !c.is_ascii())
+}
+
+fn is_important(value: &str) -> bool {
+    value.trim_end().ends_with("!important")
+}
+
+/// Splits the declarations of a rule or of a `style` attribute, ignoring the
+/// `;` contained in quotes or parenthesis, like in `url(data:...;base64,...)`.
+fn parse_declarations(input: &str) -> Vec<(String, String)> {
+    let mut result = Vec::new();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    for (index, c) in input
+        .char_indices()
+        .chain(std::iter::once((input.len(), ';')))
+    {
+        match (quote, c) {
+            (Some(q), c) if q == c => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ';') if depth <= 0 => {
+                if let Some((name, value)) = input[start..index].split_once(':') {
+                    let (name, value) = (name.trim(), value.trim());
+                    if !name.is_empty() && !value.is_empty() {
+                        result.push((name.to_ascii_lowercase(), value.to_string()));
+                    }
+                }
+                start = (index + 1).min(input.len());
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+fn merge_declaration(target: &mut Map<String, String>, name: String, value: String) {
+    let keep_existing = target
+        .get(&name)
+        .map(|existing| is_important(existing) && !is_important(&value))
+        .unwrap_or(false);
+    if !keep_existing {
+        target.insert(name, value);
+    }
+}
+
+fn strip_comments(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("/*") {
+        result.push_str(&rest[..start]);
+        rest = rest[start + 2..]
+            .find("*/")
+            .map(|end| &rest[start + 2 + end + 2..])
+            .unwrap_or_default();
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Finds the end of the at-rule starting at the beginning of the input, being
+/// a statement like `@import ...;` or a block like `@media ... { ... }`.
+pub(super) fn at_rule_end(input: &str) -> usize {
+    let mut depth = 0;
+    for (index, c) in input.char_indices() {
+        match c {
+            ';' if depth == 0 => return index + 1,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth <= 0 {
+                    return index + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    input.len()
+}
+
+pub(super) struct Element<'a> {
+    pub name: &'a str,
+    id: Option<&'a str>,
+    classes: Vec<&'a str>,
+}
+
+impl<'a> Element<'a> {
+    pub(super) fn new(name: &'a str, attributes: &[Attribute<'a>]) -> Self {
+        let find = |key: &str| {
+            attributes
+                .iter()
+                .find(|attr| attr.name.eq_ignore_ascii_case(key))
+                .and_then(|attr| attr.value)
+        };
+        Self {
+            name,
+            id: find("id"),
+            classes: find("class")
+                .map(|value| value.split_whitespace().collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub(super) struct Selector {
+    tag: Option<String>,
+    ids: Vec<String>,
+    classes: Vec<String>,
+}
+
+impl Selector {
+    pub(super) fn parse(input: &str) -> Option<Self> {
+        if input.is_empty() {
+            return None;
+        }
+        let mut result = Self::default();
+        let name_end = input.find(['.', '#']).unwrap_or(input.len());
+        let name = &input[..name_end];
+        if !name.is_empty() && name != "*" {
+            if !is_identifier(name) {
+                return None;
+            }
+            result.tag = Some(name.to_ascii_lowercase());
+        }
+        let mut rest = &input[name_end..];
+        while let Some(kind) = rest.chars().next() {
+            let value = &rest[1..];
+            let end = value.find(['.', '#']).unwrap_or(value.len());
+            if !is_identifier(&value[..end]) {
+                return None;
+            }
+            if kind == '.' {
+                result.classes.push(value[..end].to_string());
+            } else {
+                result.ids.push(value[..end].to_string());
+            }
+            rest = &value[end..];
+        }
+        Some(result)
+    }
+
+    fn specificity(&self) -> (usize, usize, usize) {
+        (
+            self.ids.len(),
+            self.classes.len(),
+            usize::from(self.tag.is_some()),
+        )
+    }
+
+    pub(super) fn matches(&self, element: &Element<'_>) -> bool {
+        self.tag
+            .iter()
+            .all(|tag| tag.eq_ignore_ascii_case(element.name))
+            && self.ids.iter().all(|id| element.id == Some(id.as_str()))
+            && self
+                .classes
+                .iter()
+                .all(|class| element.classes.contains(&class.as_str()))
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    selector: Selector,
+    declarations: Vec<(String, String)>,
+}
+
+#[derive(Debug)]
+pub(super) struct Attribute<'a> {
+    pub name: &'a str,
+    pub value: Option<&'a str>,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Lists the attributes of the opening tag, with their position in the tag.
+pub(super) fn parse_attributes(tag: &str) -> Vec<Attribute<'_>> {
+    let bytes = tag.as_bytes();
+    let mut result = Vec::new();
+    // skip the tag name
+    let mut index = 1;
+    while index < bytes.len() && !matches!(bytes[index], b'>' | b'/' | b' ' | b'\t' | b'\n' | b'\r')
+    {
+        index += 1;
+    }
+    loop {
+        while index < bytes.len() && (bytes[index].is_ascii_whitespace() || bytes[index] == b'/') {
+            index += 1;
+        }
+        if index >= bytes.len() || bytes[index] == b'>' {
+            break;
+        }
+        let start = index;
+        while index < bytes.len()
+            && !bytes[index].is_ascii_whitespace()
+            && !matches!(bytes[index], b'=' | b'>' | b'/')
+        {
+            index += 1;
+        }
+        let name = &tag[start..index];
+        let mut value = None;
+        if bytes.get(index) == Some(&b'=') {
+            index += 1;
+            match bytes.get(index) {
+                Some(&quote @ (b'"' | b'\'')) => {
+                    let value_start = index + 1;
+                    let value_end = tag[value_start..]
+                        .find(quote as char)
+                        .map(|end| value_start + end)
+                        .unwrap_or(tag.len());
+                    value = Some(&tag[value_start..value_end]);
+                    index = (value_end + 1).min(tag.len());
+                }
+                _ => {
+                    let value_start = index;
+                    while index < bytes.len()
+                        && !bytes[index].is_ascii_whitespace()
+                        && bytes[index] != b'>'
+                    {
+                        index += 1;
+                    }
+                    value = Some(&tag[value_start..index]);
+                }
+            }
+        }
+        result.push(Attribute {
+            name,
+            value,
+            start,
+            end: index,
+        });
+    }
+    result
+}
+
+/// The rules of a stylesheet, split between the ones that can be inlined and
+/// the remaining ones.
+#[derive(Debug, Default)]
+pub(crate) struct Stylesheet {
+    rules: Vec<Rule>,
+    remaining: String,
+}
+
+impl Stylesheet {
+    pub fn parse(input: &str) -> Self {
+        let mut result = Self::default();
+        let css = strip_comments(input);
+        let mut rest = css.as_str();
+        loop {
+            rest = rest.trim_start();
+            if rest.is_empty() {
+                break;
+            }
+            if rest.starts_with('@') {
+                let end = at_rule_end(rest);
+                result.remaining.push_str(&rest[..end]);
+                rest = &rest[end..];
+                continue;
+            }
+            let Some(open) = rest.find('{') else {
+                result.remaining.push_str(rest);
+                break;
+            };
+            let close = rest[open..]
+                .find('}')
+                .map(|end| open + end)
+                .unwrap_or(rest.len());
+            let body = &rest[open + 1..close];
+            let declarations = parse_declarations(body);
+            let mut unsupported = Vec::new();
+            for selector in rest[..open].split(',').map(str::trim) {
+                match Selector::parse(selector) {
+                    Some(selector) => result.rules.push(Rule {
+                        selector,
+                        declarations: declarations.clone(),
+                    }),
+                    None => unsupported.push(selector),
+                }
+            }
+            if !unsupported.is_empty() {
+                result.remaining.push_str(&unsupported.join(", "));
+                result.remaining.push_str(" {");
+                result.remaining.push_str(body.trim());
+                result.remaining.push('}');
+            }
+            rest = &rest[(close + 1).min(rest.len())..];
+        }
+        result
+    }
+
+    /// The rules that can't be inlined.
+    pub fn remaining(&self) -> &str {
+        &self.remaining
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Applies the matching rules to the opening tag. The declarations already
+    /// present in the `style` attribute take precedence, unless the rule is
+    /// `!important`.
+    fn apply(&self, tag: &str, name: &str) -> String {
+        let attributes = parse_attributes(tag);
+        let find = |key: &str| {
+            attributes
+                .iter()
+                .find(|attr| attr.name.eq_ignore_ascii_case(key))
+        };
+        let element = Element::new(name, &attributes);
+        let mut matching = self
+            .rules
+            .iter()
+            .filter(|rule| rule.selector.matches(&element))
+            .collect::<Vec<_>>();
+        if matching.is_empty() {
+            return tag.to_string();
+        }
+        // the sort is stable, so the source order is kept for a same specificity
+        matching.sort_by_key(|rule| rule.selector.specificity());
+
+        let mut declarations = Map::<String, String>::new();
+        for rule in matching {
+            for (key, value) in rule.declarations.iter() {
+                merge_declaration(&mut declarations, key.clone(), value.clone());
+            }
+        }
+        let style = find("style");
+        if let Some(value) = style.and_then(|attr| attr.value) {
+            for (key, value) in parse_declarations(value) {
+                merge_declaration(&mut declarations, key, value);
+            }
+        }
+        let value = declarations
+            .iter()
+            .map(|(key, value)| format!("{key}:{value};"))
+            .collect::<String>()
+            .replace('"', "&quot;");
+
+        match style {
+            Some(attr) => format!(
+                "{}style=\"{value}\"{}",
+                &tag[..attr.start],
+                &tag[attr.end..]
+            ),
+            None => {
+                let end = tag
+                    .trim_end_matches('>')
+                    .trim_end_matches('/')
+                    .trim_end()
+                    .len();
+                format!("{} style=\"{value}\"{}", &tag[..end], &tag[end..])
+            }
+        }
+    }
+}
+
+/// Inlines the stylesheet in the elements of the document. The content of the
+/// `head`, of the comments and of the conditional comments is left untouched.
+pub(crate) fn inline(input: &str, stylesheet: &Stylesheet) -> String {
+    if stylesheet.is_empty() {
+        return input.to_string();
+    }
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    let mut in_head = false;
+    while let Some(start) = rest.find('<') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if rest.starts_with("<!--") {
+            let end = rest
+                .find("-->")
+                .map(|index| index + 3)
+                .unwrap_or(rest.len());
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+        let end = tag_end(rest);
+        let tag = &rest[..end];
+        rest = &rest[end..];
+        let name = tag_name(tag);
+        if tag.starts_with("</") || tag.starts_with("<!") {
+            if name == "head" {
+                in_head = false;
+            }
+            output.push_str(tag);
+            continue;
+        }
+        if name == "head" {
+            in_head = true;
+        }
+        if in_head {
+            output.push_str(tag);
+        } else {
+            output.push_str(&stylesheet.apply(tag, &name));
+        }
+        if PRESERVED_TAGS.contains(&name.as_str()) {
+            let closing = format!("</{name}");
+            let end = rest
+                .to_ascii_lowercase()
+                .find(&closing)
+                .unwrap_or(rest.len());
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{inline, Selector, Stylesheet};
+
+    #[test]
+    fn should_parse_simple_selectors() {
+        assert_eq!(
+            Selector::parse("td.btn.large#main"),
+            Some(Selector {
+                tag: Some("td".into()),
+                ids: vec!["main".into()],
+                classes: vec!["btn".into(), "large".into()],
+            })
+        );
+        assert_eq!(Selector::parse("*"), Some(Selector::default()));
+        assert_eq!(Selector::parse("a:hover"), None);
+        assert_eq!(Selector::parse("div p"), None);
+        assert_eq!(Selector::parse("div > p"), None);
+        assert_eq!(Selector::parse("[data-foo]"), None);
+    }
+
+    #[test]
+    fn should_keep_rules_that_cannot_be_inlined() {
+        let stylesheet = Stylesheet::parse(
+            "/* buttons */ .btn { color: red; } a:hover, p { color: blue } @media (max-width:480px) { .btn { color: green; } }",
+        );
+        assert_eq!(stylesheet.rules.len(), 2);
+        assert_eq!(
+            stylesheet.remaining(),
+            "a:hover {color: blue}@media (max-width:480px) { .btn { color: green; } }"
+        );
+    }
+
+    #[test]
+    fn should_inline_matching_elements() {
+        let stylesheet = Stylesheet::parse(
+            ".btn { color:red } #main { font-weight: bold; } span { color: blue; }",
+        );
+        assert_eq!(
+            inline(
+                r#"<div class="btn" id="main"><span class="btn">a</span><span>b</span><p>c</p></div>"#,
+                &stylesheet
+            ),
+            r#"<div class="btn" id="main" style="color:red;font-weight:bold;"><span class="btn" style="color:red;">a</span><span style="color:blue;">b</span><p>c</p></div>"#
+        );
+    }
+
+    #[test]
+    fn should_keep_existing_style_first() {
+        let stylesheet =
+            Stylesheet::parse(".btn { color: red; padding: 0; } .btn { margin: 0 !important; }");
+        assert_eq!(
+            inline(
+                r#"<img class="btn" style="color:blue;margin:4px;" />"#,
+                &stylesheet
+            ),
+            r#"<img class="btn" style="color:blue;padding:0;margin:0 !important;" />"#
+        );
+    }
+
+    #[test]
+    fn should_ignore_head_and_comments() {
+        let stylesheet = Stylesheet::parse("td { color: red; }");
+        assert_eq!(
+            inline(
+                "<head><style>td { color: red; }</style></head><!--[if mso]><td><![endif]--><td>a</td>",
+                &stylesheet
+            ),
+            r#"<head><style>td { color: red; }</style></head><!--[if mso]><td><![endif]--><td style="color:red;">a</td>"#
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Removes the insignificant whitespaces from a rendered template.
+//!
+//! This is a conservative minification: the content of `pre`, `textarea` and
+//! `script` elements and the regular comments are kept untouched, and the
+//! content of the conditional comments is minified without changing the
+//! conditions.
+
+const BLOCK_TAGS: [&str; 33] = [
+    "!doctype",
+    "blockquote",
+    "body",
+    "center",
+    "col",
+    "colgroup",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "hr",
+    "html",
+    "li",
+    "link",
+    "meta",
+    "noscript",
+    "ol",
+    "p",
+    "style",
+    "table",
+    "tbody",
+    "td",
+    "th",
+    "thead",
+    "title",
+    "tr",
+];
+
+pub(super) const PRESERVED_TAGS: [&str; 3] = ["pre", "script", "textarea"];
+
+pub(super) const CONDITIONAL_START: &str = "<!--[if ";
+pub(super) const CONDITIONAL_END: &str = "<![endif]-->";
+const EMPTY_ATTRIBUTES: [&str; 2] = [" class=\"\"", " style=\"\""];
+
+pub(super) fn is_block(name: &str) -> bool {
+    BLOCK_TAGS.contains(&name)
+}
+
+/// Returns the lowercase name of the tag starting at the beginning of the
+/// input, without the leading `/` of closing tags.
+pub(super) fn tag_name(tag: &str) -> String {
+    tag.trim_start_matches('<')
+        .trim_start_matches('/')
+        .chars()
+        .take_while(|c| !c.is_whitespace() && *c != '>' && *c != '/')
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+/// Finds the end of the tag starting at the beginning of the input, ignoring
+/// the `>` contained in quoted attribute values.
+pub(super) fn tag_end(input: &str) -> usize {
+    let mut quote: Option<char> = None;
+    for (index, c) in input.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if q == c => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => return index + 1,
+            _ => {}
+        }
+    }
+    input.len()
+}
+
+pub(super) fn push_collapsed(output: &mut String, text: &str) {
+    let mut whitespace = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            whitespace = true;
+        } else {
+            if whitespace {
+                output.push(' ');
+                whitespace = false;
+            }
+            output.push(c);
+        }
+    }
+    if whitespace {
+        output.push(' ');
+    }
+}
+
+/// Returns the length of the empty attribute starting at the beginning of the
+/// input, when it's followed by the end of the attribute.
+fn empty_attribute(input: &str) -> Option<usize> {
+    EMPTY_ATTRIBUTES
+        .iter()
+        .find(|attribute| {
+            input.starts_with(*attribute)
+                && input[attribute.len()..]
+                    .starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        })
+        .map(|attribute| attribute.len())
+}
+
+/// Pushes the tag without its empty `class` and `style` attributes, the
+/// quoted attribute values being kept as they are.
+fn push_tag(output: &mut String, tag: &str) {
+    let mut quote: Option<char> = None;
+    let mut index = 0;
+    while let Some(c) = tag[index..].chars().next() {
+        match (quote, c) {
+            (Some(q), c) if q == c => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, ' ') => {
+                if let Some(len) = empty_attribute(&tag[index..]) {
+                    index += len;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        output.push(c);
+        index += c.len_utf8();
+    }
+}
+
+pub(crate) fn minify(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    // whether the previous tag was a block element
+    let mut after_block = true;
+    while !rest.is_empty() {
+        if rest.starts_with(CONDITIONAL_START) {
+            if let Some(end) = rest.find(CONDITIONAL_END) {
+                // conditional comment, like <!--[if mso]>...<![endif]-->
+                let start = rest.find('>').map(|index| index + 1).unwrap_or(end);
+                if start <= end {
+                    output.push_str(&rest[..start]);
+                    output.push_str(&minify(&rest[start..end]));
+                    output.push_str(CONDITIONAL_END);
+                    rest = &rest[end + CONDITIONAL_END.len()..];
+                    after_block = true;
+                    continue;
+                }
+            }
+        }
+        if rest.starts_with("<!--") {
+            let end = rest
+                .find("-->")
+                .map(|index| index + 3)
+                .unwrap_or(rest.len());
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+        } else if rest.starts_with('<') {
+            let end = tag_end(rest);
+            let tag = &rest[..end];
+            let name = tag_name(tag);
+            push_tag(&mut output, tag);
+            rest = &rest[end..];
+            after_block = is_block(&name);
+            if !tag.starts_with("</") && PRESERVED_TAGS.contains(&name.as_str()) {
+                let closing = format!("</{name}");
+                let end = rest
+                    .to_ascii_lowercase()
+                    .find(&closing)
+                    .unwrap_or(rest.len());
+                output.push_str(&rest[..end]);
+                rest = &rest[end..];
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = &rest[..end];
+            rest = &rest[end..];
+            if text.trim().is_empty() {
+                let before_block = rest.starts_with('<') && is_block(&tag_name(rest));
+                let before_comment = rest.starts_with("<!--");
+                if !(after_block || before_block || before_comment || rest.is_empty()) {
+                    output.push(' ');
+                }
+            } else {
+                push_collapsed(&mut output, text);
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::minify;
+
+    #[test]
+    fn should_remove_whitespaces_between_blocks() {
+        assert_eq!(
+            minify("<table>\n  <tr>\n\t<td>Hello   \n world</td>\n  </tr>\n</table>\n"),
+            "<table><tr><td>Hello world</td></tr></table>"
+        );
+    }
+
+    #[test]
+    fn should_keep_whitespaces_between_inline_elements() {
+        assert_eq!(
+            minify("<p><b>Hello</b>\n  <i>world</i></p>"),
+            "<p><b>Hello</b> <i>world</i></p>"
+        );
+    }
+
+    #[test]
+    fn should_remove_empty_attributes() {
+        assert_eq!(
+            minify(r#"<div class="" style=""><span class="foo" style="">a</span></div>"#),
+            r#"<div><span class="foo">a</span></div>"#
+        );
+    }
+
+    #[test]
+    fn should_keep_empty_attributes_in_values() {
+        assert_eq!(
+            minify(r#"<img alt='a class="" b' data-x=' style=""' class="" src="a.png" />"#),
+            r#"<img alt='a class="" b' data-x=' style=""' src="a.png" />"#
+        );
+        assert_eq!(
+            minify(r#"<div data-class="" xclass="">a</div>"#),
+            r#"<div data-class="" xclass="">a</div>"#
+        );
+    }
+
+    #[test]
+    fn should_keep_preformatted_content() {
+        assert_eq!(
+            minify("<div>\n<pre>  a\n  b</pre>\n</div>"),
+            "<div><pre>  a\n  b</pre></div>"
+        );
+    }
+
+    #[test]
+    fn should_keep_comments() {
+        assert_eq!(
+            minify("<div>\n<!-- hello\n  world -->\n</div>"),
+            "<div><!-- hello\n  world --></div>"
+        );
+    }
+
+    #[test]
+    fn should_minify_conditional_comments() {
+        assert_eq!(
+            minify("<!--[if mso]>\n<table>\n  <tr>\n</table>\n<![endif]-->\n<!--[if !mso]><!--><div>\n</div><!--<![endif]-->"),
+            "<!--[if mso]><table><tr></table><![endif]--><!--[if !mso]><!--><div></div><!--<![endif]-->"
+        );
+    }
+}
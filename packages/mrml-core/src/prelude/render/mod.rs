@@ -4,14 +4,27 @@ use std::sync::atomic::{AtomicU16, Ordering};
 use crate::helper::size::{Pixel, Size};
 use crate::helper::spacing::Spacing;
 
+mod amp;
 mod buffer;
+mod fragment;
 mod header;
+mod html_attributes;
+mod inline;
+mod minify;
 mod options;
+mod pretty;
 mod tag;
+pub(crate) mod text;
 
+pub(crate) use amp::amp;
 pub(crate) use buffer::*;
+pub(crate) use fragment::fragment;
 pub(crate) use header::*;
+pub(crate) use html_attributes::add_html_attributes;
+pub(crate) use inline::{inline, Stylesheet};
+pub(crate) use minify::minify;
 pub use options::*;
+pub(crate) use pretty::prettify;
 pub(crate) use tag::*;
 
 #[derive(Debug, thiserror::Error)]
@@ -20,6 +33,18 @@ pub enum Error {
     UnknownFragment(String),
     #[error("unable to format {0}")]
     Format(#[from] std::fmt::Error),
+    #[error("invalid breakpoint {0:?}, it should be a pixel value")]
+    InvalidBreakpoint(String),
+}
+
+/// Error returned when rendering a template into a
+/// [writer](std::io::Write), with [`Mjml::write_to`](crate::mjml::Mjml::write_to).
+#[derive(Debug, thiserror::Error)]
+pub enum WriteError {
+    #[error(transparent)]
+    Render(#[from] Error),
+    #[error("unable to write {0}")]
+    Io(#[from] std::io::Error),
 }
 
 #[derive(Debug, Default)]
@@ -86,6 +111,26 @@ impl<'root, Element, Extra> Renderer<'root, Element, Extra> {
     }
 }
 
+/// Size of a side of a spacing shorthand like `padding`, the sides being
+/// numbered clockwise from the top.
+fn spacing_side(value: &str, side: usize) -> Option<Size> {
+    let values = value.split_whitespace().collect::<Vec<_>>();
+    let index = match values.len() {
+        1 => 0,
+        2 => side % 2,
+        3 => [0, 1, 2, 1][side],
+        4 => side,
+        _ => return None,
+    };
+    Size::try_from(values[index]).ok()
+}
+
+fn resolve_spacing(size: Option<Size>, width: &Pixel) -> f32 {
+    size.and_then(|size| size.to_pixel(Size::Pixel(*width)))
+        .map(|size| size.value())
+        .unwrap_or(0.0)
+}
+
 pub(crate) trait Render<'root> {
     fn context(&self) -> &'root RenderContext<'root>;
 
@@ -154,37 +199,41 @@ pub(crate) trait Render<'root> {
         })
     }
 
-    fn get_padding_top(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("padding-top")
-            .or_else(|| self.attribute_as_spacing("padding").map(|s| s.into_top()))
+    fn get_padding_top(&self) -> Option<Size> {
+        self.attribute_as_size("padding-top")
+            .or_else(|| self.attribute("padding").and_then(|v| spacing_side(v, 0)))
     }
 
-    fn get_padding_bottom(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("padding-bottom").or_else(|| {
-            self.attribute_as_spacing("padding")
-                .map(|s| s.into_bottom())
-        })
+    fn get_padding_bottom(&self) -> Option<Size> {
+        self.attribute_as_size("padding-bottom")
+            .or_else(|| self.attribute("padding").and_then(|v| spacing_side(v, 2)))
     }
 
-    fn get_padding_left(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("padding-left")
-            .or_else(|| self.attribute_as_spacing("padding").map(|s| s.into_left()))
+    fn get_padding_left(&self) -> Option<Size> {
+        self.attribute_as_size("padding-left")
+            .or_else(|| self.attribute("padding").and_then(|v| spacing_side(v, 3)))
     }
 
-    fn get_padding_right(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("padding-right")
-            .or_else(|| self.attribute_as_spacing("padding").map(|s| s.into_right()))
+    fn get_padding_right(&self) -> Option<Size> {
+        self.attribute_as_size("padding-right")
+            .or_else(|| self.attribute("padding").and_then(|v| spacing_side(v, 1)))
     }
 
-    fn get_padding_horizontal(&self) -> Pixel {
-        let left = self.get_padding_left().map(|v| v.value()).unwrap_or(0.0);
-        let right = self.get_padding_right().map(|v| v.value()).unwrap_or(0.0);
+    /// Horizontal padding of the element in a container of the given width.
+    /// Like in css, the percentages are relative to the width of the
+    /// container, while they're kept as they are in the rendered styles. The
+    /// sizes depending on the font size are ignored.
+    fn get_padding_horizontal(&self, width: &Pixel) -> Pixel {
+        let left = resolve_spacing(self.get_padding_left(), width);
+        let right = resolve_spacing(self.get_padding_right(), width);
         Pixel::new(left + right)
     }
 
-    fn get_padding_vertical(&self) -> Pixel {
-        let top = self.get_padding_top().map(|v| v.value()).unwrap_or(0.0);
-        let bottom = self.get_padding_bottom().map(|v| v.value()).unwrap_or(0.0);
+    /// Vertical padding of the element in a container of the given width,
+    /// the percentages being relative to the width too.
+    fn get_padding_vertical(&self, width: &Pixel) -> Pixel {
+        let top = resolve_spacing(self.get_padding_top(), width);
+        let bottom = resolve_spacing(self.get_padding_bottom(), width);
         Pixel::new(top + bottom)
     }
 
@@ -207,11 +256,11 @@ pub(crate) trait Render<'root> {
             return Some(value);
         }
         if let Some(value) = self.raw_attribute("mj-class").and_then(|mj_classes| {
+            // like in mjml, the last class defining the attribute wins
             mj_classes
-                .split(' ')
-                .map(|mj_class| mj_class.trim())
+                .split_whitespace()
                 .filter_map(|mj_class| self.context().header.attribute_class(mj_class, key))
-                .next()
+                .last()
         }) {
             return Some(value);
         }
@@ -223,6 +272,9 @@ pub(crate) trait Render<'root> {
         if let Some(value) = self.context().header.attribute_all(key) {
             return Some(value);
         }
+        if let Some(value) = self.context().options.default_attribute(self.tag(), key) {
+            return Some(value);
+        }
         self.default_attribute(key)
     }
 
@@ -234,10 +286,31 @@ pub(crate) trait Render<'root> {
         tag
     }
 
+    /// Cell wrapping the element in its column or hero, with the
+    /// `container-background-color`, padding and alignment of the element.
+    fn container_cell<'a>(&'a self) -> Tag<'a>
+    where
+        'root: 'a,
+    {
+        Tag::td()
+            .maybe_add_style("background", self.attribute("container-background-color"))
+            .add_style("font-size", "0px")
+            .maybe_add_style("padding", self.attribute("padding"))
+            .maybe_add_style("padding-top", self.attribute("padding-top"))
+            .maybe_add_style("padding-right", self.attribute("padding-right"))
+            .maybe_add_style("padding-bottom", self.attribute("padding-bottom"))
+            .maybe_add_style("padding-left", self.attribute("padding-left"))
+            .add_style("word-break", "break-word")
+            .maybe_add_attribute("align", self.attribute("align"))
+    }
+
     fn set_container_width(&mut self, _width: Option<Pixel>) {}
     fn set_index(&mut self, _index: usize) {}
     fn set_siblings(&mut self, _count: usize) {}
     fn set_raw_siblings(&mut self, _count: usize) {}
+    /// Width of the element when it doesn't define one, when its siblings
+    /// define theirs, see [`auto_width`](crate::mj_column::auto_width).
+    fn set_auto_width(&mut self, _width: Option<Pixel>) {}
 
     fn add_extra_attribute(&mut self, _key: &'root str, _value: &'root str) {}
     fn maybe_add_extra_attribute(&mut self, key: &'root str, value: Option<&'root str>) {
@@ -305,4 +378,151 @@ mod tests {
         assert_eq!(gen.next_id(), "00000001");
         assert_eq!(gen.next_id(), "00000002");
     }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn attribute_should_follow_precedence() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <mj-all font-size="11px" />
+      <mj-text font-size="12px" />
+      <mj-class name="small" font-size="10px" />
+      <mj-class name="big" font-size="15px" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text mj-class="big" font-size="14px">local</mj-text>
+        <mj-text mj-class="big">class</mj-text>
+        <mj-text mj-class="small big">classes</mj-text>
+        <mj-text>element</mj-text>
+        <mj-button>all</mj-button>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        for (size, content) in [
+            ("14px", "local"),
+            ("15px", "class"),
+            ("15px", "classes"),
+            ("12px", "element"),
+            ("11px", "all"),
+        ] {
+            let start = result.find(&format!(">{content}<")).unwrap();
+            let style = result[..start].rfind("style=\"").unwrap();
+            assert!(
+                result[style..start].contains(&format!("font-size:{size};")),
+                "{content} should have font-size {size}"
+            );
+        }
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn option_attribute_should_follow_precedence() {
+        let options = crate::prelude::render::RenderOptions::builder()
+            .add_default_attribute("mj-text", "font-size", "16px")
+            .add_default_attribute("mj-all", "font-size", "9px")
+            .build()
+            .unwrap();
+        let font_size = |template: &str, content: &str| {
+            let root = crate::mjml::Mjml::parse(template).unwrap();
+            let result = root.element.render(&options).unwrap();
+            let start = result.find(&format!(">{content}<")).unwrap();
+            let style = result[..start].rfind("style=\"").unwrap();
+            let size = result[style..start].find("font-size:").unwrap() + style + 10;
+            let end = result[size..].find(';').unwrap() + size;
+            result[size..end].to_string()
+        };
+        // the options are used before the defaults of the element, the
+        // element ones before mj-all
+        let template = "<mjml><mj-body><mj-section><mj-column><mj-text>text</mj-text><mj-button>button</mj-button></mj-column></mj-section></mj-body></mjml>";
+        assert_eq!(font_size(template, "text"), "16px");
+        assert_eq!(font_size(template, "button"), "9px");
+        // the mj-attributes of the template are used before the options
+        let template = r#"<mjml><mj-head><mj-attributes><mj-all font-size="11px" /></mj-attributes></mj-head><mj-body><mj-section><mj-column><mj-text>text</mj-text><mj-text mj-class="missing" font-size="12px">local</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        assert_eq!(font_size(template, "text"), "11px");
+        assert_eq!(font_size(template, "local"), "12px");
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn parent_attribute_should_follow_precedence() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <mj-class name="green" color="green" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-social color="red">
+          <mj-social-element name="facebook" color="blue">local</mj-social-element>
+          <mj-social-element name="facebook" mj-class="green">parent</mj-social-element>
+        </mj-social>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        for (color, content) in [("blue", "local"), ("red", "parent")] {
+            let start = result.find(&format!(">{content}<")).unwrap();
+            let style = result[..start].rfind("style=\"").unwrap();
+            assert!(
+                result[style..start].contains(&format!("color:{color};")),
+                "{content} should have color {color}"
+            );
+        }
+    }
+
+    #[test]
+    fn padding_should_split_shorthand() {
+        use super::{Header, RenderContext, RenderOptions, Renderable};
+        use crate::mj_text::MjText;
+
+        let opts = RenderOptions::default();
+        let header = Header::new(None, None, None, None);
+        let context = RenderContext::new(&opts, header);
+        let paddings = |attributes: &[(&str, &str)]| {
+            let mut element = MjText::default();
+            for (key, value) in attributes {
+                element
+                    .attributes
+                    .insert(key.to_string(), Some(value.to_string()));
+            }
+            let renderer = element.renderer(&context);
+            [
+                renderer.get_padding_top(),
+                renderer.get_padding_right(),
+                renderer.get_padding_bottom(),
+                renderer.get_padding_left(),
+            ]
+            .map(|value| value.map(|v| v.value()))
+        };
+        let px = |values: [f32; 4]| values.map(Some);
+
+        assert_eq!(paddings(&[("padding", "10px")]), px([10.0; 4]));
+        assert_eq!(
+            paddings(&[("padding", "10px 20px")]),
+            px([10.0, 20.0, 10.0, 20.0])
+        );
+        assert_eq!(
+            paddings(&[("padding", "10px 20px 30px")]),
+            px([10.0, 20.0, 30.0, 20.0])
+        );
+        assert_eq!(
+            paddings(&[("padding", "10px 20px 30px 40px")]),
+            px([10.0, 20.0, 30.0, 40.0])
+        );
+        assert_eq!(
+            paddings(&[("padding", "10px 20px 30px 40px"), ("padding-top", "5px")]),
+            px([5.0, 20.0, 30.0, 40.0])
+        );
+    }
 }
@@ -1,5 +1,9 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::helper::size::Pixel;
+use crate::mj_social_element::{default_social_networks, SocialNetwork};
 
 pub fn default_fonts() -> HashMap<String, Cow<'static, str>> {
     HashMap::from([
@@ -26,11 +30,123 @@ pub fn default_fonts() -> HashMap<String, Cow<'static, str>> {
     ])
 }
 
+/// Builds the url of the Google Fonts stylesheet of a font family, with the
+/// same weights as the default fonts.
+pub fn google_font_href(family: &str) -> String {
+    format!(
+        "https://fonts.googleapis.com/css?family={}:300,400,500,700",
+        family.trim().replace(' ', "+")
+    )
+}
+
+/// Document type declaration at the beginning of the rendered template.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DocType {
+    /// `<!doctype html>`, like mjml does.
+    #[default]
+    Html5,
+    /// The XHTML 1.0 transitional doctype, still required by some email
+    /// clients and corporate gateways.
+    Transitional,
+    /// The XHTML 1.0 strict doctype.
+    Strict,
+}
+
+impl DocType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Html5 => "<!doctype html>",
+            Self::Transitional => {
+                r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Transitional//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd">"#
+            }
+            Self::Strict => {
+                r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd">"#
+            }
+        }
+    }
+}
+
+/// Kind of document produced by the rendering.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Regular html email, like mjml does.
+    #[default]
+    Html,
+    /// AMP for Email document, with the `⚡4email` attribute, the AMP script
+    /// and all the styles merged into a `<style amp-custom>` element. The
+    /// conditional comments for Outlook, the `!important` flags, the
+    /// `@import` rules, the stylesheet links and the attributes rejected by
+    /// AMP are removed, and the [`doctype`](RenderOptions::doctype) is
+    /// ignored. The images are rendered with `<amp-img>` elements, which need
+    /// the `height` of the `mj-image` to keep their ratio.
+    Amp,
+}
+
 #[derive(Debug)]
 pub struct RenderOptions {
     pub disable_comments: bool,
     pub social_icon_origin: Option<Cow<'static, str>>,
+    /// Networks available in the `mj-social-element` elements, by name. The
+    /// [networks supported by mjml](default_social_networks) by default.
+    pub social_networks: HashMap<String, SocialNetwork>,
     pub fonts: HashMap<String, Cow<'static, str>>,
+    /// Breakpoint used when the template doesn't define any `mj-breakpoint`.
+    /// It should be a pixel value, otherwise the rendering fails with
+    /// [`Error::InvalidBreakpoint`](super::Error::InvalidBreakpoint).
+    pub breakpoint: Option<Cow<'static, str>>,
+    /// Removes the insignificant whitespaces and the empty `class` and
+    /// `style` attributes from the output. Disabled by default.
+    pub minify: bool,
+    /// Indents the output with two spaces per nesting level and puts each
+    /// block element on its own line, to make it easier to read while
+    /// debugging. It can't be combined with [`minify`](Self::minify), which
+    /// takes precedence. Disabled by default.
+    pub pretty: bool,
+    /// Copies the declarations of the `mj-style` rules into the `style`
+    /// attribute of the matching elements, like it's always done for the
+    /// `mj-style inline="inline"` rules. Only the tag, class and id selectors
+    /// are supported. Disabled by default.
+    pub inline_css: bool,
+    /// When inlining the styles, removes the inlined rules from the `<style>`
+    /// element of the head. The other rules, like the media queries, are kept.
+    pub strip_inlined: bool,
+    /// Document type declaration of the rendered template, `<!doctype html>`
+    /// by default.
+    pub doctype: DocType,
+    /// Kind of document to produce, a regular html email by default.
+    pub output_format: OutputFormat,
+    /// Adds the links to all the registered fonts, including the ones that
+    /// are not used by the template. Disabled by default, only the fonts
+    /// used by some `font-family` attribute are included.
+    pub always_include_fonts: bool,
+    /// Query parameters appended to the links of the `mj-button`, `mj-image`
+    /// and `mj-social-element` elements, like the UTM parameters used to
+    /// track the campaigns. The `mailto:`, `tel:` and anchor links are kept
+    /// unchanged.
+    pub link_params: Vec<(String, String)>,
+    /// Adds the content of the `mj-preview` element in a hidden `div` at the
+    /// beginning of the body, displayed by the email clients next to the
+    /// subject. Enabled by default.
+    pub inject_preview_text: bool,
+    /// Adds the `color-scheme` and `supported-color-schemes` meta tags to the
+    /// head, telling the email clients that the template supports the dark
+    /// mode, with some `@media (prefers-color-scheme: dark)` rules in the
+    /// `mj-style` elements. Disabled by default.
+    pub dark_mode: bool,
+    /// Renders only the content of the body, without the doctype and the
+    /// `html`, `head` and `body` elements, to embed the template in an
+    /// existing document. The `<style>` elements of the head are kept before
+    /// the content. Disabled by default.
+    pub fragment: bool,
+    /// Default attributes of the elements, by tag name, with `mj-all` for the
+    /// attributes applied to all the elements. They have a lower priority
+    /// than the `mj-attributes` of the template.
+    pub default_attributes: HashMap<String, HashMap<String, String>>,
+    /// Url prefixed to the relative `src` of the `mj-image`,
+    /// `mj-social-element` and `mj-carousel-image` elements, like the url of
+    /// a CDN serving the images. The absolute urls, like `https://`, `//` or
+    /// `data:` ones, are kept unchanged.
+    pub image_base_url: Option<Cow<'static, str>>,
 }
 
 impl Default for RenderOptions {
@@ -38,7 +154,482 @@ impl Default for RenderOptions {
         Self {
             disable_comments: false,
             social_icon_origin: None,
+            social_networks: default_social_networks(),
             fonts: default_fonts(),
+            breakpoint: None,
+            minify: false,
+            pretty: false,
+            inline_css: false,
+            strip_inlined: false,
+            doctype: DocType::default(),
+            output_format: OutputFormat::default(),
+            always_include_fonts: false,
+            link_params: Vec::new(),
+            inject_preview_text: true,
+            dark_mode: false,
+            fragment: false,
+            default_attributes: HashMap::new(),
+            image_base_url: None,
+        }
+    }
+}
+
+impl RenderOptions {
+    pub fn builder() -> RenderOptionsBuilder {
+        RenderOptionsBuilder::default()
+    }
+
+    pub(crate) fn breakpoint(&self) -> Result<Option<Pixel>, super::Error> {
+        self.breakpoint
+            .as_deref()
+            .map(|value| {
+                Pixel::try_from(value).map_err(|_| super::Error::InvalidBreakpoint(value.into()))
+            })
+            .transpose()
+    }
+
+    /// Value of the attribute in the [`default_attributes`](Self::default_attributes)
+    /// of the element with the given tag, then of `mj-all`.
+    pub(crate) fn default_attribute(&self, tag: Option<&str>, name: &str) -> Option<&str> {
+        tag.and_then(|tag| self.default_attributes.get(tag))
+            .and_then(|attributes| attributes.get(name))
+            .or_else(|| {
+                self.default_attributes
+                    .get("mj-all")
+                    .and_then(|attributes| attributes.get(name))
+            })
+            .map(String::as_str)
+    }
+
+    /// Prefixes the relative url of an image with the
+    /// [`image_base_url`](Self::image_base_url).
+    pub(crate) fn with_image_base_url<'a>(&self, src: Cow<'a, str>) -> Cow<'a, str> {
+        match self.image_base_url {
+            Some(ref base) if !src.is_empty() && !is_absolute_url(&src) => Cow::Owned(format!(
+                "{}/{}",
+                base.trim_end_matches('/'),
+                src.trim_start_matches('/')
+            )),
+            _ => src,
+        }
+    }
+
+    /// Appends the [`link_params`](Self::link_params) to the query string of
+    /// the given link, before its fragment.
+    pub(crate) fn with_link_params<'a>(&self, href: Cow<'a, str>) -> Cow<'a, str> {
+        if self.link_params.is_empty()
+            || href.starts_with('#')
+            || ["mailto:", "tel:"].iter().any(|scheme| {
+                href.get(..scheme.len())
+                    .is_some_and(|start| start.eq_ignore_ascii_case(scheme))
+            })
+        {
+            return href;
+        }
+        let (link, fragment) = match href.find('#') {
+            Some(index) => href.split_at(index),
+            None => (href.as_ref(), ""),
+        };
+        let mut result = String::with_capacity(href.len() + 32);
+        result.push_str(link);
+        if !link.contains('?') {
+            result.push('?');
+        } else if !link.ends_with('?') && !link.ends_with('&') {
+            result.push('&');
+        }
+        for (index, (name, value)) in self.link_params.iter().enumerate() {
+            if index > 0 {
+                result.push('&');
+            }
+            encode_query_component(&mut result, name);
+            result.push('=');
+            encode_query_component(&mut result, value);
+        }
+        result.push_str(fragment);
+        Cow::Owned(result)
+    }
+}
+
+/// Whether the url has a scheme, like `https:` or `data:`, or is relative to
+/// the protocol, like `//example.com`.
+fn is_absolute_url(url: &str) -> bool {
+    if url.starts_with("//") {
+        return true;
+    }
+    url.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+/// Percent encodes the characters of a query parameter name or value, except
+/// the unreserved ones.
+pub(crate) fn encode_query_component(result: &mut String, value: &str) {
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                result.push(byte as char)
+            }
+            _ => result.push_str(&format!("%{byte:02X}")),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RenderOptionsError {
+    #[error("invalid breakpoint {0:?}, it should be a pixel value")]
+    InvalidBreakpoint(String),
+    #[error("the minify and pretty options can't be enabled together")]
+    MinifyAndPretty,
+}
+
+/// Builder to create some [`RenderOptions`].
+///
+/// ```rust
+/// use mrml::prelude::render::RenderOptions;
+///
+/// let options = RenderOptions::builder()
+///     .disable_comments(true)
+///     .disable_default_fonts()
+///     .add_font("Raleway", "https://fonts.googleapis.com/css?family=Raleway")
+///     .breakpoint("320px")
+///     .build()
+///     .expect("valid options");
+/// assert_eq!(options.fonts.len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct RenderOptionsBuilder {
+    inner: RenderOptions,
+}
+
+impl RenderOptionsBuilder {
+    pub fn disable_comments(mut self, value: bool) -> Self {
+        self.inner.disable_comments = value;
+        self
+    }
+
+    pub fn social_icon_origin<V: Into<Cow<'static, str>>>(mut self, value: V) -> Self {
+        self.inner.social_icon_origin = Some(value.into());
+        self
+    }
+
+    /// Registers a network, or replaces the default one with the same name.
+    pub fn add_social_network<N: Into<String>>(mut self, name: N, network: SocialNetwork) -> Self {
+        self.inner.social_networks.insert(name.into(), network);
+        self
+    }
+
+    pub fn add_font<N: Into<String>, H: Into<Cow<'static, str>>>(
+        mut self,
+        name: N,
+        href: H,
+    ) -> Self {
+        self.inner.fonts.insert(name.into(), href.into());
+        self
+    }
+
+    /// Registers a font hosted by Google Fonts, given its family name.
+    pub fn add_google_font<N: Into<String>>(self, family: N) -> Self {
+        let family = family.into();
+        let href = google_font_href(&family);
+        self.add_font(family, href)
+    }
+
+    /// Registers several fonts hosted by Google Fonts.
+    pub fn with_google_fonts(self, families: &[&str]) -> Self {
+        families
+            .iter()
+            .fold(self, |builder, family| builder.add_google_font(*family))
+    }
+
+    /// Removes the fonts that are registered by default, this should be
+    /// called before adding new fonts.
+    pub fn disable_default_fonts(mut self) -> Self {
+        self.inner.fonts.clear();
+        self
+    }
+
+    pub fn breakpoint<V: Into<Cow<'static, str>>>(mut self, value: V) -> Self {
+        self.inner.breakpoint = Some(value.into());
+        self
+    }
+
+    pub fn minify(mut self, value: bool) -> Self {
+        self.inner.minify = value;
+        self
+    }
+
+    pub fn pretty(mut self, value: bool) -> Self {
+        self.inner.pretty = value;
+        self
+    }
+
+    pub fn inline_css(mut self, value: bool) -> Self {
+        self.inner.inline_css = value;
+        self
+    }
+
+    pub fn strip_inlined(mut self, value: bool) -> Self {
+        self.inner.strip_inlined = value;
+        self
+    }
+
+    pub fn doctype(mut self, value: DocType) -> Self {
+        self.inner.doctype = value;
+        self
+    }
+
+    pub fn output_format(mut self, value: OutputFormat) -> Self {
+        self.inner.output_format = value;
+        self
+    }
+
+    pub fn always_include_fonts(mut self, value: bool) -> Self {
+        self.inner.always_include_fonts = value;
+        self
+    }
+
+    /// Appends a query parameter to the links, see
+    /// [`RenderOptions::link_params`].
+    pub fn add_link_param<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.inner.link_params.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn inject_preview_text(mut self, value: bool) -> Self {
+        self.inner.inject_preview_text = value;
+        self
+    }
+
+    pub fn dark_mode(mut self, value: bool) -> Self {
+        self.inner.dark_mode = value;
+        self
+    }
+
+    pub fn fragment(mut self, value: bool) -> Self {
+        self.inner.fragment = value;
+        self
+    }
+
+    /// Default attribute of the elements with the given tag, or of all the
+    /// elements with `mj-all`, see [`RenderOptions::default_attributes`].
+    pub fn add_default_attribute<E: Into<String>, N: Into<String>, V: Into<String>>(
+        mut self,
+        element: E,
+        name: N,
+        value: V,
+    ) -> Self {
+        self.inner
+            .default_attributes
+            .entry(element.into())
+            .or_default()
+            .insert(name.into(), value.into());
+        self
+    }
+
+    pub fn image_base_url<V: Into<Cow<'static, str>>>(mut self, value: V) -> Self {
+        self.inner.image_base_url = Some(value.into());
+        self
+    }
+
+    pub fn build(self) -> Result<RenderOptions, RenderOptionsError> {
+        if let Some(ref value) = self.inner.breakpoint {
+            if Pixel::try_from(value.as_ref()).is_err() {
+                return Err(RenderOptionsError::InvalidBreakpoint(value.to_string()));
+            }
+        }
+        if self.inner.minify && self.inner.pretty {
+            return Err(RenderOptionsError::MinifyAndPretty);
         }
+        Ok(self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DocType, RenderOptions, RenderOptionsError};
+    use crate::helper::size::Pixel;
+
+    #[test]
+    fn should_build_with_fonts() {
+        let options = RenderOptions::builder()
+            .disable_default_fonts()
+            .add_font("Foo", "https://example.com/foo.css")
+            .build()
+            .unwrap();
+        assert_eq!(options.fonts.len(), 1);
+        assert_eq!(
+            options.fonts.get("Foo").unwrap(),
+            "https://example.com/foo.css"
+        );
+    }
+
+    #[test]
+    fn should_build_with_google_fonts() {
+        let options = RenderOptions::builder()
+            .disable_default_fonts()
+            .add_google_font("Open Sans")
+            .with_google_fonts(&["Roboto Condensed", "Lato"])
+            .build()
+            .unwrap();
+        assert_eq!(options.fonts.len(), 3);
+        assert_eq!(
+            options.fonts.get("Open Sans").unwrap(),
+            "https://fonts.googleapis.com/css?family=Open+Sans:300,400,500,700"
+        );
+        assert_eq!(
+            options.fonts.get("Roboto Condensed").unwrap(),
+            "https://fonts.googleapis.com/css?family=Roboto+Condensed:300,400,500,700"
+        );
+    }
+
+    #[test]
+    fn should_keep_default_fonts() {
+        let options = RenderOptions::builder()
+            .add_font("Foo", "https://example.com/foo.css")
+            .build()
+            .unwrap();
+        assert_eq!(options.fonts.len(), 6);
+    }
+
+    #[test]
+    fn should_build_with_pixel_breakpoint() {
+        let options = RenderOptions::builder()
+            .breakpoint("320px")
+            .build()
+            .unwrap();
+        assert_eq!(options.breakpoint().unwrap(), Some(Pixel::new(320.0)));
+    }
+
+    #[test]
+    fn should_fail_with_percent_breakpoint() {
+        let err = RenderOptions::builder()
+            .breakpoint("50%")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, RenderOptionsError::InvalidBreakpoint(value) if value == "50%"));
+    }
+
+    #[test]
+    fn should_fail_with_minify_and_pretty() {
+        let err = RenderOptions::builder()
+            .minify(true)
+            .pretty(true)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, RenderOptionsError::MinifyAndPretty));
+    }
+
+    #[test]
+    fn should_fail_using_invalid_breakpoint_set_without_builder() {
+        let options = RenderOptions {
+            breakpoint: Some("50%".into()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            options.breakpoint(),
+            Err(crate::prelude::render::Error::InvalidBreakpoint(value)) if value == "50%"
+        ));
+    }
+
+    #[test]
+    fn should_build_with_doctype() {
+        assert_eq!(RenderOptions::default().doctype, DocType::Html5);
+        let options = RenderOptions::builder()
+            .doctype(DocType::Strict)
+            .build()
+            .unwrap();
+        assert_eq!(options.doctype, DocType::Strict);
+    }
+
+    #[test]
+    fn should_add_link_params() {
+        let options = RenderOptions::builder()
+            .add_link_param("utm_source", "newsletter")
+            .add_link_param("utm_campaign", "spring sale")
+            .build()
+            .unwrap();
+        let cases = [
+            (
+                "https://example.com",
+                "https://example.com?utm_source=newsletter&utm_campaign=spring%20sale",
+            ),
+            (
+                "https://example.com/?page=1",
+                "https://example.com/?page=1&utm_source=newsletter&utm_campaign=spring%20sale",
+            ),
+            (
+                "https://example.com/?",
+                "https://example.com/?utm_source=newsletter&utm_campaign=spring%20sale",
+            ),
+            (
+                "https://example.com/#top",
+                "https://example.com/?utm_source=newsletter&utm_campaign=spring%20sale#top",
+            ),
+            ("mailto:hello@example.com", "mailto:hello@example.com"),
+            ("TEL:+33123456789", "TEL:+33123456789"),
+            ("#top", "#top"),
+        ];
+        for (href, expected) in cases {
+            assert_eq!(options.with_link_params(href.into()), expected);
+        }
+    }
+
+    #[test]
+    fn should_find_default_attributes() {
+        let options = RenderOptions::builder()
+            .add_default_attribute("mj-all", "color", "red")
+            .add_default_attribute("mj-text", "color", "blue")
+            .build()
+            .unwrap();
+        assert_eq!(
+            options.default_attribute(Some("mj-text"), "color"),
+            Some("blue")
+        );
+        assert_eq!(
+            options.default_attribute(Some("mj-button"), "color"),
+            Some("red")
+        );
+        assert_eq!(options.default_attribute(None, "color"), Some("red"));
+        assert_eq!(options.default_attribute(Some("mj-text"), "padding"), None);
+    }
+
+    #[test]
+    fn should_prefix_relative_image_urls() {
+        let options = RenderOptions::builder()
+            .image_base_url("https://cdn.example.com/")
+            .build()
+            .unwrap();
+        let cases = [
+            ("images/logo.png", "https://cdn.example.com/images/logo.png"),
+            ("/logo.png", "https://cdn.example.com/logo.png"),
+            (
+                "https://example.com/logo.png",
+                "https://example.com/logo.png",
+            ),
+            ("HTTP://example.com/logo.png", "HTTP://example.com/logo.png"),
+            ("//example.com/logo.png", "//example.com/logo.png"),
+            ("data:image/png;base64,AAAA", "data:image/png;base64,AAAA"),
+            ("cid:logo", "cid:logo"),
+            ("", ""),
+        ];
+        for (src, expected) in cases {
+            assert_eq!(options.with_image_base_url(src.into()), expected);
+        }
+        assert_eq!(
+            RenderOptions::default().with_image_base_url("images/logo.png".into()),
+            "images/logo.png"
+        );
+    }
+
+    #[test]
+    fn should_not_change_links_without_params() {
+        let options = RenderOptions::default();
+        assert!(matches!(
+            options.with_link_params("https://example.com".into()),
+            std::borrow::Cow::Borrowed(_)
+        ));
     }
 }
@@ -0,0 +1,144 @@
+//! Indents a rendered template, to make it easier to read while debugging.
+//!
+//! Each block element starts a new line, indented with two spaces per
+//! nesting level, while the inline elements and the text are kept on the
+//! line of their parent. The comments, the conditional comments and the
+//! content of `pre`, `script`, `style` and `textarea` elements are kept
+//! untouched.
+
+use super::minify::{
+    is_block, push_collapsed, tag_end, tag_name, CONDITIONAL_END, CONDITIONAL_START, PRESERVED_TAGS,
+};
+
+const INDENT: &str = "  ";
+const VOID_TAGS: [&str; 7] = ["!doctype", "br", "col", "hr", "img", "link", "meta"];
+
+fn new_line(output: &mut String, depth: usize) {
+    if !output.is_empty() {
+        output.push('\n');
+    }
+    for _ in 0..depth {
+        output.push_str(INDENT);
+    }
+}
+
+fn is_preserved(name: &str) -> bool {
+    name == "style" || PRESERVED_TAGS.contains(&name)
+}
+
+pub(crate) fn prettify(input: &str) -> String {
+    let mut output = String::with_capacity(input.len() * 2);
+    let mut rest = input;
+    let mut depth: usize = 0;
+    // whether the previous token was a block element or a comment
+    let mut after_block = true;
+    while !rest.is_empty() {
+        if rest.starts_with("<!--") {
+            let end = if rest.starts_with(CONDITIONAL_START) {
+                rest.find(CONDITIONAL_END)
+                    .map(|index| index + CONDITIONAL_END.len())
+            } else {
+                rest.find("-->").map(|index| index + 3)
+            }
+            .unwrap_or(rest.len());
+            new_line(&mut output, depth);
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+            after_block = true;
+        } else if rest.starts_with('<') {
+            let end = tag_end(rest);
+            let tag = &rest[..end];
+            let name = tag_name(tag);
+            rest = &rest[end..];
+            let closing = tag.starts_with("</");
+            if is_block(&name) {
+                if closing {
+                    depth = depth.saturating_sub(1);
+                }
+                new_line(&mut output, depth);
+                if !closing && !tag.ends_with("/>") && !VOID_TAGS.contains(&name.as_str()) {
+                    depth += 1;
+                }
+                after_block = true;
+            } else {
+                if after_block {
+                    new_line(&mut output, depth);
+                }
+                after_block = false;
+            }
+            output.push_str(tag);
+            if !closing && is_preserved(&name) {
+                let closing = format!("</{name}");
+                let end = rest
+                    .to_ascii_lowercase()
+                    .find(&closing)
+                    .unwrap_or(rest.len());
+                output.push_str(&rest[..end]);
+                rest = &rest[end..];
+                if is_block(&name) {
+                    // the closing tag stays next to the preserved content
+                    let end = tag_end(rest);
+                    output.push_str(&rest[..end]);
+                    rest = &rest[end..];
+                    depth = depth.saturating_sub(1);
+                }
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = &rest[..end];
+            rest = &rest[end..];
+            if text.trim().is_empty() {
+                let before_block = rest.starts_with('<') && is_block(&tag_name(rest));
+                if !(after_block || before_block || rest.starts_with("<!--") || rest.is_empty()) {
+                    output.push(' ');
+                }
+            } else {
+                if after_block {
+                    new_line(&mut output, depth);
+                    push_collapsed(&mut output, text.trim_start());
+                } else {
+                    push_collapsed(&mut output, text);
+                }
+                after_block = false;
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prettify;
+
+    #[test]
+    fn should_indent_blocks() {
+        assert_eq!(
+            prettify("<table><tbody><tr><td>Hello <b>World</b></td></tr></tbody></table>"),
+            "<table>\n  <tbody>\n    <tr>\n      <td>\n        Hello <b>World</b>\n      </td>\n    </tr>\n  </tbody>\n</table>"
+        );
+    }
+
+    #[test]
+    fn should_not_indent_void_elements() {
+        assert_eq!(
+            prettify(r#"<head><meta charset="utf-8"><link href="a.css"><title>Hello</title></head>"#),
+            "<head>\n  <meta charset=\"utf-8\">\n  <link href=\"a.css\">\n  <title>\n    Hello\n  </title>\n</head>"
+        );
+    }
+
+    #[test]
+    fn should_keep_preformatted_content() {
+        assert_eq!(
+            prettify("<div><pre>  a\n  b</pre><style>.a { color: red; }\n</style></div>"),
+            "<div>\n  <pre>  a\n  b</pre>\n  <style>.a { color: red; }\n</style>\n</div>"
+        );
+    }
+
+    #[test]
+    fn should_keep_conditional_comments() {
+        assert_eq!(
+            prettify("<div><!--[if mso]><table><tr><td><![endif]--><p>Hello</p><!--[if mso]></td></tr></table><![endif]--></div>"),
+            "<div>\n  <!--[if mso]><table><tr><td><![endif]-->\n  <p>\n    Hello\n  </p>\n  <!--[if mso]></td></tr></table><![endif]-->\n</div>"
+        );
+    }
+}
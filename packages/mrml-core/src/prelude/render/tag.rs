@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 use std::fmt::Write;
 
-use super::RenderBuffer;
+use super::{AttributeValue, RenderBuffer};
 use crate::prelude::hash::{Map, Set};
 
 #[derive(Default)]
@@ -11,7 +11,7 @@ impl std::fmt::Debug for Styles<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_char('"')?;
         for (key, value) in self.0.iter() {
-            write!(f, "{key}:{value};")?;
+            write!(f, "{}:{};", AttributeValue(key), AttributeValue(value))?;
         }
         f.write_char('"')
     }
@@ -27,7 +27,7 @@ impl std::fmt::Debug for Classes<'_> {
             if i > 0 {
                 f.write_char(' ')?;
             }
-            f.write_str(c)?;
+            write!(f, "{}", AttributeValue(c))?;
         }
         f.write_char('"')
     }
@@ -76,13 +76,35 @@ impl<'a> Tag<'a> {
         }
     }
 
+    /// Adds the space separated classes of `value`, ignoring the ones already
+    /// added.
     pub fn add_class<C: Into<Cow<'a, str>>>(mut self, value: C) -> Self {
-        self.classes.0.insert(value.into());
+        match value.into() {
+            Cow::Borrowed(value) => {
+                for item in value.split_whitespace() {
+                    self.classes.0.insert(Cow::Borrowed(item));
+                }
+            }
+            Cow::Owned(value) if value.contains(char::is_whitespace) => {
+                for item in value.split_whitespace() {
+                    self.classes.0.insert(Cow::Owned(item.to_string()));
+                }
+            }
+            Cow::Owned(value) if !value.is_empty() => {
+                self.classes.0.insert(Cow::Owned(value));
+            }
+            Cow::Owned(_) => {}
+        }
         self
     }
 
+    /// Adds the space separated classes of `value`, each of them followed by
+    /// the suffix, like `a b` becoming `a-outlook b-outlook`.
     pub fn add_suffixed_class<T: AsRef<str>>(self, value: T, suffix: &str) -> Self {
-        self.add_class(format!("{}-{suffix}", value.as_ref()))
+        value
+            .as_ref()
+            .split_whitespace()
+            .fold(self, |tag, item| tag.add_class(format!("{item}-{suffix}")))
     }
 
     pub fn maybe_add_suffixed_class<T: AsRef<str>>(self, value: Option<T>, suffix: &str) -> Self {
@@ -195,3 +217,46 @@ impl Tag<'_> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Tag;
+    use crate::prelude::render::RenderBuffer;
+
+    #[test]
+    fn should_render_styles_in_insertion_order() {
+        let tag = Tag::new("div")
+            .add_style("width", "100%")
+            .add_style("color", "red")
+            .maybe_add_style("border", None::<&str>)
+            .add_style("align", "left");
+        for _ in 0..3 {
+            let mut buffer = RenderBuffer::default();
+            tag.render_closed(&mut buffer).unwrap();
+            let output: String = buffer.into();
+            assert_eq!(
+                output,
+                r#"<div style="width:100%;color:red;align:left;" />"#
+            );
+        }
+    }
+
+    #[test]
+    fn should_split_and_deduplicate_classes() {
+        let mut buffer = RenderBuffer::default();
+        Tag::new("div")
+            .add_class("mj-column-per-100")
+            .add_class("a  b")
+            .add_class(String::from("b c"))
+            .add_class("a")
+            .add_class("")
+            .add_suffixed_class("a b", "outlook")
+            .render_closed(&mut buffer)
+            .unwrap();
+        let output: String = buffer.into();
+        assert_eq!(
+            output,
+            r#"<div class="mj-column-per-100 a b c a-outlook b-outlook" />"#
+        );
+    }
+}
@@ -0,0 +1,440 @@
+//! Plain text rendering of the body, used as an alternative to the html
+//! output for email clients that don't display html.
+
+use crate::comment::Comment;
+use crate::mj_accordion::{MjAccordion, MjAccordionChild};
+use crate::mj_body::{MjBody, MjBodyChild};
+use crate::mj_carousel::{MjCarousel, MjCarouselChild};
+use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyChild};
+use crate::mj_navbar::{MjNavbar, MjNavbarChild};
+use crate::mj_raw::MjRawChild;
+use crate::mj_social::{MjSocial, MjSocialChild};
+use crate::node::Node;
+use crate::prelude::AttributeMap;
+use crate::text::Text;
+
+const DIVIDER: &str = "----------------------------------------";
+
+const BLOCK_TAGS: [&str; 17] = [
+    "address",
+    "blockquote",
+    "br",
+    "div",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "li",
+    "ol",
+    "p",
+    "table",
+    "tr",
+    "ul",
+];
+
+const IGNORED_TAGS: [&str; 3] = ["head", "script", "style"];
+
+#[derive(Debug, Default)]
+pub(crate) struct TextBuffer {
+    inner: String,
+}
+
+impl TextBuffer {
+    fn push_str(&mut self, value: &str) {
+        self.inner.push_str(value);
+    }
+
+    fn push_text(&mut self, value: &str) {
+        self.inner.push_str(&decode_entities(value));
+    }
+
+    fn new_line(&mut self) {
+        self.inner.push('\n');
+    }
+
+    fn new_paragraph(&mut self) {
+        self.inner.push_str("\n\n");
+    }
+
+    fn push_link(&mut self, label: &str, href: Option<&str>) {
+        match href {
+            Some(href) if !href.is_empty() && label.is_empty() => self.push_text(href),
+            Some(href) if !href.is_empty() => {
+                self.push_text(label);
+                self.push_str(" (");
+                self.push_str(href);
+                self.push_str(")");
+            }
+            _ => self.push_text(label),
+        }
+    }
+}
+
+impl From<TextBuffer> for String {
+    /// Removes the extra whitespaces on each line and collapses the
+    /// consecutive blank lines.
+    fn from(value: TextBuffer) -> Self {
+        let mut result = String::with_capacity(value.inner.len());
+        let mut blank = true;
+        for line in value.inner.lines() {
+            let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+            if line.is_empty() {
+                if !blank {
+                    result.push('\n');
+                }
+                blank = true;
+            } else {
+                result.push_str(&line);
+                result.push('\n');
+                blank = false;
+            }
+        }
+        let length = result.trim_end().len();
+        result.truncate(length);
+        result
+    }
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "apos" => Some('\''),
+        "gt" => Some('>'),
+        "lt" => Some('<'),
+        "nbsp" => Some(' '),
+        "quot" => Some('"'),
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse::<u32>().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Converts the html content of a template into plain text, like the content
+/// of the `mj-title` or of a `mj-text`: the usual named entities and the
+/// numeric ones are decoded, the unknown ones are kept as they are.
+pub(crate) fn decode_entities(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest
+            .find(';')
+            .and_then(|end| decode_entity(&rest[1..end]).map(|value| (value, end + 1)));
+        match decoded {
+            Some((value, length)) => {
+                result.push(value);
+                rest = &rest[length..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+fn attribute<'a>(attributes: &'a AttributeMap, name: &str) -> Option<&'a str> {
+    attributes
+        .get(name)
+        .and_then(|value| value.as_deref())
+        .filter(|value| !value.is_empty())
+}
+
+/// Renders the children in a separate buffer to use them as a label.
+fn render_label<T: RenderText>(children: &[T]) -> String {
+    let mut buffer = TextBuffer::default();
+    children.render_text(&mut buffer);
+    buffer.into()
+}
+
+pub(crate) trait RenderText {
+    fn render_text(&self, buffer: &mut TextBuffer);
+}
+
+impl<T: RenderText> RenderText for [T] {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        for item in self.iter() {
+            item.render_text(buffer);
+        }
+    }
+}
+
+impl RenderText for Comment {
+    fn render_text(&self, _buffer: &mut TextBuffer) {}
+}
+
+impl RenderText for Text {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        buffer.push_text(self.inner_str());
+    }
+}
+
+impl<T: RenderText> RenderText for Node<T> {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        let tag = self.tag.to_ascii_lowercase();
+        if IGNORED_TAGS.contains(&tag.as_str()) {
+            return;
+        }
+        if tag == "a" {
+            let label = render_label(&self.children);
+            buffer.push_link(&label, attribute(&self.attributes, "href"));
+        } else if tag == "img" {
+            if let Some(alt) = attribute(&self.attributes, "alt") {
+                buffer.push_text(alt);
+            }
+        } else if BLOCK_TAGS.contains(&tag.as_str()) {
+            buffer.new_line();
+            self.children.render_text(buffer);
+            buffer.new_line();
+        } else {
+            self.children.render_text(buffer);
+        }
+    }
+}
+
+impl RenderText for MjRawChild {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        match self {
+            Self::Comment(inner) => inner.render_text(buffer),
+            Self::Node(inner) => inner.render_text(buffer),
+            Self::Text(inner) => inner.render_text(buffer),
+        }
+    }
+}
+
+impl RenderText for MjAccordion {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        for child in self.children.iter() {
+            if let MjAccordionChild::MjAccordionElement(element) = child {
+                buffer.new_paragraph();
+                if let Some(ref title) = element.children.title {
+                    title.children.render_text(buffer);
+                }
+                buffer.new_line();
+                if let Some(ref text) = element.children.text {
+                    text.children.render_text(buffer);
+                }
+                buffer.new_paragraph();
+            }
+        }
+    }
+}
+
+impl RenderText for MjCarousel {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        for child in self.children.iter() {
+            if let MjCarouselChild::MjCarouselImage(image) = child {
+                if let Some(alt) = attribute(&image.attributes, "alt") {
+                    buffer.push_text(alt);
+                    buffer.new_line();
+                }
+            }
+        }
+    }
+}
+
+impl RenderText for MjNavbar {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        buffer.new_paragraph();
+        for child in self.children.iter() {
+            if let MjNavbarChild::MjNavbarLink(link) = child {
+                let label = render_label(&link.children);
+                buffer.push_link(&label, attribute(&link.attributes, "href"));
+                buffer.new_line();
+            }
+        }
+        buffer.new_paragraph();
+    }
+}
+
+impl RenderText for MjSocial {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        buffer.new_paragraph();
+        for child in self.children.iter() {
+            if let MjSocialChild::MjSocialElement(element) = child {
+                let label = render_label(&element.children);
+                buffer.push_link(&label, attribute(&element.attributes, "href"));
+                buffer.new_line();
+            }
+        }
+        buffer.new_paragraph();
+    }
+}
+
+impl RenderText for MjIncludeBodyChild {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        // the include children are the same as the body ones
+        match self {
+            Self::Comment(inner) => inner.render_text(buffer),
+            Self::MjAccordion(inner) => inner.render_text(buffer),
+            Self::MjButton(inner) => render_button(&inner.attributes, &inner.children, buffer),
+            Self::MjCarousel(inner) => inner.render_text(buffer),
+            Self::MjColumn(inner) => render_block(&inner.children, buffer),
+            Self::MjDivider(_) => render_divider(buffer),
+            Self::MjGroup(inner) => render_block(&inner.children, buffer),
+            Self::MjHero(inner) => render_block(&inner.children, buffer),
+            Self::MjImage(inner) => render_image(&inner.attributes, buffer),
+            Self::MjNavbar(inner) => inner.render_text(buffer),
+            Self::MjRaw(inner) => inner.children.render_text(buffer),
+            Self::MjSection(inner) => render_block(&inner.children, buffer),
+            Self::MjSocial(inner) => inner.render_text(buffer),
+            Self::MjSpacer(_) => buffer.new_paragraph(),
+            Self::MjTable(inner) => render_block(&inner.children, buffer),
+            Self::MjText(inner) => render_block(&inner.children, buffer),
+            Self::MjWrapper(inner) => render_block(&inner.children, buffer),
+            Self::Node(inner) => inner.render_text(buffer),
+            Self::Text(inner) => inner.render_text(buffer),
+        }
+    }
+}
+
+impl RenderText for MjIncludeBody {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        self.0.children.render_text(buffer);
+    }
+}
+
+impl RenderText for MjBodyChild {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        match self {
+            Self::Comment(inner) => inner.render_text(buffer),
+            Self::MjAccordion(inner) => inner.render_text(buffer),
+            Self::MjButton(inner) => render_button(&inner.attributes, &inner.children, buffer),
+            Self::MjCarousel(inner) => inner.render_text(buffer),
+            Self::MjColumn(inner) => render_block(&inner.children, buffer),
+            Self::MjDivider(_) => render_divider(buffer),
+            Self::MjGroup(inner) => render_block(&inner.children, buffer),
+            Self::MjHero(inner) => render_block(&inner.children, buffer),
+            Self::MjInclude(inner) => inner.render_text(buffer),
+            Self::MjImage(inner) => render_image(&inner.attributes, buffer),
+            Self::MjNavbar(inner) => inner.render_text(buffer),
+            Self::MjRaw(inner) => inner.children.render_text(buffer),
+            Self::MjSection(inner) => render_block(&inner.children, buffer),
+            Self::MjSocial(inner) => inner.render_text(buffer),
+            Self::MjSpacer(_) => buffer.new_paragraph(),
+            Self::MjTable(inner) => render_block(&inner.children, buffer),
+            Self::MjText(inner) => render_block(&inner.children, buffer),
+            Self::MjWrapper(inner) => render_block(&inner.children, buffer),
+            Self::Node(inner) => inner.render_text(buffer),
+            Self::Text(inner) => inner.render_text(buffer),
+        }
+    }
+}
+
+impl RenderText for MjBody {
+    fn render_text(&self, buffer: &mut TextBuffer) {
+        self.children.render_text(buffer);
+    }
+}
+
+fn render_block<T: RenderText>(children: &[T], buffer: &mut TextBuffer) {
+    buffer.new_paragraph();
+    children.render_text(buffer);
+    buffer.new_paragraph();
+}
+
+fn render_button(attributes: &AttributeMap, children: &[MjBodyChild], buffer: &mut TextBuffer) {
+    let label = render_label(children);
+    buffer.new_paragraph();
+    buffer.push_link(&label, attribute(attributes, "href"));
+    buffer.new_paragraph();
+}
+
+fn render_divider(buffer: &mut TextBuffer) {
+    buffer.new_paragraph();
+    buffer.push_str(DIVIDER);
+    buffer.new_paragraph();
+}
+
+fn render_image(attributes: &AttributeMap, buffer: &mut TextBuffer) {
+    if let Some(alt) = attribute(attributes, "alt") {
+        buffer.new_paragraph();
+        buffer.push_text(alt);
+        buffer.new_paragraph();
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::prelude::parser::{MrmlCursor, MrmlParser, ParserOptions};
+    use crate::prelude::render::text::{RenderText, TextBuffer};
+
+    fn to_text(template: &str) -> String {
+        let opts = ParserOptions::default();
+        let mut cursor = MrmlCursor::new(template);
+        let body: crate::mj_body::MjBody = MrmlParser::new(&opts).parse_root(&mut cursor).unwrap();
+        let mut buffer = TextBuffer::default();
+        body.render_text(&mut buffer);
+        buffer.into()
+    }
+
+    #[test]
+    fn should_decode_entities() {
+        assert_eq!(
+            super::decode_entities(
+                "Tom &amp; Jerry &lt;3 &#39;&#x27;&apos; &amp;lt; &copy; & done;"
+            ),
+            "Tom & Jerry <3 ''' &lt; &copy; & done;"
+        );
+    }
+
+    #[test]
+    fn should_strip_tags_from_text() {
+        assert_eq!(
+            to_text(
+                r#"<mj-body><mj-text><h1>Hello</h1><p>World, <b>how</b> are <a href="https://example.com">you</a>?</p></mj-text></mj-body>"#
+            ),
+            "Hello\n\nWorld, how are you (https://example.com)?"
+        );
+    }
+
+    #[test]
+    fn should_render_button_divider_and_image() {
+        assert_eq!(
+            to_text(
+                r#"<mj-body><mj-section><mj-column>
+  <mj-image src="https://example.com/logo.png" alt="Logo" />
+  <mj-divider />
+  <mj-button href="https://example.com">Click &amp; go</mj-button>
+  <mj-image src="https://example.com/spacer.png" />
+</mj-column></mj-section></mj-body>"#
+            ),
+            "Logo\n\n----------------------------------------\n\nClick & go (https://example.com)"
+        );
+    }
+
+    #[test]
+    fn should_collapse_blank_lines() {
+        assert_eq!(
+            to_text(
+                r#"<mj-body>
+  <mj-section><mj-column><mj-text>First</mj-text></mj-column></mj-section>
+  <mj-spacer /><mj-spacer />
+  <mj-section><mj-column><mj-text>Second</mj-text></mj-column></mj-section>
+</mj-body>"#
+            ),
+            "First\n\nSecond"
+        );
+    }
+
+    #[test]
+    fn should_ignore_style_and_comments() {
+        assert_eq!(
+            to_text(
+                r#"<mj-body><!-- hidden --><mj-raw><style>.foo { color: red; }</style><span>Visible</span></mj-raw></mj-body>"#
+            ),
+            "Visible"
+        );
+    }
+}
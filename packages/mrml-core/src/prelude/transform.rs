@@ -0,0 +1,336 @@
+//! Rewrite of a template before rendering it, like changing the attributes or
+//! the children of its elements.
+
+use crate::mj_accordion::MjAccordionChild;
+use crate::mj_accordion_element::MjAccordionElementChildren;
+use crate::mj_body::MjBodyChild;
+use crate::mj_carousel::MjCarouselChild;
+use crate::mj_head::MjHeadChild;
+use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyChild};
+use crate::mj_include::head::{MjIncludeHead, MjIncludeHeadChild};
+use crate::mj_navbar::MjNavbarChild;
+use crate::mj_raw::MjRawChild;
+use crate::mj_social::MjSocialChild;
+use crate::mjml::MjmlChildren;
+use crate::prelude::Component;
+use crate::text::Text;
+
+/// Callbacks called for each element found while rewriting a template with
+/// [`Transform::transform`]. Each callback is called before rewriting the
+/// children of the element it returns.
+///
+/// ```rust
+/// use mrml::mj_body::MjBodyChild;
+/// use mrml::prelude::transform::{Transform, Transformer};
+///
+/// struct ImageResizer;
+///
+/// impl Transformer for ImageResizer {
+///     fn transform_body_child(&mut self, element: MjBodyChild) -> MjBodyChild {
+///         match element {
+///             MjBodyChild::MjImage(mut inner) => {
+///                 inner.attributes.insert("width".into(), Some("100px".into()));
+///                 MjBodyChild::MjImage(inner)
+///             }
+///             other => other,
+///         }
+///     }
+/// }
+///
+/// let root = mrml::parse("<mjml><mj-body><mj-image src=\"a.png\" /></mj-body></mjml>").unwrap();
+/// let root = root.element.transform(&mut ImageResizer);
+/// let body = root.body().unwrap();
+/// assert!(matches!(&body.children[0], MjBodyChild::MjImage(image) if image.attributes.contains_key("width")));
+/// ```
+pub trait Transformer {
+    fn transform_head_child(&mut self, element: MjHeadChild) -> MjHeadChild {
+        element
+    }
+    fn transform_include_head_child(&mut self, element: MjIncludeHeadChild) -> MjIncludeHeadChild {
+        element
+    }
+    fn transform_body_child(&mut self, element: MjBodyChild) -> MjBodyChild {
+        element
+    }
+    fn transform_include_body_child(&mut self, element: MjIncludeBodyChild) -> MjIncludeBodyChild {
+        element
+    }
+    fn transform_accordion_child(&mut self, element: MjAccordionChild) -> MjAccordionChild {
+        element
+    }
+    fn transform_carousel_child(&mut self, element: MjCarouselChild) -> MjCarouselChild {
+        element
+    }
+    fn transform_navbar_child(&mut self, element: MjNavbarChild) -> MjNavbarChild {
+        element
+    }
+    fn transform_social_child(&mut self, element: MjSocialChild) -> MjSocialChild {
+        element
+    }
+    /// Called for the html content of elements like `mj-raw` or `mj-text`.
+    fn transform_raw_child(&mut self, element: MjRawChild) -> MjRawChild {
+        element
+    }
+}
+
+pub trait Transform: Sized {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self;
+}
+
+impl Transform for () {
+    fn transform<T: Transformer>(self, _transformer: &mut T) -> Self {
+        self
+    }
+}
+
+impl Transform for String {
+    fn transform<T: Transformer>(self, _transformer: &mut T) -> Self {
+        self
+    }
+}
+
+impl Transform for Text {
+    fn transform<T: Transformer>(self, _transformer: &mut T) -> Self {
+        self
+    }
+}
+
+impl<I: Transform> Transform for Option<I> {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        self.map(|inner| inner.transform(transformer))
+    }
+}
+
+impl<I: Transform> Transform for Vec<I> {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        self.into_iter()
+            .map(|item| item.transform(transformer))
+            .collect()
+    }
+}
+
+impl<Tag, Attributes, Children: Transform> Transform for Component<Tag, Attributes, Children> {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        Self {
+            tag: self.tag,
+            attributes: self.attributes,
+            children: self.children.transform(transformer),
+        }
+    }
+}
+
+impl Transform for MjIncludeHead {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        Self(self.0.transform(transformer))
+    }
+}
+
+impl Transform for MjIncludeBody {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        Self(self.0.transform(transformer))
+    }
+}
+
+impl Transform for MjmlChildren {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        Self {
+            head: self.head.transform(transformer),
+            body: self.body.transform(transformer),
+        }
+    }
+}
+
+impl Transform for MjAccordionElementChildren {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        Self {
+            title: self.title.transform(transformer),
+            text: self.text.transform(transformer),
+        }
+    }
+}
+
+impl Transform for MjHeadChild {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        match transformer.transform_head_child(self) {
+            Self::MjInclude(elt) => Self::MjInclude(elt.transform(transformer)),
+            Self::MjRaw(elt) => Self::MjRaw(elt.transform(transformer)),
+            other => other,
+        }
+    }
+}
+
+impl Transform for MjIncludeHeadChild {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        match transformer.transform_include_head_child(self) {
+            Self::MjRaw(elt) => Self::MjRaw(elt.transform(transformer)),
+            other => other,
+        }
+    }
+}
+
+impl Transform for MjBodyChild {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        match transformer.transform_body_child(self) {
+            Self::MjAccordion(elt) => Self::MjAccordion(elt.transform(transformer)),
+            Self::MjButton(elt) => Self::MjButton(elt.transform(transformer)),
+            Self::MjCarousel(elt) => Self::MjCarousel(elt.transform(transformer)),
+            Self::MjColumn(elt) => Self::MjColumn(elt.transform(transformer)),
+            Self::MjGroup(elt) => Self::MjGroup(elt.transform(transformer)),
+            Self::MjHero(elt) => Self::MjHero(elt.transform(transformer)),
+            Self::MjInclude(elt) => Self::MjInclude(elt.transform(transformer)),
+            Self::MjNavbar(elt) => Self::MjNavbar(elt.transform(transformer)),
+            Self::MjRaw(elt) => Self::MjRaw(elt.transform(transformer)),
+            Self::MjSection(elt) => Self::MjSection(elt.transform(transformer)),
+            Self::MjSocial(elt) => Self::MjSocial(elt.transform(transformer)),
+            Self::MjTable(elt) => Self::MjTable(elt.transform(transformer)),
+            Self::MjText(elt) => Self::MjText(elt.transform(transformer)),
+            Self::MjWrapper(elt) => Self::MjWrapper(elt.transform(transformer)),
+            Self::Node(elt) => Self::Node(elt.transform(transformer)),
+            other => other,
+        }
+    }
+}
+
+impl Transform for MjIncludeBodyChild {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        match transformer.transform_include_body_child(self) {
+            Self::MjAccordion(elt) => Self::MjAccordion(elt.transform(transformer)),
+            Self::MjButton(elt) => Self::MjButton(elt.transform(transformer)),
+            Self::MjCarousel(elt) => Self::MjCarousel(elt.transform(transformer)),
+            Self::MjColumn(elt) => Self::MjColumn(elt.transform(transformer)),
+            Self::MjGroup(elt) => Self::MjGroup(elt.transform(transformer)),
+            Self::MjHero(elt) => Self::MjHero(elt.transform(transformer)),
+            Self::MjNavbar(elt) => Self::MjNavbar(elt.transform(transformer)),
+            Self::MjRaw(elt) => Self::MjRaw(elt.transform(transformer)),
+            Self::MjSection(elt) => Self::MjSection(elt.transform(transformer)),
+            Self::MjSocial(elt) => Self::MjSocial(elt.transform(transformer)),
+            Self::MjTable(elt) => Self::MjTable(elt.transform(transformer)),
+            Self::MjText(elt) => Self::MjText(elt.transform(transformer)),
+            Self::MjWrapper(elt) => Self::MjWrapper(elt.transform(transformer)),
+            Self::Node(elt) => Self::Node(elt.transform(transformer)),
+            other => other,
+        }
+    }
+}
+
+impl Transform for MjAccordionChild {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        match transformer.transform_accordion_child(self) {
+            Self::MjAccordionElement(elt) => Self::MjAccordionElement(elt.transform(transformer)),
+            other => other,
+        }
+    }
+}
+
+impl Transform for MjCarouselChild {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        transformer.transform_carousel_child(self)
+    }
+}
+
+impl Transform for MjNavbarChild {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        match transformer.transform_navbar_child(self) {
+            Self::MjNavbarLink(elt) => Self::MjNavbarLink(elt.transform(transformer)),
+            other => other,
+        }
+    }
+}
+
+impl Transform for MjSocialChild {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        match transformer.transform_social_child(self) {
+            Self::MjSocialElement(elt) => Self::MjSocialElement(elt.transform(transformer)),
+            other => other,
+        }
+    }
+}
+
+impl Transform for MjRawChild {
+    fn transform<T: Transformer>(self, transformer: &mut T) -> Self {
+        match transformer.transform_raw_child(self) {
+            Self::Node(elt) => Self::Node(elt.transform(transformer)),
+            other => other,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parse", feature = "render"))]
+mod tests {
+    use super::{Transform, Transformer};
+    use crate::mj_body::MjBodyChild;
+    use crate::prelude::AttributeMap;
+
+    struct Tracker;
+
+    impl Tracker {
+        fn track(attributes: &mut AttributeMap) {
+            if let Some(Some(href)) = attributes.get_mut("href") {
+                href.push_str("?utm_source=x");
+            }
+        }
+    }
+
+    impl Transformer for Tracker {
+        fn transform_body_child(&mut self, element: MjBodyChild) -> MjBodyChild {
+            match element {
+                MjBodyChild::MjButton(mut inner) => {
+                    Self::track(&mut inner.attributes);
+                    MjBodyChild::MjButton(inner)
+                }
+                MjBodyChild::MjImage(mut inner) => {
+                    Self::track(&mut inner.attributes);
+                    MjBodyChild::MjImage(inner)
+                }
+                other => other,
+            }
+        }
+    }
+
+    #[test]
+    fn should_add_tracking_to_links() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-image src="logo.png" href="https://example.com/logo" />
+        <mj-button href="https://example.com/button">Click</mj-button>
+        <mj-button>No link</mj-button>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let root = root.element.transform(&mut Tracker);
+        let html = root.render(&Default::default()).unwrap();
+        assert!(html.contains("href=\"https://example.com/logo?utm_source=x\""));
+        assert!(html.contains("href=\"https://example.com/button?utm_source=x\""));
+        assert_eq!(html.matches("utm_source").count(), 2);
+    }
+
+    #[test]
+    fn should_replace_elements() {
+        struct Remover;
+
+        impl Transformer for Remover {
+            fn transform_body_child(&mut self, element: MjBodyChild) -> MjBodyChild {
+                match element {
+                    MjBodyChild::MjDivider(_) => MjBodyChild::MjSpacer(Default::default()),
+                    other => other,
+                }
+            }
+        }
+
+        let template = "<mjml><mj-body><mj-section><mj-column><mj-divider /></mj-column></mj-section></mj-body></mjml>";
+        let root = crate::parse(template).unwrap();
+        let root = root.element.transform(&mut Remover);
+        let expected = crate::parse(
+            "<mjml><mj-body><mj-section><mj-column><mj-spacer /></mj-column></mj-section></mj-body></mjml>",
+        )
+        .unwrap();
+        assert_eq!(
+            root.render(&Default::default()).unwrap(),
+            expected.element.render(&Default::default()).unwrap()
+        );
+    }
+}
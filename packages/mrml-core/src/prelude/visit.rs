@@ -0,0 +1,341 @@
+//! Read-only walk through a template, to inspect it without rendering it.
+
+use crate::mj_accordion::MjAccordionChild;
+use crate::mj_accordion_element::MjAccordionElementChildren;
+use crate::mj_body::MjBodyChild;
+use crate::mj_carousel::MjCarouselChild;
+use crate::mj_head::MjHeadChild;
+use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyChild};
+use crate::mj_include::head::{MjIncludeHead, MjIncludeHeadChild};
+use crate::mj_navbar::MjNavbarChild;
+use crate::mj_raw::MjRawChild;
+use crate::mj_social::MjSocialChild;
+use crate::mjml::MjmlChildren;
+use crate::prelude::Component;
+use crate::text::Text;
+
+/// Callbacks called for each element found while walking a template with
+/// [`Visit::visit`]. Each callback is called before walking the children of
+/// the element.
+///
+/// ```rust
+/// use mrml::mj_body::MjBodyChild;
+/// use mrml::prelude::visit::{Visit, Visitor};
+///
+/// #[derive(Default)]
+/// struct ImageCounter(usize);
+///
+/// impl Visitor for ImageCounter {
+///     fn visit_body_child(&mut self, element: &MjBodyChild) {
+///         if element.tag_name() == Some("mj-image") {
+///             self.0 += 1;
+///         }
+///     }
+/// }
+///
+/// let root = mrml::parse("<mjml><mj-body><mj-image src=\"a.png\" /></mj-body></mjml>").unwrap();
+/// let mut counter = ImageCounter::default();
+/// root.element.visit(&mut counter);
+/// assert_eq!(counter.0, 1);
+/// ```
+pub trait Visitor {
+    fn visit_head_child(&mut self, _element: &MjHeadChild) {}
+    fn visit_include_head_child(&mut self, _element: &MjIncludeHeadChild) {}
+    fn visit_body_child(&mut self, _element: &MjBodyChild) {}
+    fn visit_include_body_child(&mut self, _element: &MjIncludeBodyChild) {}
+    fn visit_accordion_child(&mut self, _element: &MjAccordionChild) {}
+    fn visit_carousel_child(&mut self, _element: &MjCarouselChild) {}
+    fn visit_navbar_child(&mut self, _element: &MjNavbarChild) {}
+    fn visit_social_child(&mut self, _element: &MjSocialChild) {}
+    /// Called for the html content of elements like `mj-raw` or `mj-text`.
+    fn visit_raw_child(&mut self, _element: &MjRawChild) {}
+}
+
+pub trait Visit {
+    fn visit<V: Visitor>(&self, visitor: &mut V);
+}
+
+impl Visit for () {
+    fn visit<V: Visitor>(&self, _visitor: &mut V) {}
+}
+
+impl Visit for String {
+    fn visit<V: Visitor>(&self, _visitor: &mut V) {}
+}
+
+impl Visit for Text {
+    fn visit<V: Visitor>(&self, _visitor: &mut V) {}
+}
+
+impl<T: Visit> Visit for Option<T> {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        if let Some(inner) = self {
+            inner.visit(visitor);
+        }
+    }
+}
+
+impl<T: Visit> Visit for Vec<T> {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        for item in self.iter() {
+            item.visit(visitor);
+        }
+    }
+}
+
+impl<Tag, Attributes, Children: Visit> Visit for Component<Tag, Attributes, Children> {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        self.children.visit(visitor);
+    }
+}
+
+impl Visit for MjIncludeHead {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        self.0.visit(visitor);
+    }
+}
+
+impl Visit for MjIncludeBody {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        self.0.visit(visitor);
+    }
+}
+
+impl Visit for MjmlChildren {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        self.head.visit(visitor);
+        self.body.visit(visitor);
+    }
+}
+
+impl Visit for MjAccordionElementChildren {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        self.title.visit(visitor);
+        self.text.visit(visitor);
+    }
+}
+
+impl Visit for MjHeadChild {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        visitor.visit_head_child(self);
+        match self {
+            Self::MjInclude(elt) => elt.visit(visitor),
+            Self::MjRaw(elt) => elt.visit(visitor),
+            _ => {}
+        }
+    }
+}
+
+impl Visit for MjIncludeHeadChild {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        visitor.visit_include_head_child(self);
+        if let Self::MjRaw(elt) = self {
+            elt.visit(visitor);
+        }
+    }
+}
+
+impl Visit for MjBodyChild {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        visitor.visit_body_child(self);
+        match self {
+            Self::Comment(_) | Self::Text(_) => {}
+            Self::MjAccordion(elt) => elt.visit(visitor),
+            Self::MjButton(elt) => elt.visit(visitor),
+            Self::MjCarousel(elt) => elt.visit(visitor),
+            Self::MjColumn(elt) => elt.visit(visitor),
+            Self::MjDivider(elt) => elt.visit(visitor),
+            Self::MjGroup(elt) => elt.visit(visitor),
+            Self::MjHero(elt) => elt.visit(visitor),
+            Self::MjInclude(elt) => elt.visit(visitor),
+            Self::MjImage(elt) => elt.visit(visitor),
+            Self::MjNavbar(elt) => elt.visit(visitor),
+            Self::MjRaw(elt) => elt.visit(visitor),
+            Self::MjSection(elt) => elt.visit(visitor),
+            Self::MjSocial(elt) => elt.visit(visitor),
+            Self::MjSpacer(elt) => elt.visit(visitor),
+            Self::MjTable(elt) => elt.visit(visitor),
+            Self::MjText(elt) => elt.visit(visitor),
+            Self::MjWrapper(elt) => elt.visit(visitor),
+            Self::Node(elt) => elt.visit(visitor),
+        }
+    }
+}
+
+impl Visit for MjIncludeBodyChild {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        visitor.visit_include_body_child(self);
+        match self {
+            Self::Comment(_) | Self::Text(_) => {}
+            Self::MjAccordion(elt) => elt.visit(visitor),
+            Self::MjButton(elt) => elt.visit(visitor),
+            Self::MjCarousel(elt) => elt.visit(visitor),
+            Self::MjColumn(elt) => elt.visit(visitor),
+            Self::MjDivider(elt) => elt.visit(visitor),
+            Self::MjGroup(elt) => elt.visit(visitor),
+            Self::MjHero(elt) => elt.visit(visitor),
+            Self::MjImage(elt) => elt.visit(visitor),
+            Self::MjNavbar(elt) => elt.visit(visitor),
+            Self::MjRaw(elt) => elt.visit(visitor),
+            Self::MjSection(elt) => elt.visit(visitor),
+            Self::MjSocial(elt) => elt.visit(visitor),
+            Self::MjSpacer(elt) => elt.visit(visitor),
+            Self::MjTable(elt) => elt.visit(visitor),
+            Self::MjText(elt) => elt.visit(visitor),
+            Self::MjWrapper(elt) => elt.visit(visitor),
+            Self::Node(elt) => elt.visit(visitor),
+        }
+    }
+}
+
+impl Visit for MjAccordionChild {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        visitor.visit_accordion_child(self);
+        if let Self::MjAccordionElement(elt) = self {
+            elt.visit(visitor);
+        }
+    }
+}
+
+impl Visit for MjCarouselChild {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        visitor.visit_carousel_child(self);
+    }
+}
+
+impl Visit for MjNavbarChild {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        visitor.visit_navbar_child(self);
+        if let Self::MjNavbarLink(elt) = self {
+            elt.visit(visitor);
+        }
+    }
+}
+
+impl Visit for MjSocialChild {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        visitor.visit_social_child(self);
+        if let Self::MjSocialElement(elt) = self {
+            elt.visit(visitor);
+        }
+    }
+}
+
+impl Visit for MjRawChild {
+    fn visit<V: Visitor>(&self, visitor: &mut V) {
+        visitor.visit_raw_child(self);
+        if let Self::Node(elt) = self {
+            elt.visit(visitor);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{Visit, Visitor};
+    use crate::mj_body::MjBodyChild;
+    use crate::mj_raw::MjRawChild;
+    use crate::mj_social::MjSocialChild;
+
+    #[derive(Default)]
+    struct Inspector {
+        images: usize,
+        links: Vec<String>,
+    }
+
+    impl Inspector {
+        fn add_link(&mut self, attributes: &crate::prelude::AttributeMap) {
+            if let Some(Some(href)) = attributes.get("href") {
+                self.links.push(href.clone());
+            }
+        }
+    }
+
+    impl Visitor for Inspector {
+        fn visit_body_child(&mut self, element: &MjBodyChild) {
+            match element {
+                MjBodyChild::MjImage(inner) => {
+                    self.images += 1;
+                    self.add_link(&inner.attributes);
+                }
+                MjBodyChild::MjButton(inner) => self.add_link(&inner.attributes),
+                _ => {}
+            }
+        }
+
+        fn visit_social_child(&mut self, element: &MjSocialChild) {
+            if let MjSocialChild::MjSocialElement(inner) = element {
+                self.add_link(&inner.attributes);
+            }
+        }
+
+        fn visit_raw_child(&mut self, element: &MjRawChild) {
+            if let MjRawChild::Node(inner) = element {
+                if inner.tag == "a" {
+                    self.add_link(&inner.attributes);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_count_images_and_links() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-image src="header.png" />
+    <mj-section>
+      <mj-column>
+        <mj-image src="first.png" href="https://first.com" />
+        <mj-text>Go to <b><a href="https://text.com">text</a></b></mj-text>
+      </mj-column>
+      <mj-column>
+        <mj-image src="second.png" />
+        <mj-button href="https://button.com">Click</mj-button>
+      </mj-column>
+    </mj-section>
+    <mj-hero>
+      <mj-image src="hero.png" />
+      <mj-social>
+        <mj-social-element name="github" href="https://github.com" />
+      </mj-social>
+    </mj-hero>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let mut inspector = Inspector::default();
+        root.element.visit(&mut inspector);
+        assert_eq!(inspector.images, 4);
+        assert_eq!(
+            inspector.links,
+            vec![
+                "https://first.com",
+                "https://text.com",
+                "https://button.com",
+                "https://github.com",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_list_tag_names() {
+        #[derive(Default)]
+        struct Names(Vec<String>);
+
+        impl Visitor for Names {
+            fn visit_body_child(&mut self, element: &MjBodyChild) {
+                if let Some(name) = element.tag_name() {
+                    self.0.push(name.to_string());
+                }
+            }
+        }
+
+        let template = "<mjml><mj-body><mj-wrapper><!-- comment --><mj-section><mj-column><mj-divider /><p>Hello</p></mj-column></mj-section></mj-wrapper></mj-body></mjml>";
+        let root = crate::parse(template).unwrap();
+        let mut names = Names::default();
+        root.element.visit(&mut names);
+        assert_eq!(
+            names.0,
+            vec!["mj-wrapper", "mj-section", "mj-column", "mj-divider", "p"]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Renderer keeping the parsing and rendering options of an application, to
+//! render many templates with the same configuration.
+
+use crate::prelude::parser::ParserOptions;
+use crate::prelude::render::RenderOptions;
+
+/// Parses and renders templates with the same options, like
+/// [`to_html`](crate::to_html), the options being built and checked once
+/// instead of for each template. It can be shared between the requests of a
+/// server.
+///
+/// ```rust
+/// use mrml::prelude::render::RenderOptions;
+/// use mrml::Renderer;
+///
+/// let options = RenderOptions::builder().minify(true).build().unwrap();
+/// let renderer = Renderer::new(options);
+/// for name in ["Alice", "Bob"] {
+///     let template = format!("<mjml><mj-body><mj-text>Hello {name}</mj-text></mj-body></mjml>");
+///     let html = renderer.render(template).unwrap();
+///     assert!(html.contains(name));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Renderer {
+    parser_options: ParserOptions,
+    render_options: RenderOptions,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new(RenderOptions::default())
+    }
+}
+
+impl Renderer {
+    pub fn new(render_options: RenderOptions) -> Self {
+        Self {
+            parser_options: ParserOptions::default(),
+            render_options,
+        }
+    }
+
+    /// Options used to parse the templates, to load the
+    /// [`mj-include`](crate::mj_include) elements for example.
+    pub fn with_parser_options(mut self, value: ParserOptions) -> Self {
+        self.parser_options = value;
+        self
+    }
+
+    pub fn parser_options(&self) -> &ParserOptions {
+        &self.parser_options
+    }
+
+    pub fn render_options(&self) -> &RenderOptions {
+        &self.render_options
+    }
+
+    /// Parses the template then renders it.
+    pub fn render<T: AsRef<str>>(&self, input: T) -> Result<String, crate::Error> {
+        let root = crate::parse_with_options(input, &self.parser_options)?;
+        Ok(root.element.render(&self.render_options)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Renderer;
+    use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+    use crate::prelude::parser::ParserOptions;
+    use crate::prelude::render::RenderOptions;
+
+    #[test]
+    fn should_render_several_templates() {
+        let options = RenderOptions::builder()
+            .breakpoint("320px")
+            .build()
+            .unwrap();
+        let renderer = Renderer::new(options);
+        let templates = [
+            "<mjml><mj-body><mj-section><mj-column><mj-text>First</mj-text></mj-column></mj-section></mj-body></mjml>",
+            "<mjml><mj-body><mj-section><mj-column><mj-button>Second</mj-button></mj-column></mj-section></mj-body></mjml>",
+            "<mjml><mj-head><mj-breakpoint width=\"400px\" /></mj-head><mj-body><mj-section><mj-column><mj-text>Third</mj-text></mj-column></mj-section></mj-body></mjml>",
+        ];
+        let outputs = templates
+            .iter()
+            .map(|template| renderer.render(template).unwrap())
+            .collect::<Vec<_>>();
+        for (output, template) in outputs.iter().zip(templates) {
+            assert_eq!(
+                output,
+                &crate::to_html(
+                    template,
+                    renderer.parser_options(),
+                    renderer.render_options()
+                )
+                .unwrap()
+            );
+        }
+        assert!(outputs[0].contains("First"));
+        assert!(outputs[0].contains("(min-width:320px)"));
+        assert!(outputs[1].contains("Second"));
+        assert!(outputs[2].contains("(min-width:400px)"));
+    }
+
+    #[test]
+    fn should_use_parser_options() {
+        let loader =
+            MemoryIncludeLoader::from(vec![("partial.mjml", "<mj-button>Hello</mj-button>")]);
+        let renderer = Renderer::default().with_parser_options(ParserOptions {
+            include_loader: Box::new(loader),
+            ..Default::default()
+        });
+        let output = renderer
+            .render(r#"<mjml><mj-body><mj-include path="partial.mjml" /></mj-body></mjml>"#)
+            .unwrap();
+        assert!(output.contains("Hello"));
+        assert!(matches!(
+            renderer.render("<mjml><mj-body>"),
+            Err(crate::Error::Parser(_))
+        ));
+    }
+}
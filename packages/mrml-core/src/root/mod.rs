@@ -7,7 +7,7 @@ mod parse;
 mod render;
 
 #[derive(Debug)]
-enum RootChild {
+pub(crate) enum RootChild {
     Mjml(Mjml),
     #[allow(dead_code)]
     Comment(Comment),
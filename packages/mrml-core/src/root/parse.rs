@@ -21,6 +21,7 @@ impl crate::prelude::parser::ParseChildren<Vec<RootChild>> for MrmlParser<'_> {
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -55,6 +56,7 @@ impl crate::prelude::parser::AsyncParseChildren<Vec<RootChild>>
                     return Err(Error::UnexpectedToken {
                         origin: cursor.origin(),
                         position: other.span(),
+                        location: cursor.location(other.span()),
                     });
                 }
             }
@@ -72,6 +74,9 @@ impl super::Root {
     ) -> Result<ParseOutput<Self>, Error> {
         let parser = MrmlParser::new(opts);
         let mut cursor = MrmlCursor::new(value.as_ref());
+        cursor.set_limits(opts.max_depth, opts.max_elements);
+        cursor.set_strict(opts.strict);
+        cursor.set_keep_line_endings(opts.keep_line_endings);
         let element = Self(parser.parse_children(&mut cursor)?);
         Ok(ParseOutput {
             element,
@@ -86,8 +91,11 @@ impl super::Root {
     ) -> Result<ParseOutput<Self>, Error> {
         use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren};
 
-        let parser = AsyncMrmlParser::new(opts);
         let mut cursor = MrmlCursor::new(value.as_ref());
+        cursor.set_limits(opts.max_depth, opts.max_elements);
+        cursor.set_strict(opts.strict);
+        cursor.set_keep_line_endings(opts.keep_line_endings);
+        let parser = AsyncMrmlParser::new(opts);
         let element = Self(parser.async_parse_children(&mut cursor).await?);
         Ok(ParseOutput {
             element,
@@ -0,0 +1,86 @@
+//! Helpers to compare rendered templates in the tests of other crates.
+//!
+//! ```rust
+//! use mrml::testing::{assert_renders_to, normalize_html};
+//!
+//! assert_eq!(
+//!     normalize_html("<div class=\"\">\n  <p>Hello   <b>World</b></p>\n</div>\n"),
+//!     "<div><p>Hello <b>World</b></p></div>"
+//! );
+//!
+//! let html = mrml::parse("<mjml><mj-body><mj-raw><p>Hello</p></mj-raw></mj-body></mjml>")
+//!     .unwrap()
+//!     .element
+//!     .render(&Default::default())
+//!     .unwrap();
+//! assert_renders_to(
+//!     "<mjml><mj-body><mj-raw>\n  <p>Hello</p>\n</mj-raw></mj-body></mjml>",
+//!     &html,
+//! );
+//! ```
+
+use crate::prelude::render::{minify, RenderOptions};
+
+/// Normalizes some html so that two documents only differing by their
+/// insignificant whitespaces are equal:
+///
+/// - the whitespaces between the block elements, like `div` or `td`, are
+///   removed,
+/// - the other sequences of whitespaces are replaced by a single space,
+/// - the empty `class` and `style` attributes are removed,
+/// - the content of the `pre`, `script` and `textarea` elements and the
+///   comments are kept untouched.
+///
+/// The result only depends on the given html.
+pub fn normalize_html(html: &str) -> String {
+    minify(html.trim())
+}
+
+/// Renders the template with the default options and checks that it matches
+/// the expected html, once both are normalized with [`normalize_html`].
+///
+/// # Panics
+///
+/// Panics if the template can't be parsed or rendered, or if the result
+/// doesn't match.
+#[track_caller]
+pub fn assert_renders_to(source: &str, expected: &str) {
+    let root = match crate::parse(source) {
+        Ok(root) => root,
+        Err(error) => panic!("unable to parse the template: {error}"),
+    };
+    let result = match root.element.render(&RenderOptions::default()) {
+        Ok(result) => result,
+        Err(error) => panic!("unable to render the template: {error}"),
+    };
+    let result = normalize_html(&result);
+    let expected = normalize_html(expected);
+    assert!(
+        result == expected,
+        "the rendered template doesn't match\n  result: {result}\nexpected: {expected}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_renders_to, normalize_html};
+
+    #[test]
+    fn should_normalize_whitespaces() {
+        assert_eq!(
+            normalize_html(
+                "\n<table>\n  <tr>\n    <td style=\"\">Hello\n  world</td>\n  </tr>\n</table>\n"
+            ),
+            "<table><tr><td>Hello world</td></tr></table>"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't match")]
+    fn should_fail_with_different_content() {
+        assert_renders_to(
+            "<mjml><mj-body><mj-raw><p>Hello</p></mj-raw></mj-body></mjml>",
+            "<p>Hello</p>",
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! C interface to render templates from other languages without spawning the
+//! cli.
+//!
+//! ```c
+//! char *html = NULL;
+//! int status = mrml_to_html("<mjml><mj-body></mj-body></mjml>", &html);
+//! if (status == MRML_OK) {
+//!     puts(html);
+//! }
+//! mrml_string_free(html);
+//! ```
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::AssertUnwindSafe;
+
+/// The template has been rendered.
+pub const MRML_OK: c_int = 0;
+/// The input or the output pointer is null.
+pub const MRML_NULL_POINTER: c_int = 1;
+/// The input is not a valid UTF-8 string.
+pub const MRML_INVALID_UTF8: c_int = 2;
+/// The template couldn't be parsed.
+pub const MRML_PARSE_ERROR: c_int = 3;
+/// The template couldn't be rendered.
+pub const MRML_RENDER_ERROR: c_int = 4;
+/// The rendered template contains a nul byte and can't be a C string.
+pub const MRML_NUL_BYTE: c_int = 5;
+/// The rendering panicked, the panic doesn't unwind into the caller.
+pub const MRML_PANIC: c_int = 6;
+
+fn to_html(input: &CStr) -> Result<String, (c_int, String)> {
+    let input = input
+        .to_str()
+        .map_err(|err| (MRML_INVALID_UTF8, err.to_string()))?;
+    let root = mrml::parse(input).map_err(|err| (MRML_PARSE_ERROR, err.to_string()))?;
+    root.element
+        .render(&Default::default())
+        .map_err(|err| (MRML_RENDER_ERROR, err.to_string()))
+}
+
+/// Calls `func`, turning a panic into an [`MRML_PANIC`] error since unwinding
+/// across the C boundary is undefined behavior.
+fn catch_panic<F>(func: F) -> Result<String, (c_int, String)>
+where
+    F: FnOnce() -> Result<String, (c_int, String)>,
+{
+    std::panic::catch_unwind(AssertUnwindSafe(func)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|msg| msg.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| String::from("unknown panic"));
+        Err((MRML_PANIC, message))
+    })
+}
+
+/// Renders the nul terminated `input` template with the default options.
+///
+/// On success, `out` points to the rendered html, otherwise it points to the
+/// error message, a panic being reported with [`MRML_PANIC`]. In both cases,
+/// the string has to be released with [`mrml_string_free`]. When a pointer is
+/// null, nothing is written.
+///
+/// # Safety
+///
+/// `input` must be null or point to a nul terminated string and `out` must be
+/// null or point to a writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn mrml_to_html(input: *const c_char, out: *mut *mut c_char) -> c_int {
+    if input.is_null() || out.is_null() {
+        return MRML_NULL_POINTER;
+    }
+    let input = CStr::from_ptr(input);
+    let (status, content) = match catch_panic(|| to_html(input)) {
+        Ok(html) => (MRML_OK, html),
+        Err(error) => error,
+    };
+    match CString::new(content) {
+        Ok(content) => {
+            *out = content.into_raw();
+            status
+        }
+        Err(_) => {
+            *out = std::ptr::null_mut();
+            MRML_NUL_BYTE
+        }
+    }
+}
+
+/// Releases a string returned by [`mrml_to_html`]. Does nothing when `ptr` is
+/// null.
+///
+/// # Safety
+///
+/// `ptr` must be null or a string returned by [`mrml_to_html`] that hasn't
+/// already been released.
+#[no_mangle]
+pub unsafe extern "C" fn mrml_string_free(ptr: *mut c_char) {
+    if !ptr.is_null() {
+        drop(CString::from_raw(ptr));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{c_char, CStr, CString};
+
+    use super::*;
+
+    fn call(input: *const c_char) -> (i32, Option<String>) {
+        let mut out: *mut c_char = std::ptr::null_mut();
+        let status = unsafe { mrml_to_html(input, &mut out) };
+        let content = (!out.is_null()).then(|| {
+            let content = unsafe { CStr::from_ptr(out) }
+                .to_string_lossy()
+                .into_owned();
+            unsafe { mrml_string_free(out) };
+            content
+        });
+        (status, content)
+    }
+
+    #[test]
+    fn should_render_template() {
+        let input =
+            CString::new("<mjml><mj-body><mj-text>Hello World</mj-text></mj-body></mjml>").unwrap();
+        let (status, content) = call(input.as_ptr());
+        assert_eq!(status, MRML_OK);
+        let content = content.unwrap();
+        assert!(content.starts_with("<!doctype html>"));
+        assert!(content.contains("Hello World"));
+    }
+
+    #[test]
+    fn should_return_parser_error() {
+        let input = CString::new("<mjml><mj-body><mj-text>Hello").unwrap();
+        let (status, content) = call(input.as_ptr());
+        assert_eq!(status, MRML_PARSE_ERROR);
+        assert!(content.unwrap().contains("root template"));
+    }
+
+    #[test]
+    fn should_reject_invalid_utf8() {
+        let input = CString::new(vec![b'<', 0xff, 0xfe, b'>']).unwrap();
+        let (status, content) = call(input.as_ptr());
+        assert_eq!(status, MRML_INVALID_UTF8);
+        assert!(content.is_some());
+    }
+
+    #[test]
+    fn should_reject_null_pointers() {
+        let (status, content) = call(std::ptr::null());
+        assert_eq!(status, MRML_NULL_POINTER);
+        assert!(content.is_none());
+
+        let input = CString::new("<mjml />").unwrap();
+        let status = unsafe { mrml_to_html(input.as_ptr(), std::ptr::null_mut()) };
+        assert_eq!(status, MRML_NULL_POINTER);
+    }
+
+    #[test]
+    fn should_catch_panics() {
+        let result = catch_panic(|| panic!("boom"));
+        assert_eq!(result, Err((MRML_PANIC, String::from("boom"))));
+        let result = catch_panic(|| panic!("{} failed", "render"));
+        assert_eq!(result, Err((MRML_PANIC, String::from("render failed"))));
+        assert_eq!(
+            catch_panic(|| Ok(String::from("ok"))),
+            Ok(String::from("ok"))
+        );
+    }
+
+    #[test]
+    fn should_free_null_pointer() {
+        unsafe { mrml_string_free(std::ptr::null_mut()) };
+    }
+}
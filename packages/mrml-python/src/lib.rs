@@ -1,3 +1,6 @@
+// pyo3's `#[pyfunction]` expansion triggers this lint on `PyResult` returns
+#![allow(clippy::useless_conversion)]
+
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
@@ -23,18 +26,13 @@ pub struct MemoryIncludeLoaderOptions(HashMap<String, String>);
 pub struct LocalIncludeLoaderOptions(PathBuf);
 
 #[pyclass(eq, eq_int)]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub enum HttpIncludeLoaderOptionsMode {
+    #[default]
     Allow,
     Deny,
 }
 
-impl Default for HttpIncludeLoaderOptionsMode {
-    fn default() -> Self {
-        Self::Allow
-    }
-}
-
 #[pyclass]
 #[derive(Clone, Debug, Default)]
 pub struct HttpIncludeLoaderOptions {
@@ -151,7 +149,10 @@ impl ParserOptions {
 impl From<ParserOptions> for mrml::prelude::parser::ParserOptions {
     fn from(value: ParserOptions) -> Self {
         let include_loader = value.include_loader.build();
-        mrml::prelude::parser::ParserOptions { include_loader }
+        mrml::prelude::parser::ParserOptions {
+            include_loader,
+            ..Default::default()
+        }
     }
 }
 
@@ -1,4 +1,4 @@
-#![allow(clippy::empty_docs)]
+#![allow(clippy::empty_docs, clippy::result_large_err)]
 
 mod parser;
 mod render;
@@ -16,7 +16,7 @@ fn to_html(
     parser_options: &mrml::prelude::parser::ParserOptions,
     render_options: &mrml::prelude::render::RenderOptions,
 ) -> Result<(String, Vec<Warning>), ToHtmlError> {
-    let element = mrml::parse_with_options(input, parser_options)?;
+    let element = mrml::parse_with_options(input, parser_options).map_err(ToHtmlError::parser)?;
     let html = element.element.render(render_options)?;
     Ok((html, Warning::from_vec(element.warnings)))
 }
@@ -28,7 +28,9 @@ async fn to_html_async(
     parser_options: std::sync::Arc<mrml::prelude::parser::AsyncParserOptions>,
     render_options: &mrml::prelude::render::RenderOptions,
 ) -> Result<(String, Vec<Warning>), ToHtmlError> {
-    let element = mrml::async_parse_with_options(input, parser_options).await?;
+    let element = mrml::async_parse_with_options(input, parser_options)
+        .await
+        .map_err(ToHtmlError::parser)?;
     let html = element.element.render(render_options)?;
     Ok((html, Warning::from_vec(element.warnings)))
 }
@@ -97,17 +99,21 @@ pub enum ToHtmlError {
     Parser {
         message: String,
         details: ParserError,
+        /// Line and column of the error in the template where it occurred.
+        location: Option<Location>,
     },
     Render {
         message: String,
     },
 }
 
-impl From<mrml::prelude::parser::Error> for ToHtmlError {
-    fn from(value: mrml::prelude::parser::Error) -> Self {
+impl ToHtmlError {
+    fn parser(value: mrml::prelude::parser::Error) -> Self {
+        let location = value.location().map(Location::from);
         ToHtmlError::Parser {
             message: value.to_string(),
             details: value.into(),
+            location,
         }
     }
 }
@@ -155,7 +161,7 @@ mod tests {
 
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use crate::{Engine, ToHtmlResult};
+    use crate::{Engine, ToHtmlError, ToHtmlResult};
 
     #[wasm_bindgen_test]
     fn it_should_render() {
@@ -173,6 +179,26 @@ mod tests {
         assert!(matches!(result, ToHtmlResult::Error(_)));
     }
 
+    #[wasm_bindgen_test]
+    fn it_should_error_with_location() {
+        let template = "<mjml>\n  <mj-head>\n    <mj-foo />\n  </mj-head>\n</mjml>";
+        let opts = Engine::new();
+        match opts.to_html(template) {
+            ToHtmlResult::Error(ToHtmlError::Parser {
+                message,
+                location: Some(location),
+                ..
+            }) => {
+                assert_eq!(
+                    message,
+                    "unexpected element in root template at line 3, column 6"
+                );
+                assert_eq!((location.line, location.column), (3, 6));
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+    }
+
     #[wasm_bindgen_test]
     fn it_should_render_with_include() {
         let template = "<mjml><mj-body><mj-include path=\"/hello-world.mjml\" /></mj-body></mjml>";
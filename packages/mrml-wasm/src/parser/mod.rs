@@ -6,20 +6,15 @@ pub use memory_include_loader::*;
 #[cfg(feature = "reqwest-include-loader")]
 pub use reqwest_include_loader::*;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, tsify::Tsify)]
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, tsify::Tsify)]
 #[serde(tag = "type", rename_all = "camelCase")]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum IncludeLoaderOptions {
+    #[default]
     Noop,
     Memory(MemoryIncludeLoaderOptions),
 }
 
-impl Default for IncludeLoaderOptions {
-    fn default() -> Self {
-        Self::Noop
-    }
-}
-
 impl IncludeLoaderOptions {
     pub fn build(
         self,
@@ -42,29 +37,24 @@ impl From<ParserOptions> for mrml::prelude::parser::ParserOptions {
     fn from(value: ParserOptions) -> Self {
         mrml::prelude::parser::ParserOptions {
             include_loader: value.include_loader.build(),
+            ..Default::default()
         }
     }
 }
 
 // ASYNC RELATED
 #[cfg(feature = "async")]
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, tsify::Tsify)]
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, tsify::Tsify)]
 #[serde(tag = "type", rename_all = "camelCase")]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum AsyncIncludeLoaderOptions {
+    #[default]
     Noop,
     Memory(MemoryIncludeLoaderOptions),
     #[cfg(feature = "reqwest-include-loader")]
     Reqwest(ReqwestIncludeLoaderOptions),
 }
 
-#[cfg(feature = "async")]
-impl Default for AsyncIncludeLoaderOptions {
-    fn default() -> Self {
-        Self::Noop
-    }
-}
-
 #[cfg(feature = "async")]
 impl AsyncIncludeLoaderOptions {
     pub fn build_async(
@@ -92,6 +82,7 @@ impl From<AsyncParserOptions> for mrml::prelude::parser::AsyncParserOptions {
     fn from(value: AsyncParserOptions) -> Self {
         mrml::prelude::parser::AsyncParserOptions {
             include_loader: value.include_loader.build_async(),
+            ..Default::default()
         }
     }
 }
@@ -104,6 +95,10 @@ pub enum ParserError {
         origin: super::Origin,
         position: super::Span,
     },
+    DuplicateElement {
+        origin: super::Origin,
+        position: super::Span,
+    },
     UnexpectedToken {
         origin: super::Origin,
         position: super::Span,
@@ -113,6 +108,12 @@ pub enum ParserError {
         origin: super::Origin,
         position: super::Span,
     },
+    UnknownAttribute {
+        element: String,
+        name: String,
+        origin: super::Origin,
+        position: super::Span,
+    },
     InvalidAttribute {
         origin: super::Origin,
         position: super::Span,
@@ -140,6 +141,19 @@ pub enum ParserError {
         position: super::Span,
         source: String,
     },
+    IncludeCycle {
+        path: String,
+        origin: super::Origin,
+        position: super::Span,
+    },
+    LimitExceeded {
+        limit: String,
+        origin: super::Origin,
+        position: super::Span,
+    },
+    Io {
+        source: String,
+    },
 }
 
 impl From<mrml::prelude::parser::Error> for ParserError {
@@ -148,6 +162,9 @@ impl From<mrml::prelude::parser::Error> for ParserError {
 
         match value {
             Error::NoRootNode => Self::NoRootNode,
+            Error::Io { source } => Self::Io {
+                source: source.to_string(),
+            },
             Error::EndOfStream { origin } => Self::EndOfStream {
                 origin: origin.into(),
             },
@@ -155,16 +172,53 @@ impl From<mrml::prelude::parser::Error> for ParserError {
                 origin,
                 position,
                 source,
+                ..
             } => Self::IncludeLoaderError {
                 origin: origin.into(),
                 position: position.into(),
                 source: source.to_string(),
             },
-            Error::InvalidAttribute { origin, position } => Self::InvalidAttribute {
+            Error::IncludeCycle {
+                path,
+                origin,
+                position,
+                ..
+            } => Self::IncludeCycle {
+                path,
                 origin: origin.into(),
                 position: position.into(),
             },
-            Error::InvalidFormat { origin, position } => Self::InvalidFormat {
+            Error::LimitExceeded {
+                limit,
+                origin,
+                position,
+                ..
+            } => Self::LimitExceeded {
+                limit: limit.to_string(),
+                origin: origin.into(),
+                position: position.into(),
+            },
+            Error::UnknownAttribute {
+                element,
+                name,
+                origin,
+                position,
+                ..
+            } => Self::UnknownAttribute {
+                element,
+                name,
+                origin: origin.into(),
+                position: position.into(),
+            },
+            Error::InvalidAttribute {
+                origin, position, ..
+            } => Self::InvalidAttribute {
+                origin: origin.into(),
+                position: position.into(),
+            },
+            Error::InvalidFormat {
+                origin, position, ..
+            } => Self::InvalidFormat {
                 origin: origin.into(),
                 position: position.into(),
             },
@@ -172,6 +226,7 @@ impl From<mrml::prelude::parser::Error> for ParserError {
                 name,
                 origin,
                 position,
+                ..
             } => Self::MissingAttribute {
                 name: name.into(),
                 origin: origin.into(),
@@ -184,11 +239,21 @@ impl From<mrml::prelude::parser::Error> for ParserError {
             Error::SizeLimit { origin } => Self::SizeLimit {
                 origin: origin.into(),
             },
-            Error::UnexpectedElement { origin, position } => Self::UnexpectedElement {
+            Error::UnexpectedElement {
+                origin, position, ..
+            } => Self::UnexpectedElement {
+                origin: origin.into(),
+                position: position.into(),
+            },
+            Error::DuplicateElement {
+                origin, position, ..
+            } => Self::DuplicateElement {
                 origin: origin.into(),
                 position: position.into(),
             },
-            Error::UnexpectedToken { origin, position } => Self::UnexpectedToken {
+            Error::UnexpectedToken {
+                origin, position, ..
+            } => Self::UnexpectedToken {
                 origin: origin.into(),
                 position: position.into(),
             },
@@ -229,17 +294,44 @@ impl From<mrml::prelude::parser::Span> for Span {
     }
 }
 
+/// Human readable position in the root template, lines and columns start at 1.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, tsify::Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl From<mrml::prelude::parser::Location> for Location {
+    fn from(value: mrml::prelude::parser::Location) -> Self {
+        Self {
+            line: value.line,
+            column: value.column,
+        }
+    }
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, tsify::Tsify)]
 #[serde(rename_all = "kebab-case")]
 #[tsify(into_wasm_abi)]
 pub enum WarningKind {
     UnexpectedAttributes,
+    IgnoredComment,
+    DuplicateElement,
+    InvalidSize,
+    PreviewTooLong,
+    MissingAlt,
 }
 
 impl From<mrml::prelude::parser::WarningKind> for WarningKind {
     fn from(value: mrml::prelude::parser::WarningKind) -> Self {
         match value {
             mrml::prelude::parser::WarningKind::UnexpectedAttribute => Self::UnexpectedAttributes,
+            mrml::prelude::parser::WarningKind::IgnoredComment => Self::IgnoredComment,
+            mrml::prelude::parser::WarningKind::DuplicateElement => Self::DuplicateElement,
+            mrml::prelude::parser::WarningKind::InvalidSize => Self::InvalidSize,
+            mrml::prelude::parser::WarningKind::PreviewTooLong => Self::PreviewTooLong,
+            mrml::prelude::parser::WarningKind::MissingAlt => Self::MissingAlt,
         }
     }
 }
@@ -29,10 +29,7 @@ fn it_should_fail_when_render_template() {
     let result = engine.to_html(template);
     match result {
         mrml_wasm::ToHtmlResult::Error(err) => match err {
-            ToHtmlError::Parser {
-                message,
-                details: _,
-            } => {
+            ToHtmlError::Parser { message, .. } => {
                 assert_eq!(message, "unable to parse next template in root template")
             }
             other => panic!("unexpected error {:?}", other),
@@ -49,10 +46,7 @@ async fn it_should_fail_when_render_template_async() {
     let result = engine.to_html_async(template).await;
     match result {
         mrml_wasm::ToHtmlResult::Error(err) => match err {
-            ToHtmlError::Parser {
-                message,
-                details: _,
-            } => {
+            ToHtmlError::Parser { message, .. } => {
                 assert_eq!(message, "unable to parse next template in root template")
             }
             other => panic!("unexpected error {:?}", other),
@@ -109,13 +103,10 @@ fn it_should_use_noop_include_loader_by_default() {
     let result = engine.to_html(template);
     match result {
         mrml_wasm::ToHtmlResult::Error(err) => match err {
-            ToHtmlError::Parser {
-                message,
-                details: _,
-            } => {
+            ToHtmlError::Parser { message, .. } => {
                 assert_eq!(
                     message,
-                    "unable to load included template in root template at position 46:56"
+                    "unable to load included template in root template at line 1, column 47"
                 )
             }
             other => panic!("unexpected error {:?}", other),
@@ -136,13 +127,10 @@ async fn it_should_use_noop_include_loader_by_default_async() {
     let result = engine.to_html_async(template).await;
     match result {
         mrml_wasm::ToHtmlResult::Error(err) => match err {
-            ToHtmlError::Parser {
-                message,
-                details: _,
-            } => {
+            ToHtmlError::Parser { message, .. } => {
                 assert_eq!(
                     message,
-                    "unable to load included template in root template at position 46:56"
+                    "unable to load included template in root template at line 1, column 47"
                 )
             }
             other => panic!("unexpected error {:?}", other),